@@ -95,8 +95,14 @@ fn main() -> Result<()> {
                                 denom: DepositToken::VotingModuleToken {},
                                 amount: Uint128::new(1000000000),
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                slash_recipient: None,
                             }),
                             open_proposal_submission: false,
+                            submission_policy: None,
+                            proposal_limits: None,
+                            alternative_deposits: vec![],
+                            nft_deposit: None,
+                            submission_fee: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -105,6 +111,12 @@ fn main() -> Result<()> {
                     },
                 },
                 close_proposal_on_execution_failure: false,
+                early_execution: None,
+                execution_window: None,
+                retry_policy: None,
+                amendment_window: None,
+                reveal_period: None,
+                veto_threshold: None,
             })?,
             admin: Some(Admin::CoreModule {}),
             label: "DAO DAO Proposal Module".to_string(),
@@ -72,6 +72,12 @@ pub fn create_dao(
                 allow_revoting: false,
                 only_members_execute: true,
                 close_proposal_on_execution_failure: false,
+                early_execution: None,
+                execution_window: None,
+                retry_policy: None,
+                amendment_window: None,
+                reveal_period: None,
+                veto_threshold: None,
                 pre_propose_info: PreProposeInfo::ModuleMayPropose {
                     info: ModuleInstantiateInfo {
                         code_id: chain.orc.contract_map.code_id("dao_pre_propose_single")?,
@@ -80,8 +86,14 @@ pub fn create_dao(
                                 denom: DepositToken::VotingModuleToken {},
                                 amount: DEPOSIT_AMOUNT,
                                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                                slash_recipient: None,
                             }),
                             open_proposal_submission: false,
+                            submission_policy: None,
+                            proposal_limits: None,
+                            alternative_deposits: vec![],
+                            nft_deposit: None,
+                            submission_fee: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -118,15 +130,16 @@ pub fn create_dao(
         .unwrap();
 
     let ProposalCreationPolicy::Module { addr: pre_propose } = chain
-	.orc
-	.query(
-	    "dao_proposal_single",
-	    &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {}
-	).unwrap()
-	.data()
-	.unwrap()
+        .orc
+        .query(
+            "dao_proposal_single",
+            &dao_proposal_single::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+        .data()
+        .unwrap()
     else {
-	panic!("expected pre-propose module")
+        panic!("expected pre-propose module")
     };
     chain
         .orc
@@ -240,6 +253,7 @@ pub fn create_proposal(
                     title: "title".to_string(),
                     description: "desc".to_string(),
                     msgs,
+                    depends_on: None,
                 },
             },
             key,
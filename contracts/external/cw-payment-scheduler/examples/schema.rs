@@ -0,0 +1,10 @@
+use cosmwasm_schema::write_api;
+use cw_payment_scheduler::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+        execute: ExecuteMsg,
+    }
+}
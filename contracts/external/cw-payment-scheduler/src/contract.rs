@@ -0,0 +1,291 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdError, StdResult,
+    Storage, Timestamp, Uint128,
+};
+use cw2::set_contract_version;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_storage_plus::Bound;
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, OutflowResponse, QueryMsg};
+use crate::payment::Payment;
+use crate::state::{payments, COMMITTED_OUTFLOW, NEXT_ID};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:cw-payment-scheduler";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    NEXT_ID.save(deps.storage, &0)?;
+    COMMITTED_OUTFLOW.save(deps.storage, &vec![])?;
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::AddPayment {
+            recipient,
+            denom,
+            amount,
+            interval_seconds,
+            start,
+            end,
+        } => execute_add_payment(
+            deps,
+            env,
+            info,
+            recipient,
+            denom,
+            amount,
+            interval_seconds,
+            start,
+            end,
+        ),
+        ExecuteMsg::RemovePayment { id } => execute_remove_payment(deps, info, id),
+        ExecuteMsg::ExecuteDue { start_after, limit } => {
+            execute_execute_due(deps, env, start_after, limit)
+        }
+        ExecuteMsg::Withdraw { denom, amount } => execute_withdraw(deps, env, info, denom, amount),
+        ExecuteMsg::UpdateOwnership(action) => {
+            let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+            Ok(Response::default().add_attributes(ownership.into_attributes()))
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_add_payment(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    recipient: String,
+    denom: UncheckedDenom,
+    amount: Uint128,
+    interval_seconds: u64,
+    start: Option<Timestamp>,
+    end: Timestamp,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if amount.is_zero() {
+        return Err(ContractError::ZeroAmount {});
+    }
+    if interval_seconds == 0 {
+        return Err(ContractError::ZeroInterval {});
+    }
+    let start = start.unwrap_or(env.block.time);
+    if end < start {
+        return Err(ContractError::EndBeforeStart {
+            start: start.seconds(),
+            end: end.seconds(),
+        });
+    }
+
+    let id = NEXT_ID.load(deps.storage)?;
+    let payment = Payment {
+        id,
+        recipient: deps.api.addr_validate(&recipient)?,
+        denom: denom.into_checked(deps.as_ref())?,
+        amount,
+        interval_seconds,
+        next_payment: start,
+        end,
+    };
+    payments().save(deps.storage, id, &payment)?;
+    NEXT_ID.save(deps.storage, &(id + 1))?;
+    add_outflow(deps.storage, &payment.denom, payment.remaining()?)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "add_payment")
+        .add_attribute("id", id.to_string())
+        .add_attribute("recipient", payment.recipient)
+        .add_attribute("denom", payment.denom.to_string())
+        .add_attribute("amount", amount))
+}
+
+pub fn execute_remove_payment(
+    deps: DepsMut,
+    info: MessageInfo,
+    id: u64,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    let payment = payments()
+        .may_load(deps.storage, id)?
+        .ok_or(ContractError::NoSuchPayment { id })?;
+    payments().remove(deps.storage, id)?;
+    sub_outflow(deps.storage, &payment.denom, payment.remaining()?)?;
+    Ok(Response::default()
+        .add_attribute("method", "remove_payment")
+        .add_attribute("id", id.to_string()))
+}
+
+pub fn execute_execute_due(
+    deps: DepsMut,
+    env: Env,
+    start_after: Option<u64>,
+    limit: Option<u32>,
+) -> Result<Response, ContractError> {
+    let limit = limit.unwrap_or(MAX_LIMIT).min(MAX_LIMIT) as usize;
+    let batch = payments()
+        .range(
+            deps.storage,
+            start_after.map(Bound::exclusive),
+            None,
+            Order::Ascending,
+        )
+        .take(limit)
+        .map(|p| p.map(|(_, p)| p))
+        .collect::<StdResult<Vec<Payment>>>()?;
+
+    // Balance available for payments in each denom, loaded as needed.
+    let mut available: Vec<(CheckedDenom, Uint128)> = vec![];
+    let mut response = Response::default().add_attribute("method", "execute_due");
+    let mut paid = 0;
+
+    for mut payment in batch {
+        let due = payment.due(env.block.time)?;
+        if due.is_zero() {
+            continue;
+        }
+
+        let index = match available.iter().position(|(d, _)| *d == payment.denom) {
+            Some(index) => index,
+            None => {
+                let balance = payment
+                    .denom
+                    .query_balance(&deps.querier, &env.contract.address)?;
+                available.push((payment.denom.clone(), balance));
+                available.len() - 1
+            }
+        };
+        let balance = &mut available[index].1;
+        if *balance < due {
+            // Insufficient funds. Leave the payment due so that it is
+            // paid once this contract is topped up.
+            response = response.add_attribute("skipped", payment.id.to_string());
+            continue;
+        }
+        *balance -= due;
+
+        response = response.add_message(
+            payment
+                .denom
+                .get_transfer_to_message(&payment.recipient, due)?,
+        );
+        sub_outflow(deps.storage, &payment.denom, due)?;
+        if payment.advance(env.block.time) {
+            payments().remove(deps.storage, payment.id)?;
+        } else {
+            payments().save(deps.storage, payment.id, &payment)?;
+        }
+        paid += 1;
+    }
+
+    Ok(response.add_attribute("paid", paid.to_string()))
+}
+
+/// Adds AMOUNT of DENOM to the committed outflow.
+fn add_outflow(storage: &mut dyn Storage, denom: &CheckedDenom, amount: Uint128) -> StdResult<()> {
+    COMMITTED_OUTFLOW.update(storage, |mut outflow| {
+        match outflow.iter_mut().find(|o| o.denom == *denom) {
+            Some(o) => o.amount = o.amount.checked_add(amount)?,
+            None => outflow.push(OutflowResponse {
+                denom: denom.clone(),
+                amount,
+            }),
+        }
+        Ok::<_, StdError>(outflow)
+    })?;
+    Ok(())
+}
+
+/// Removes AMOUNT of DENOM from the committed outflow. Denoms with no
+/// outflow remaining are removed.
+fn sub_outflow(storage: &mut dyn Storage, denom: &CheckedDenom, amount: Uint128) -> StdResult<()> {
+    COMMITTED_OUTFLOW.update(storage, |mut outflow| {
+        if let Some(o) = outflow.iter_mut().find(|o| o.denom == *denom) {
+            o.amount = o.amount.checked_sub(amount)?;
+        }
+        outflow.retain(|o| !o.amount.is_zero());
+        Ok::<_, StdError>(outflow)
+    })?;
+    Ok(())
+}
+
+pub fn execute_withdraw(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    denom: UncheckedDenom,
+    amount: Option<Uint128>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    let denom = denom.into_checked(deps.as_ref())?;
+    let amount = match amount {
+        Some(amount) => amount,
+        None => denom.query_balance(&deps.querier, &env.contract.address)?,
+    };
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "withdraw")
+        .add_attribute("denom", denom.to_string())
+        .add_attribute("amount", amount)
+        .add_message(denom.get_transfer_to_message(&info.sender, amount)?))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Payment { id } => to_binary(&payments().load(deps.storage, id)?),
+        QueryMsg::ListPayments { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let payments = payments()
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit)
+                .map(|p| p.map(|(_, p)| p))
+                .collect::<StdResult<Vec<Payment>>>()?;
+            to_binary(&payments)
+        }
+        QueryMsg::Upcoming { limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let payments = payments()
+                .idx
+                .next_payment
+                .range(deps.storage, None, None, Order::Ascending)
+                .take(limit)
+                .map(|p| p.map(|(_, p)| p))
+                .collect::<StdResult<Vec<Payment>>>()?;
+            to_binary(&payments)
+        }
+        QueryMsg::CommittedOutflow {} => to_binary(&COMMITTED_OUTFLOW.load(deps.storage)?),
+        QueryMsg::Ownership {} => to_binary(&cw_ownable::get_ownership(deps.storage)?),
+    }
+}
@@ -0,0 +1,31 @@
+use cosmwasm_std::StdError;
+use cw_denom::DenomError;
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownable(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error("payment amount must be non-zero")]
+    ZeroAmount {},
+
+    #[error("payment interval must be non-zero")]
+    ZeroInterval {},
+
+    #[error("payment ends ({end}) before it starts ({start})")]
+    EndBeforeStart { start: u64, end: u64 },
+
+    #[error("no payment with id ({id})")]
+    NoSuchPayment { id: u64 },
+
+    #[error("nothing to withdraw")]
+    NothingToWithdraw {},
+}
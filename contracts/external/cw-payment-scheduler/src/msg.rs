@@ -0,0 +1,74 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Timestamp, Uint128};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_ownable::cw_ownable_execute;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The owner of this contract, usually a DAO. Only the owner may
+    /// add and remove payments.
+    pub owner: Option<String>,
+}
+
+#[cw_ownable_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Callable only by the owner. Registers a payment of AMOUNT
+    /// DENOM to RECIPIENT every INTERVAL_SECONDS between START and
+    /// END. If START is not set, the first payment is due when this
+    /// message is executed.
+    AddPayment {
+        recipient: String,
+        denom: UncheckedDenom,
+        amount: Uint128,
+        interval_seconds: u64,
+        start: Option<Timestamp>,
+        end: Timestamp,
+    },
+    /// Callable only by the owner. Removes a payment. Payments that
+    /// are due but have not been made are not paid.
+    RemovePayment { id: u64 },
+    /// Makes the payments that are due among the LIMIT payments
+    /// with IDs after START_AFTER. At most 50 payments are considered
+    /// per call. Callable by anyone.
+    ExecuteDue {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Callable only by the owner. Sends AMOUNT of DENOM held by this
+    /// contract to the owner, or this contract's entire balance if
+    /// AMOUNT is not set.
+    Withdraw {
+        denom: UncheckedDenom,
+        amount: Option<Uint128>,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets a payment by ID.
+    #[returns(crate::payment::Payment)]
+    Payment { id: u64 },
+    /// Lists payments in order of their ID.
+    #[returns(Vec<crate::payment::Payment>)]
+    ListPayments {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists payments in the order they will next be paid.
+    #[returns(Vec<crate::payment::Payment>)]
+    Upcoming { limit: Option<u32> },
+    /// Gets the total amount of each token that remains to be paid by
+    /// all payments.
+    #[returns(Vec<OutflowResponse>)]
+    CommittedOutflow {},
+    #[returns(::cw_ownable::Ownership::<::cosmwasm_std::Addr>)]
+    Ownership {},
+}
+
+#[cw_serde]
+pub struct OutflowResponse {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+}
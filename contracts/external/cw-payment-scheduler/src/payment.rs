@@ -0,0 +1,99 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, StdResult, Timestamp, Uint128};
+use cw_denom::CheckedDenom;
+
+/// A recurring payment.
+#[cw_serde]
+pub struct Payment {
+    pub id: u64,
+    /// The address receiving the payment.
+    pub recipient: Addr,
+    /// The token being paid.
+    pub denom: CheckedDenom,
+    /// The amount paid each interval.
+    pub amount: Uint128,
+    /// The number of seconds between payments.
+    pub interval_seconds: u64,
+    /// The time the next payment is due.
+    pub next_payment: Timestamp,
+    /// No payments are made after this time.
+    pub end: Timestamp,
+}
+
+impl Payment {
+    /// The number of payments due at or before time T. Payments are
+    /// due every `interval_seconds` starting at `next_payment` and
+    /// until `end`.
+    pub fn periods_until(&self, t: Timestamp) -> u64 {
+        let last = std::cmp::min(t, self.end);
+        if self.next_payment > last {
+            0
+        } else {
+            (last.seconds() - self.next_payment.seconds()) / self.interval_seconds + 1
+        }
+    }
+
+    /// The amount that has not yet been paid and is due at or before
+    /// time T.
+    pub fn due(&self, t: Timestamp) -> StdResult<Uint128> {
+        Ok(self
+            .amount
+            .checked_mul(Uint128::from(self.periods_until(t)))?)
+    }
+
+    /// The amount remaining to be paid over the life of the payment.
+    pub fn remaining(&self) -> StdResult<Uint128> {
+        self.due(self.end)
+    }
+
+    /// Marks all payments due at or before time T as paid. Returns
+    /// true if the payment has been completed.
+    pub fn advance(&mut self, t: Timestamp) -> bool {
+        let periods = self.periods_until(t);
+        self.next_payment = self
+            .next_payment
+            .plus_seconds(periods * self.interval_seconds);
+        self.next_payment > self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payment() -> Payment {
+        Payment {
+            id: 0,
+            recipient: Addr::unchecked("ekez"),
+            denom: CheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(10),
+            interval_seconds: 100,
+            next_payment: Timestamp::from_seconds(100),
+            end: Timestamp::from_seconds(350),
+        }
+    }
+
+    #[test]
+    fn test_periods_until() {
+        let p = payment();
+        assert_eq!(p.periods_until(Timestamp::from_seconds(99)), 0);
+        assert_eq!(p.periods_until(Timestamp::from_seconds(100)), 1);
+        assert_eq!(p.periods_until(Timestamp::from_seconds(299)), 2);
+        assert_eq!(p.periods_until(Timestamp::from_seconds(1000)), 3);
+        assert_eq!(p.remaining().unwrap(), Uint128::new(30));
+    }
+
+    #[test]
+    fn test_advance() {
+        let mut p = payment();
+        assert!(!p.advance(Timestamp::from_seconds(50)));
+        assert_eq!(p.next_payment, Timestamp::from_seconds(100));
+
+        assert!(!p.advance(Timestamp::from_seconds(250)));
+        assert_eq!(p.next_payment, Timestamp::from_seconds(300));
+        assert_eq!(p.remaining().unwrap(), Uint128::new(10));
+
+        assert!(p.advance(Timestamp::from_seconds(300)));
+        assert_eq!(p.remaining().unwrap(), Uint128::zero());
+    }
+}
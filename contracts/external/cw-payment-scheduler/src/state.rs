@@ -0,0 +1,35 @@
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+
+use crate::{msg::OutflowResponse, payment::Payment};
+
+/// The ID that will be assigned to the next payment.
+pub const NEXT_ID: Item<u64> = Item::new("next_id");
+/// The total amount of each token that remains to be paid by all
+/// payments. Updated as payments are added, made, and removed so that
+/// it need not be computed from every payment.
+pub const COMMITTED_OUTFLOW: Item<Vec<OutflowResponse>> = Item::new("committed_outflow");
+
+pub struct PaymentIndexes<'a> {
+    /// Payments indexed by the time, in nanoseconds, that their next
+    /// payment is due.
+    pub next_payment: MultiIndex<'a, u64, Payment, u64>,
+}
+
+impl<'a> IndexList<Payment> for PaymentIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Payment>> + '_> {
+        let v: Vec<&dyn Index<Payment>> = vec![&self.next_payment];
+        Box::new(v.into_iter())
+    }
+}
+
+/// Payments that have not yet completed, by ID.
+pub fn payments<'a>() -> IndexedMap<'a, u64, Payment, PaymentIndexes<'a>> {
+    let indexes = PaymentIndexes {
+        next_payment: MultiIndex::new(
+            |_pk: &[u8], p: &Payment| p.next_payment.nanos(),
+            "payments",
+            "payments__next_payment",
+        ),
+    };
+    IndexedMap::new("payments", indexes)
+}
@@ -0,0 +1,429 @@
+use cosmwasm_std::{coins, Addr, Empty, Timestamp, Uint128};
+use cw20::Cw20Coin;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_multi_test::{App, AppResponse, BankSudo, Contract, ContractWrapper, Executor, SudoMsg};
+use cw_ownable::OwnershipError;
+
+use crate::{
+    msg::{ExecuteMsg, InstantiateMsg, OutflowResponse, QueryMsg},
+    payment::Payment,
+    ContractError,
+};
+
+const DAO: &str = "dao";
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+const NATIVE_DENOM: &str = "ujuno";
+const DAY: u64 = 60 * 60 * 24;
+
+fn scheduler_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw20_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn setup(app: &mut App) -> Addr {
+    let code_id = app.store_code(scheduler_contract());
+    app.instantiate_contract(
+        code_id,
+        Addr::unchecked(DAO),
+        &InstantiateMsg {
+            owner: Some(DAO.to_string()),
+        },
+        &[],
+        "cw-payment-scheduler",
+        None,
+    )
+    .unwrap()
+}
+
+fn fund(app: &mut App, scheduler: &Addr, amount: u128) {
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: scheduler.to_string(),
+        amount: coins(amount, NATIVE_DENOM),
+    }))
+    .unwrap();
+}
+
+fn add_payment(
+    app: &mut App,
+    scheduler: &Addr,
+    recipient: &str,
+    denom: UncheckedDenom,
+    amount: u128,
+    interval_seconds: u64,
+    end: Timestamp,
+) -> anyhow::Result<AppResponse> {
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        scheduler.clone(),
+        &ExecuteMsg::AddPayment {
+            recipient: recipient.to_string(),
+            denom,
+            amount: Uint128::new(amount),
+            interval_seconds,
+            start: None,
+            end,
+        },
+        &[],
+    )
+}
+
+fn execute_due(app: &mut App, scheduler: &Addr) {
+    app.execute_contract(
+        Addr::unchecked(BOB),
+        scheduler.clone(),
+        &ExecuteMsg::ExecuteDue {
+            start_after: None,
+            limit: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn advance_time(app: &mut App, seconds: u64) {
+    app.update_block(|b| {
+        b.time = b.time.plus_seconds(seconds);
+        b.height += 1;
+    });
+}
+
+fn native_balance(app: &App, who: &str) -> u128 {
+    app.wrap()
+        .query_balance(who, NATIVE_DENOM)
+        .unwrap()
+        .amount
+        .u128()
+}
+
+fn query_outflow(app: &App, scheduler: &Addr) -> Vec<OutflowResponse> {
+    app.wrap()
+        .query_wasm_smart(scheduler, &QueryMsg::CommittedOutflow {})
+        .unwrap()
+}
+
+fn query_upcoming(app: &App, scheduler: &Addr) -> Vec<Payment> {
+    app.wrap()
+        .query_wasm_smart(scheduler, &QueryMsg::Upcoming { limit: None })
+        .unwrap()
+}
+
+#[test]
+fn test_recurring_native_payment() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+    fund(&mut app, &scheduler, 1000);
+
+    let end = app.block_info().time.plus_seconds(30 * DAY * 2);
+    add_payment(
+        &mut app,
+        &scheduler,
+        ALICE,
+        UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+        100,
+        30 * DAY,
+        end,
+    )
+    .unwrap();
+
+    assert_eq!(
+        query_outflow(&app, &scheduler),
+        vec![OutflowResponse {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(300),
+        }]
+    );
+
+    // First payment is due immediately.
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, ALICE), 100);
+
+    // Nothing more is due until the interval has passed.
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, ALICE), 100);
+
+    // Missed payments are paid all at once and the payment is
+    // removed once complete.
+    advance_time(&mut app, 30 * DAY * 3);
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, ALICE), 300);
+    assert_eq!(native_balance(&app, scheduler.as_str()), 700);
+    assert!(query_upcoming(&app, &scheduler).is_empty());
+    assert!(query_outflow(&app, &scheduler).is_empty());
+}
+
+#[test]
+fn test_insufficient_balance_skips() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+    fund(&mut app, &scheduler, 150);
+
+    let end = app.block_info().time.plus_seconds(DAY * 10);
+    for recipient in [ALICE, BOB] {
+        add_payment(
+            &mut app,
+            &scheduler,
+            recipient,
+            UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            100,
+            DAY,
+            end,
+        )
+        .unwrap();
+    }
+
+    // Only the first payment can be made.
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, ALICE), 100);
+    assert_eq!(native_balance(&app, BOB), 0);
+
+    let upcoming = query_upcoming(&app, &scheduler);
+    assert_eq!(upcoming[0].id, 1);
+    assert_eq!(upcoming[0].next_payment, app.block_info().time);
+    assert_eq!(upcoming[1].id, 0);
+
+    // Once funded the skipped payment is made.
+    fund(&mut app, &scheduler, 50);
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, BOB), 100);
+}
+
+#[test]
+fn test_execute_due_pagination() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+    fund(&mut app, &scheduler, 300);
+
+    let end = app.block_info().time.plus_seconds(DAY * 10);
+    for recipient in [ALICE, BOB, DAO] {
+        add_payment(
+            &mut app,
+            &scheduler,
+            recipient,
+            UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            100,
+            DAY * 20,
+            end,
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        Addr::unchecked(BOB),
+        scheduler.clone(),
+        &ExecuteMsg::ExecuteDue {
+            start_after: None,
+            limit: Some(2),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(native_balance(&app, ALICE), 100);
+    assert_eq!(native_balance(&app, BOB), 100);
+    assert_eq!(native_balance(&app, DAO), 0);
+    assert_eq!(
+        query_outflow(&app, &scheduler),
+        vec![OutflowResponse {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(100),
+        }]
+    );
+
+    app.execute_contract(
+        Addr::unchecked(BOB),
+        scheduler.clone(),
+        &ExecuteMsg::ExecuteDue {
+            start_after: Some(1),
+            limit: Some(2),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(native_balance(&app, DAO), 100);
+    assert!(query_outflow(&app, &scheduler).is_empty());
+}
+
+#[test]
+fn test_cw20_payment() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+
+    let cw20_id = app.store_code(cw20_contract());
+    let cw20 = app
+        .instantiate_contract(
+            cw20_id,
+            Addr::unchecked(DAO),
+            &cw20_base::msg::InstantiateMsg {
+                name: "token".to_string(),
+                symbol: "TOKEN".to_string(),
+                decimals: 6,
+                initial_balances: vec![Cw20Coin {
+                    address: scheduler.to_string(),
+                    amount: Uint128::new(1000),
+                }],
+                mint: None,
+                marketing: None,
+            },
+            &[],
+            "token",
+            None,
+        )
+        .unwrap();
+
+    let end = app.block_info().time.plus_seconds(DAY);
+    add_payment(
+        &mut app,
+        &scheduler,
+        ALICE,
+        UncheckedDenom::Cw20(cw20.to_string()),
+        10,
+        DAY,
+        end,
+    )
+    .unwrap();
+    execute_due(&mut app, &scheduler);
+
+    let balance: cw20::BalanceResponse = app
+        .wrap()
+        .query_wasm_smart(
+            &cw20,
+            &cw20::Cw20QueryMsg::Balance {
+                address: ALICE.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(balance.balance, Uint128::new(10));
+}
+
+#[test]
+fn test_remove_and_withdraw() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+    fund(&mut app, &scheduler, 1000);
+
+    let end = app.block_info().time.plus_seconds(DAY);
+    add_payment(
+        &mut app,
+        &scheduler,
+        ALICE,
+        UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+        100,
+        DAY,
+        end,
+    )
+    .unwrap();
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(ALICE),
+            scheduler.clone(),
+            &ExecuteMsg::RemovePayment { id: 0 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::Ownable(OwnershipError::NotOwner));
+
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        scheduler.clone(),
+        &ExecuteMsg::RemovePayment { id: 0 },
+        &[],
+    )
+    .unwrap();
+    execute_due(&mut app, &scheduler);
+    assert_eq!(native_balance(&app, ALICE), 0);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO),
+            scheduler.clone(),
+            &ExecuteMsg::RemovePayment { id: 0 },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NoSuchPayment { id: 0 });
+
+    app.execute_contract(
+        Addr::unchecked(DAO),
+        scheduler.clone(),
+        &ExecuteMsg::Withdraw {
+            denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(native_balance(&app, DAO), 1000);
+
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(DAO),
+            scheduler,
+            &ExecuteMsg::Withdraw {
+                denom: UncheckedDenom::Native(NATIVE_DENOM.to_string()),
+                amount: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::NothingToWithdraw {});
+}
+
+#[test]
+fn test_add_payment_validation() {
+    let mut app = App::default();
+    let scheduler = setup(&mut app);
+    let now = app.block_info().time;
+    let denom = UncheckedDenom::Native(NATIVE_DENOM.to_string());
+
+    let err: ContractError = add_payment(&mut app, &scheduler, ALICE, denom.clone(), 0, DAY, now)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroAmount {});
+
+    let err: ContractError = add_payment(&mut app, &scheduler, ALICE, denom.clone(), 1, 0, now)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, ContractError::ZeroInterval {});
+
+    let err: ContractError = add_payment(
+        &mut app,
+        &scheduler,
+        ALICE,
+        denom,
+        1,
+        DAY,
+        now.minus_seconds(1),
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap();
+    assert_eq!(
+        err,
+        ContractError::EndBeforeStart {
+            start: now.seconds(),
+            end: now.seconds() - 1,
+        }
+    );
+}
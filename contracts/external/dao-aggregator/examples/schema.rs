@@ -0,0 +1,9 @@
+use cosmwasm_schema::write_api;
+use dao_aggregator::msg::{InstantiateMsg, QueryMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        query: QueryMsg,
+    }
+}
@@ -0,0 +1,228 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, to_vec, Addr, Binary, ContractResult, Deps, DepsMut, Empty, Env,
+    MessageInfo, QuerierWrapper, QueryRequest, Response, StdError, StdResult, SystemResult,
+    WasmQuery,
+};
+use cw2::set_contract_version;
+use dao_core::{query::DumpStateResponse, state::ProposalModule};
+use dao_interface::voting::{InfoResponse, TotalPowerAtHeightResponse};
+use dao_voting::pre_propose::ProposalCreationPolicy;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ContractError;
+use crate::msg::{
+    DaoStateResponse, InstantiateMsg, OpenProposal, ProposalModuleState, QueryMsg,
+    VotingModuleState,
+};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-aggregator";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const DEFAULT_SCAN_LIMIT: u64 = 10;
+pub const MAX_SCAN_LIMIT: u64 = 30;
+
+/// Queries common to proposal modules. Serializes identically to the
+/// corresponding variants of each proposal module's query message.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ProposalModuleQuery {
+    Info {},
+    Config {},
+    ProposalCount {},
+    ProposalCreationPolicy {},
+    Proposal {
+        proposal_id: u64,
+    },
+    ReverseProposals {
+        start_before: Option<u64>,
+        limit: Option<u64>,
+    },
+}
+
+/// Queries common to voting modules.
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum VotingModuleQuery {
+    Info {},
+    TotalPowerAtHeight { height: Option<u64> },
+}
+
+/// Parses only the parts of a proposal list response needed to find
+/// open proposals. Unknown fields are ignored so that this may be
+/// used with any proposal module.
+#[derive(Deserialize)]
+struct ProposalList {
+    proposals: Vec<ProposalListItem>,
+}
+
+#[derive(Deserialize)]
+struct ProposalListItem {
+    id: u64,
+    proposal: ProposalStatus,
+}
+
+#[derive(Deserialize)]
+struct ProposalStatus {
+    status: String,
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    _msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::DaoState {
+            dao,
+            open_proposal_scan_limit,
+        } => to_binary(&query_dao_state(deps, dao, open_proposal_scan_limit)?),
+    }
+}
+
+/// Performs a smart query and returns the raw response without
+/// parsing it.
+fn query_raw<T: Serialize>(
+    querier: &QuerierWrapper,
+    contract: &Addr,
+    msg: &T,
+) -> StdResult<Binary> {
+    let request: QueryRequest<Empty> = WasmQuery::Smart {
+        contract_addr: contract.to_string(),
+        msg: to_binary(msg)?,
+    }
+    .into();
+    match querier.raw_query(&to_vec(&request)?) {
+        SystemResult::Err(system_err) => Err(StdError::generic_err(format!(
+            "Querier system error: {system_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Err(contract_err)) => Err(StdError::generic_err(format!(
+            "Querier contract error: {contract_err}"
+        ))),
+        SystemResult::Ok(ContractResult::Ok(value)) => Ok(value),
+    }
+}
+
+pub fn query_dao_state(
+    deps: Deps,
+    dao: String,
+    open_proposal_scan_limit: Option<u64>,
+) -> StdResult<DaoStateResponse> {
+    let dao = deps.api.addr_validate(&dao)?;
+    let scan_limit = open_proposal_scan_limit
+        .unwrap_or(DEFAULT_SCAN_LIMIT)
+        .min(MAX_SCAN_LIMIT);
+
+    let core: DumpStateResponse = deps
+        .querier
+        .query_wasm_smart(&dao, &dao_core::msg::QueryMsg::DumpState {})?;
+
+    let voting_module = query_voting_module(deps, core.voting_module.clone());
+    let proposal_modules = core
+        .proposal_modules
+        .iter()
+        .map(|module| query_proposal_module(deps, module.clone(), scan_limit))
+        .collect();
+
+    Ok(DaoStateResponse {
+        core,
+        voting_module,
+        proposal_modules,
+    })
+}
+
+fn query_voting_module(deps: Deps, address: Addr) -> VotingModuleState {
+    let info: Option<InfoResponse> = deps
+        .querier
+        .query_wasm_smart(&address, &VotingModuleQuery::Info {})
+        .ok();
+    let total_power: Option<TotalPowerAtHeightResponse> = deps
+        .querier
+        .query_wasm_smart(
+            &address,
+            &VotingModuleQuery::TotalPowerAtHeight { height: None },
+        )
+        .ok();
+    VotingModuleState {
+        address,
+        info: info.map(|i| i.info),
+        total_power,
+    }
+}
+
+fn query_proposal_module(
+    deps: Deps,
+    module: ProposalModule,
+    scan_limit: u64,
+) -> ProposalModuleState {
+    let address = &module.address;
+    let querier = &deps.querier;
+
+    let info: Option<InfoResponse> = querier
+        .query_wasm_smart(address, &ProposalModuleQuery::Info {})
+        .ok();
+    let config = query_raw(querier, address, &ProposalModuleQuery::Config {}).ok();
+    let proposal_count: Option<u64> = querier
+        .query_wasm_smart(address, &ProposalModuleQuery::ProposalCount {})
+        .ok();
+    let creation_policy: Option<ProposalCreationPolicy> = querier
+        .query_wasm_smart(address, &ProposalModuleQuery::ProposalCreationPolicy {})
+        .ok();
+    let pre_propose_config = match creation_policy {
+        Some(ProposalCreationPolicy::Module { ref addr }) => {
+            query_raw(querier, addr, &ProposalModuleQuery::Config {}).ok()
+        }
+        _ => None,
+    };
+    let open_proposals = query_open_proposals(querier, address, scan_limit).unwrap_or_default();
+
+    ProposalModuleState {
+        info: info.map(|i| i.info),
+        config,
+        proposal_count,
+        creation_policy,
+        pre_propose_config,
+        open_proposals,
+        module,
+    }
+}
+
+fn query_open_proposals(
+    querier: &QuerierWrapper,
+    address: &Addr,
+    scan_limit: u64,
+) -> StdResult<Vec<OpenProposal>> {
+    let proposals: ProposalList = from_binary(&query_raw(
+        querier,
+        address,
+        &ProposalModuleQuery::ReverseProposals {
+            start_before: None,
+            limit: Some(scan_limit),
+        },
+    )?)?;
+    proposals
+        .proposals
+        .into_iter()
+        .filter(|p| p.proposal.status == "open")
+        .map(|p| {
+            Ok(OpenProposal {
+                id: p.id,
+                proposal: query_raw(
+                    querier,
+                    address,
+                    &ProposalModuleQuery::Proposal { proposal_id: p.id },
+                )?,
+            })
+        })
+        .collect()
+}
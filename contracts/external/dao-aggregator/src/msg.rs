@@ -0,0 +1,63 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Addr, Binary};
+use cw2::ContractVersion;
+use dao_core::{query::DumpStateResponse, state::ProposalModule};
+use dao_interface::voting::TotalPowerAtHeightResponse;
+use dao_voting::pre_propose::ProposalCreationPolicy;
+
+#[cw_serde]
+pub struct InstantiateMsg {}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the state of DAO and all of its modules.
+    #[returns(DaoStateResponse)]
+    DaoState {
+        dao: String,
+        /// The number of most recent proposals of each proposal
+        /// module to search for open proposals. Defaults to
+        /// `DEFAULT_SCAN_LIMIT` and may not exceed `MAX_SCAN_LIMIT`.
+        open_proposal_scan_limit: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub struct DaoStateResponse {
+    /// The result of the DAO's `DumpState` query.
+    pub core: DumpStateResponse,
+    pub voting_module: VotingModuleState,
+    /// The state of every proposal module, in the order returned by
+    /// `DumpState`.
+    pub proposal_modules: Vec<ProposalModuleState>,
+}
+
+#[cw_serde]
+pub struct VotingModuleState {
+    pub address: Addr,
+    pub info: Option<ContractVersion>,
+    /// The total voting power at the current height.
+    pub total_power: Option<TotalPowerAtHeightResponse>,
+}
+
+#[cw_serde]
+pub struct ProposalModuleState {
+    pub module: ProposalModule,
+    pub info: Option<ContractVersion>,
+    /// The JSON response to the module's `Config {}` query.
+    pub config: Option<Binary>,
+    pub proposal_count: Option<u64>,
+    pub creation_policy: Option<ProposalCreationPolicy>,
+    /// The JSON response to the pre-propose module's `Config {}`
+    /// query, if the module has a pre-propose module.
+    pub pre_propose_config: Option<Binary>,
+    /// The JSON responses to the module's `Proposal { proposal_id }`
+    /// query for each open proposal found, most recent first.
+    pub open_proposals: Vec<OpenProposal>,
+}
+
+#[cw_serde]
+pub struct OpenProposal {
+    pub id: u64,
+    pub proposal: Binary,
+}
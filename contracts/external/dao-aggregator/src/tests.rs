@@ -0,0 +1,206 @@
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, DepsMut, Empty, Env, MessageInfo, Response, Uint128,
+};
+use cw20::Cw20Coin;
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_testing::{
+    contracts::{pre_propose_single_contract, proposal_single_contract},
+    helpers::instantiate_with_cw4_groups_governance,
+};
+use dao_voting::{
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    status::Status,
+    threshold::{PercentageThreshold, Threshold},
+};
+
+use crate::msg::{DaoStateResponse, InstantiateMsg, QueryMsg};
+
+const CREATOR_ADDR: &str = "creator";
+
+// The aggregator has no execute entry point.
+fn execute(
+    _deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    _msg: Empty,
+) -> Result<Response, crate::ContractError> {
+    unimplemented!()
+}
+
+fn aggregator_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn setup(app: &mut App) -> (Addr, Addr) {
+    let proposal_id = app.store_code(proposal_single_contract());
+    let pre_propose_id = app.store_code(pre_propose_single_contract());
+    let aggregator_id = app.store_code(aggregator_contract());
+
+    let dao = instantiate_with_cw4_groups_governance(
+        app,
+        proposal_id,
+        to_binary(&dao_proposal_single::msg::InstantiateMsg {
+            threshold: Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Majority {},
+            },
+            max_voting_period: Duration::Time(86400),
+            min_voting_period: None,
+            only_members_execute: true,
+            allow_revoting: false,
+            pre_propose_info: PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: pre_propose_id,
+                    msg: to_binary(&dao_pre_propose_single::InstantiateMsg {
+                        deposit_info: None,
+                        open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
+                        extension: Empty::default(),
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    label: "pre-propose".to_string(),
+                },
+            },
+            close_proposal_on_execution_failure: true,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
+        })
+        .unwrap(),
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(1),
+        }]),
+    );
+
+    let aggregator = app
+        .instantiate_contract(
+            aggregator_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &InstantiateMsg {},
+            &[],
+            "aggregator",
+            None,
+        )
+        .unwrap();
+
+    (dao, aggregator)
+}
+
+fn query_dao_state(app: &App, aggregator: &Addr, dao: &Addr) -> DaoStateResponse {
+    app.wrap()
+        .query_wasm_smart(
+            aggregator,
+            &QueryMsg::DaoState {
+                dao: dao.to_string(),
+                open_proposal_scan_limit: None,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_dao_state() {
+    let mut app = App::default();
+    let (dao, aggregator) = setup(&mut app);
+
+    let state = query_dao_state(&app, &aggregator, &dao);
+    assert_eq!(state.core.version.contract, "crates.io:dao-core");
+    assert_eq!(
+        state.voting_module.info.unwrap().contract,
+        "crates.io:dao-voting-cw4"
+    );
+    assert_eq!(
+        state.voting_module.total_power.unwrap().power,
+        Uint128::new(1)
+    );
+
+    assert_eq!(state.proposal_modules.len(), 1);
+    let module = &state.proposal_modules[0];
+    assert_eq!(
+        module.info.as_ref().unwrap().contract,
+        "crates.io:dao-proposal-single"
+    );
+    assert_eq!(module.proposal_count, Some(0));
+    assert!(module.open_proposals.is_empty());
+
+    let config: dao_proposal_single::state::Config =
+        from_binary(module.config.as_ref().unwrap()).unwrap();
+    assert_eq!(config.dao, dao);
+
+    let pre_propose = match module.creation_policy {
+        Some(ProposalCreationPolicy::Module { ref addr }) => addr.clone(),
+        _ => panic!("expected a pre-propose module"),
+    };
+    let pre_propose_config: dao_pre_propose_single::Config =
+        from_binary(module.pre_propose_config.as_ref().unwrap()).unwrap();
+    assert_eq!(pre_propose_config.deposit_info, None);
+
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        pre_propose,
+        &dao_pre_propose_single::ExecuteMsg::Propose {
+            msg: dao_pre_propose_single::ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                depends_on: None,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let state = query_dao_state(&app, &aggregator, &dao);
+    let module = &state.proposal_modules[0];
+    assert_eq!(module.proposal_count, Some(1));
+    assert_eq!(module.open_proposals.len(), 1);
+    assert_eq!(module.open_proposals[0].id, 1);
+    let proposal: dao_proposal_single::query::ProposalResponse =
+        from_binary(&module.open_proposals[0].proposal).unwrap();
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    // Voting passes the proposal so it is no longer open.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        module.module.address.clone(),
+        &dao_proposal_single::msg::ExecuteMsg::Vote {
+            proposal_id: 1,
+            vote: dao_voting::voting::Vote::Yes,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let state = query_dao_state(&app, &aggregator, &dao);
+    assert!(state.proposal_modules[0].open_proposals.is_empty());
+}
+
+#[test]
+fn test_invalid_dao() {
+    let mut app = App::default();
+    let (_, aggregator) = setup(&mut app);
+    app.wrap()
+        .query_wasm_smart::<DaoStateResponse>(
+            &aggregator,
+            &QueryMsg::DaoState {
+                dao: aggregator.to_string(),
+                open_proposal_scan_limit: None,
+            },
+        )
+        .unwrap_err();
+}
@@ -0,0 +1,356 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, instantiate2_address, to_binary, Addr, Api, Binary, Coin, CosmosMsg, Deps,
+    DepsMut, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, SubMsg, WasmMsg,
+};
+use cw2::set_contract_version;
+use cw20::Cw20ReceiveMsg;
+use cw_denom::CheckedDenom;
+use cw_storage_plus::Bound;
+use cw_utils::{nonpayable, parse_reply_instantiate_data};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg, UncheckedFee};
+use crate::state::{daos, Dao, Fee, DAO_COUNT, FEE, TMP_DAO_INFO};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-factory";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const INSTANTIATE_DAO_REPLY_ID: u64 = 0;
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+/// The maximum length of a salt provided by a DAO's creator. The
+/// salt used for `Instantiate2` is the creator's canonical address
+/// followed by this salt and may be at most 64 bytes long.
+pub const MAX_SALT_LENGTH: usize = 32;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let fee = msg
+        .fee
+        .map(|fee| check_fee(deps.as_ref(), fee))
+        .transpose()?;
+    FEE.save(deps.storage, &fee)?;
+    DAO_COUNT.save(deps.storage, &0)?;
+    Ok(Response::new()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::InstantiateDao {
+            code_id,
+            instantiate_msg,
+            label,
+            salt,
+        } => execute_instantiate_native(deps, env, info, code_id, instantiate_msg, label, salt),
+        ExecuteMsg::Receive(msg) => execute_receive_cw20(deps, env, info, msg),
+        ExecuteMsg::UpdateFee { fee } => execute_update_fee(deps, info, fee),
+        ExecuteMsg::UpdateOwnership(action) => {
+            let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+            Ok(Response::default().add_attributes(ownership.into_attributes()))
+        }
+    }
+}
+
+fn check_fee(deps: Deps, fee: UncheckedFee) -> Result<Fee, ContractError> {
+    if fee.amount.is_zero() {
+        return Err(ContractError::ZeroFee {});
+    }
+    Ok(Fee {
+        denom: fee.denom.into_checked(deps)?,
+        amount: fee.amount,
+        recipient: deps.api.addr_validate(&fee.recipient)?,
+    })
+}
+
+/// Gets the salt used to instantiate a DAO created by CREATOR with
+/// SALT. Prefixing the salt with the creator's address prevents
+/// others from front-running the creation of a DAO at an address.
+pub fn dao_salt(api: &dyn Api, creator: &Addr, salt: &Binary) -> Result<Binary, ContractError> {
+    if salt.is_empty() || salt.len() > MAX_SALT_LENGTH {
+        return Err(ContractError::InvalidSalt {
+            max: MAX_SALT_LENGTH,
+        });
+    }
+    let mut dao_salt = api.addr_canonicalize(creator.as_str())?.to_vec();
+    dao_salt.extend_from_slice(salt.as_slice());
+    Ok(Binary::from(dao_salt))
+}
+
+pub fn execute_instantiate_native(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    code_id: u64,
+    instantiate_msg: Binary,
+    label: String,
+    salt: Binary,
+) -> Result<Response, ContractError> {
+    let mut funds = info.funds;
+    let fee = match FEE.load(deps.storage)? {
+        None => None,
+        Some(Fee {
+            denom,
+            amount,
+            recipient,
+        }) => {
+            let paid = match denom {
+                CheckedDenom::Native(ref denom) => funds.iter_mut().find(|c| c.denom == *denom),
+                // cw20 fees must be paid via `Receive`.
+                CheckedDenom::Cw20(_) => None,
+            };
+            match paid {
+                Some(coin) if coin.amount >= amount => coin.amount -= amount,
+                paid => {
+                    return Err(ContractError::WrongFee {
+                        denom: denom.to_string(),
+                        expected: amount,
+                        actual: paid.map(|c| c.amount).unwrap_or_default(),
+                    })
+                }
+            }
+            Some(denom.get_transfer_to_message(&recipient, amount)?)
+        }
+    };
+    funds.retain(|c| !c.amount.is_zero());
+
+    instantiate_dao(
+        deps,
+        env,
+        info.sender,
+        funds,
+        fee,
+        code_id,
+        instantiate_msg,
+        label,
+        salt,
+    )
+}
+
+pub fn execute_receive_cw20(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive_msg: Cw20ReceiveMsg,
+) -> Result<Response, ContractError> {
+    // Only accepts cw20 tokens.
+    nonpayable(&info)?;
+
+    let fee = match FEE.load(deps.storage)? {
+        Some(fee) if fee.denom.is_cw20(&info.sender) => fee,
+        _ => {
+            return Err(ContractError::UnexpectedCw20 {
+                token: info.sender.into_string(),
+            })
+        }
+    };
+    if receive_msg.amount != fee.amount {
+        return Err(ContractError::WrongFee {
+            denom: fee.denom.to_string(),
+            expected: fee.amount,
+            actual: receive_msg.amount,
+        });
+    }
+    let fee_msg = fee
+        .denom
+        .get_transfer_to_message(&fee.recipient, fee.amount)?;
+
+    let creator = deps.api.addr_validate(&receive_msg.sender)?;
+    match from_binary(&receive_msg.msg)? {
+        ReceiveMsg::InstantiateDao {
+            code_id,
+            instantiate_msg,
+            label,
+            salt,
+        } => instantiate_dao(
+            deps,
+            env,
+            creator,
+            vec![],
+            Some(fee_msg),
+            code_id,
+            instantiate_msg,
+            label,
+            salt,
+        ),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn instantiate_dao(
+    deps: DepsMut,
+    env: Env,
+    creator: Addr,
+    funds: Vec<Coin>,
+    fee: Option<CosmosMsg>,
+    code_id: u64,
+    instantiate_msg: Binary,
+    label: String,
+    salt: Binary,
+) -> Result<Response, ContractError> {
+    if TMP_DAO_INFO.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::Reentrancy);
+    }
+    let salt = dao_salt(deps.api, &creator, &salt)?;
+
+    // Saved for use in the reply, where the DAO's address is known.
+    let id = DAO_COUNT.load(deps.storage)?;
+    TMP_DAO_INFO.save(
+        deps.storage,
+        &Dao {
+            id,
+            address: String::new(),
+            creator: creator.to_string(),
+            code_id,
+            label: label.clone(),
+        },
+    )?;
+
+    // The factory is the initial admin and makes the DAO its own
+    // admin in the reply.
+    let instantiate = WasmMsg::Instantiate2 {
+        admin: Some(env.contract.address.into_string()),
+        code_id,
+        label,
+        msg: instantiate_msg,
+        funds,
+        salt,
+    };
+
+    Ok(Response::default()
+        .add_attribute("action", "instantiate_dao")
+        .add_attribute("creator", creator)
+        .add_attribute("code_id", code_id.to_string())
+        .add_messages(fee)
+        .add_submessage(SubMsg::reply_on_success(
+            instantiate,
+            INSTANTIATE_DAO_REPLY_ID,
+        )))
+}
+
+pub fn execute_update_fee(
+    deps: DepsMut,
+    info: MessageInfo,
+    fee: Option<UncheckedFee>,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+    let fee = fee.map(|fee| check_fee(deps.as_ref(), fee)).transpose()?;
+    FEE.save(deps.storage, &fee)?;
+    Ok(Response::default()
+        .add_attribute("action", "update_fee")
+        .add_attribute(
+            "fee",
+            fee.map(|f| format!("{} {}", f.amount, f.denom))
+                .unwrap_or_else(|| "none".to_string()),
+        ))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::ListDaos { start_after, limit } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.map(Bound::exclusive);
+
+            let res = daos()
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|d| d.map(|(_, d)| d))
+                .collect::<StdResult<Vec<Dao>>>()?;
+
+            to_binary(&res)
+        }
+        QueryMsg::ListDaosByCreator {
+            creator,
+            start_after,
+            limit,
+        } => {
+            let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+            let start = start_after.map(Bound::<u64>::exclusive);
+
+            // Validate creator address
+            deps.api.addr_validate(&creator)?;
+
+            let res = daos()
+                .idx
+                .creator
+                .prefix(creator)
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|d| d.map(|(_, d)| d))
+                .collect::<StdResult<Vec<Dao>>>()?;
+
+            to_binary(&res)
+        }
+        QueryMsg::DaoAddress {
+            creator,
+            code_id,
+            salt,
+        } => to_binary(&query_dao_address(deps, env, creator, code_id, salt)?),
+        QueryMsg::Fee {} => to_binary(&FEE.load(deps.storage)?),
+        QueryMsg::Ownership {} => to_binary(&cw_ownable::get_ownership(deps.storage)?),
+    }
+}
+
+pub fn query_dao_address(
+    deps: Deps,
+    env: Env,
+    creator: String,
+    code_id: u64,
+    salt: Binary,
+) -> StdResult<Addr> {
+    let creator = deps.api.addr_validate(&creator)?;
+    let salt =
+        dao_salt(deps.api, &creator, &salt).map_err(|e| StdError::generic_err(e.to_string()))?;
+    let checksum = deps.querier.query_wasm_code_info(code_id)?.checksum;
+    let address = instantiate2_address(
+        checksum.as_slice(),
+        &deps.api.addr_canonicalize(env.contract.address.as_str())?,
+        salt.as_slice(),
+    )
+    .map_err(|e| StdError::generic_err(e.to_string()))?;
+    deps.api.addr_humanize(&address)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        INSTANTIATE_DAO_REPLY_ID => {
+            let res = parse_reply_instantiate_data(msg)?;
+            let contract_addr = deps.api.addr_validate(&res.contract_address)?;
+
+            let mut dao = TMP_DAO_INFO.load(deps.storage)?;
+            TMP_DAO_INFO.remove(deps.storage);
+            dao.address = contract_addr.to_string();
+            daos().save(deps.storage, dao.id, &dao)?;
+            DAO_COUNT.save(deps.storage, &(dao.id + 1))?;
+
+            // Make the DAO its own admin.
+            let msg = WasmMsg::UpdateAdmin {
+                contract_addr: contract_addr.to_string(),
+                admin: contract_addr.to_string(),
+            };
+
+            Ok(Response::default()
+                .add_attribute("dao", contract_addr)
+                .add_attribute("dao_id", dao.id.to_string())
+                .add_message(msg))
+        }
+        _ => Err(ContractError::UnknownReplyId { id: msg.id }),
+    }
+}
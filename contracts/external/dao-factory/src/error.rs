@@ -0,0 +1,45 @@
+use cosmwasm_std::{StdError, Uint128};
+use cw_denom::DenomError;
+use cw_ownable::OwnershipError;
+use cw_utils::{ParseReplyError, PaymentError};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownable(#[from] OwnershipError),
+
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+
+    #[error(transparent)]
+    Payment(#[from] PaymentError),
+
+    #[error(transparent)]
+    ParseReply(#[from] ParseReplyError),
+
+    #[error("salt must be between 1 and {max} bytes long")]
+    InvalidSalt { max: usize },
+
+    #[error("fee must be non-zero")]
+    ZeroFee {},
+
+    #[error("a fee of ({expected}) ({denom}) must be paid to create a DAO, got ({actual})")]
+    WrongFee {
+        denom: String,
+        expected: Uint128,
+        actual: Uint128,
+    },
+
+    #[error("this factory does not accept cw20 tokens from ({token})")]
+    UnexpectedCw20 { token: String },
+
+    #[error("reentered factory during DAO instantiation")]
+    Reentrancy,
+
+    #[error("Got a submessage reply with unknown id: {id}")]
+    UnknownReplyId { id: u64 },
+}
@@ -0,0 +1,85 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Uint128};
+use cw20::Cw20ReceiveMsg;
+use cw_denom::UncheckedDenom;
+use cw_ownable::cw_ownable_execute;
+
+/// A fee that must be paid to create a DAO.
+#[cw_serde]
+pub struct UncheckedFee {
+    pub denom: UncheckedDenom,
+    pub amount: Uint128,
+    /// The address fees are sent to.
+    pub recipient: String,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The owner of this contract. Only the owner may update the fee.
+    pub owner: Option<String>,
+    /// The fee that must be paid to create a DAO, if any.
+    pub fee: Option<UncheckedFee>,
+}
+
+#[cw_ownable_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Instantiates a DAO, paying the fee in native tokens if there
+    /// is one. Funds sent beyond the fee are sent to the DAO.
+    InstantiateDao {
+        code_id: u64,
+        instantiate_msg: Binary,
+        label: String,
+        /// Combined with the sender's address to determine the
+        /// address of the new DAO. At most `MAX_SALT_LENGTH` bytes.
+        salt: Binary,
+    },
+    /// Instantiates a DAO, paying the fee in cw20 tokens. The `msg`
+    /// field should be a `ReceiveMsg`.
+    Receive(Cw20ReceiveMsg),
+    /// Callable only by the owner. Updates the fee to create a DAO.
+    UpdateFee { fee: Option<UncheckedFee> },
+}
+
+#[cw_serde]
+pub enum ReceiveMsg {
+    /// Instantiates a DAO, paying the fee in cw20 tokens.
+    InstantiateDao {
+        code_id: u64,
+        instantiate_msg: Binary,
+        label: String,
+        salt: Binary,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Lists DAOs created by this factory in the order they were
+    /// created.
+    #[returns(Vec<crate::state::Dao>)]
+    ListDaos {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Lists DAOs created by CREATOR in the order they were created.
+    #[returns(Vec<crate::state::Dao>)]
+    ListDaosByCreator {
+        creator: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the address a DAO will be created at if CREATOR
+    /// instantiates CODE_ID with SALT.
+    #[returns(::cosmwasm_std::Addr)]
+    DaoAddress {
+        creator: String,
+        code_id: u64,
+        salt: Binary,
+    },
+    /// Gets the fee to create a DAO, if any.
+    #[returns(Option<crate::state::Fee>)]
+    Fee {},
+    #[returns(::cw_ownable::Ownership::<::cosmwasm_std::Addr>)]
+    Ownership {},
+}
@@ -0,0 +1,55 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Uint128};
+use cw_denom::CheckedDenom;
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, MultiIndex};
+
+/// A fee that must be paid to create a DAO.
+#[cw_serde]
+pub struct Fee {
+    pub denom: CheckedDenom,
+    pub amount: Uint128,
+    /// The address fees are sent to.
+    pub recipient: Addr,
+}
+
+/// A DAO created by this factory.
+#[cw_serde]
+pub struct Dao {
+    /// The order in which this DAO was created, starting at zero.
+    pub id: u64,
+    pub address: String,
+    pub creator: String,
+    pub code_id: u64,
+    pub label: String,
+}
+
+/// The fee to create a DAO, if any.
+pub const FEE: Item<Option<Fee>> = Item::new("fee");
+/// The ID of the next DAO to be created.
+pub const DAO_COUNT: Item<u64> = Item::new("dao_count");
+/// Holds information about the DAO being instantiated for use in the
+/// instantiation reply.
+pub const TMP_DAO_INFO: Item<Dao> = Item::new("tmp_dao_info");
+
+pub struct DaoIndexes<'a> {
+    pub creator: MultiIndex<'a, String, Dao, u64>,
+}
+
+impl<'a> IndexList<Dao> for DaoIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<Dao>> + '_> {
+        let v: Vec<&dyn Index<Dao>> = vec![&self.creator];
+        Box::new(v.into_iter())
+    }
+}
+
+/// DAO ID -> DAO, indexed by creator.
+pub fn daos<'a>() -> IndexedMap<'a, u64, Dao, DaoIndexes<'a>> {
+    let indexes = DaoIndexes {
+        creator: MultiIndex::new(
+            |_pk: &[u8], d: &Dao| d.creator.clone(),
+            "daos",
+            "daos__creator",
+        ),
+    };
+    IndexedMap::new("daos", indexes)
+}
@@ -0,0 +1,537 @@
+use std::marker::PhantomData;
+
+use cosmwasm_std::{
+    coin, coins, from_binary, instantiate2_address,
+    testing::{mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage},
+    to_binary, Addr, Api, BankMsg, Binary, CanonicalAddr, ContractResult, CosmosMsg, HexBinary,
+    OwnedDeps, RecoverPubkeyError, Reply, StdResult, SubMsg, SubMsgResponse, SubMsgResult,
+    SystemResult, Uint128, VerificationError, WasmMsg, WasmQuery,
+};
+use cw20::Cw20ReceiveMsg;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_ownable::OwnershipError;
+
+use crate::{
+    contract::{execute, instantiate, query, reply, INSTANTIATE_DAO_REPLY_ID, MAX_SALT_LENGTH},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, ReceiveMsg, UncheckedFee},
+    state::{Dao, Fee},
+    ContractError,
+};
+
+const OWNER: &str = "owner";
+const CREATOR: &str = "creator";
+const TREASURY: &str = "treasury";
+const DENOM: &str = "ujuno";
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+fn setup(fee: Option<UncheckedFee>) -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[]),
+        InstantiateMsg {
+            owner: Some(OWNER.to_string()),
+            fee,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn native_fee(amount: u128) -> Option<UncheckedFee> {
+    Some(UncheckedFee {
+        denom: UncheckedDenom::Native(DENOM.to_string()),
+        amount: Uint128::new(amount),
+        recipient: TREASURY.to_string(),
+    })
+}
+
+fn instantiate_dao_msg(salt: &[u8]) -> ExecuteMsg {
+    ExecuteMsg::InstantiateDao {
+        code_id: 1,
+        instantiate_msg: to_binary("dao").unwrap(),
+        label: "dao".to_string(),
+        salt: Binary::from(salt),
+    }
+}
+
+fn expected_salt(deps: &Deps, creator: &str, salt: &[u8]) -> Binary {
+    let mut expected = deps.api.addr_canonicalize(creator).unwrap().to_vec();
+    expected.extend_from_slice(salt);
+    Binary::from(expected)
+}
+
+/// Replies to the instantiation of a DAO with address ADDRESS, which
+/// must be nine bytes long.
+fn reply_instantiated(deps: &mut Deps, address: &str) {
+    let mut data = vec![10, 9];
+    data.extend_from_slice(address.as_bytes());
+    reply(
+        deps.as_mut(),
+        mock_env(),
+        Reply {
+            id: INSTANTIATE_DAO_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(data)),
+            }),
+        },
+    )
+    .unwrap();
+}
+
+fn list_daos(deps: &Deps, creator: Option<&str>) -> Vec<Dao> {
+    let msg = match creator {
+        Some(creator) => QueryMsg::ListDaosByCreator {
+            creator: creator.to_string(),
+            start_after: None,
+            limit: None,
+        },
+        None => QueryMsg::ListDaos {
+            start_after: None,
+            limit: None,
+        },
+    };
+    from_binary(&query(deps.as_ref(), mock_env(), msg).unwrap()).unwrap()
+}
+
+#[test]
+fn test_instantiate_dao() {
+    let mut deps = setup(None);
+    let env = mock_env();
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CREATOR, &coins(10, DENOM)),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_on_success(
+            WasmMsg::Instantiate2 {
+                admin: Some(env.contract.address.to_string()),
+                code_id: 1,
+                label: "dao".to_string(),
+                msg: to_binary("dao").unwrap(),
+                funds: coins(10, DENOM),
+                salt: expected_salt(&deps, CREATOR, b"salt"),
+            },
+            INSTANTIATE_DAO_REPLY_ID
+        )]
+    );
+
+    // Can't create another DAO while one is being created.
+    let err = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CREATOR, &[]),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Reentrancy);
+
+    reply_instantiated(&mut deps, "contract1");
+
+    execute(
+        deps.as_mut(),
+        env,
+        mock_info("other", &[]),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap();
+    reply_instantiated(&mut deps, "contract2");
+
+    let first = Dao {
+        id: 0,
+        address: "contract1".to_string(),
+        creator: CREATOR.to_string(),
+        code_id: 1,
+        label: "dao".to_string(),
+    };
+    let second = Dao {
+        id: 1,
+        address: "contract2".to_string(),
+        creator: "other".to_string(),
+        code_id: 1,
+        label: "dao".to_string(),
+    };
+    assert_eq!(list_daos(&deps, None), vec![first.clone(), second.clone()]);
+    assert_eq!(list_daos(&deps, Some(CREATOR)), vec![first]);
+    assert_eq!(list_daos(&deps, Some("other")), vec![second.clone()]);
+
+    let page: Vec<Dao> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListDaos {
+                start_after: Some(0),
+                limit: Some(1),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(page, vec![second]);
+}
+
+#[test]
+fn test_invalid_salt() {
+    let mut deps = setup(None);
+    for salt in [vec![], vec![0; MAX_SALT_LENGTH + 1]] {
+        let err = execute(
+            deps.as_mut(),
+            mock_env(),
+            mock_info(CREATOR, &[]),
+            instantiate_dao_msg(&salt),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::InvalidSalt {
+                max: MAX_SALT_LENGTH
+            }
+        );
+    }
+}
+
+/// `MockApi` pads canonical addresses to 90 bytes, which makes DAO
+/// salts too long for `instantiate2_address`, and can not humanize
+/// the 32 byte addresses it produces. Like a real chain, this uses
+/// short canonical addresses: the bytes of the human readable address,
+/// or hex for instantiate2 addresses.
+#[derive(Clone, Copy, Default)]
+struct Instantiate2Api(MockApi);
+
+impl Api for Instantiate2Api {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.addr_canonicalize(human)?;
+        Ok(Addr::unchecked(human))
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        if human.len() > 32 {
+            Ok(CanonicalAddr::from(HexBinary::from_hex(human)?))
+        } else {
+            Ok(CanonicalAddr::from(human.as_bytes()))
+        }
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        if canonical.len() == 32 {
+            Ok(Addr::unchecked(
+                HexBinary::from(canonical.as_slice()).to_hex(),
+            ))
+        } else {
+            Ok(Addr::unchecked(String::from_utf8_lossy(
+                canonical.as_slice(),
+            )))
+        }
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.0
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.0
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.0.debug(message)
+    }
+}
+
+#[test]
+fn test_predicted_dao_address() {
+    const CHECKSUM: &str = "13a1fc994cc6d1c81b746ee0c0ff6f90043875e0bf1d9be6b7d779fc978dc2a5";
+
+    let mut deps = OwnedDeps {
+        storage: MockStorage::default(),
+        api: Instantiate2Api::default(),
+        querier: MockQuerier::default(),
+        custom_query_type: PhantomData,
+    };
+    deps.querier.update_wasm(|query| match query {
+        WasmQuery::CodeInfo { code_id: 1 } => SystemResult::Ok(ContractResult::Ok(Binary::from(
+            format!(r#"{{"code_id":1,"creator":"creator","checksum":"{CHECKSUM}"}}"#).into_bytes(),
+        ))),
+        _ => panic!("unexpected query"),
+    });
+    let env = mock_env();
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(OWNER, &[]),
+        InstantiateMsg {
+            owner: Some(OWNER.to_string()),
+            fee: None,
+        },
+    )
+    .unwrap();
+
+    let predicted: Addr = from_binary(
+        &query(
+            deps.as_ref(),
+            env.clone(),
+            QueryMsg::DaoAddress {
+                creator: CREATOR.to_string(),
+                code_id: 1,
+                salt: Binary::from(b"salt"),
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(CREATOR, &[]),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap();
+    let salt = match &res.messages[0].msg {
+        CosmosMsg::Wasm(WasmMsg::Instantiate2 { salt, .. }) => salt.clone(),
+        _ => panic!("expected an instantiate2 message"),
+    };
+
+    // The chain derives the DAO's address from the code's checksum,
+    // the instantiating contract, and the salt.
+    let instantiated = instantiate2_address(
+        HexBinary::from_hex(CHECKSUM).unwrap().as_slice(),
+        &deps
+            .api
+            .addr_canonicalize(env.contract.address.as_str())
+            .unwrap(),
+        salt.as_slice(),
+    )
+    .unwrap();
+    let instantiated = deps.api.addr_humanize(&instantiated).unwrap();
+    assert_eq!(predicted, instantiated);
+
+    let mut data = vec![10, instantiated.as_str().len() as u8];
+    data.extend_from_slice(instantiated.as_bytes());
+    reply(
+        deps.as_mut(),
+        env,
+        Reply {
+            id: INSTANTIATE_DAO_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(data)),
+            }),
+        },
+    )
+    .unwrap();
+    let daos: Vec<Dao> = from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::ListDaos {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(daos[0].address, predicted.to_string());
+}
+
+#[test]
+fn test_native_fee() {
+    let mut deps = setup(native_fee(100));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &coins(99, DENOM)),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongFee {
+            denom: DENOM.to_string(),
+            expected: Uint128::new(100),
+            actual: Uint128::new(99),
+        }
+    );
+
+    // Funds beyond the fee are sent to the DAO.
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &[coin(150, DENOM), coin(10, "uatom")]),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages[0],
+        SubMsg::new(BankMsg::Send {
+            to_address: TREASURY.to_string(),
+            amount: coins(100, DENOM),
+        })
+    );
+    match &res.messages[1].msg {
+        cosmwasm_std::CosmosMsg::Wasm(WasmMsg::Instantiate2 { funds, .. }) => {
+            assert_eq!(funds, &vec![coin(50, DENOM), coin(10, "uatom")])
+        }
+        _ => panic!("expected instantiate2"),
+    }
+}
+
+#[test]
+fn test_cw20_fee() {
+    let mut deps = setup(None);
+    let cw20 = Addr::unchecked("cw20");
+    // Setting a cw20 fee queries the token's info, so the fee is set
+    // directly.
+    crate::state::FEE
+        .save(
+            deps.as_mut().storage,
+            &Some(Fee {
+                denom: CheckedDenom::Cw20(cw20.clone()),
+                amount: Uint128::new(100),
+                recipient: Addr::unchecked(TREASURY),
+            }),
+        )
+        .unwrap();
+
+    // Native payment is not accepted.
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &coins(100, DENOM)),
+        instantiate_dao_msg(b"salt"),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::WrongFee { .. }));
+
+    let receive = |amount: u128| {
+        ExecuteMsg::Receive(Cw20ReceiveMsg {
+            sender: CREATOR.to_string(),
+            amount: Uint128::new(amount),
+            msg: to_binary(&ReceiveMsg::InstantiateDao {
+                code_id: 1,
+                instantiate_msg: to_binary("dao").unwrap(),
+                label: "dao".to_string(),
+                salt: Binary::from(b"salt"),
+            })
+            .unwrap(),
+        })
+    };
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("other_cw20", &[]),
+        receive(100),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::UnexpectedCw20 {
+            token: "other_cw20".to_string()
+        }
+    );
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(cw20.as_str(), &[]),
+        receive(50),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ContractError::WrongFee { .. }));
+
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(cw20.as_str(), &[]),
+        receive(100),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 2);
+    reply_instantiated(&mut deps, "contract1");
+    assert_eq!(list_daos(&deps, Some(CREATOR)).len(), 1);
+}
+
+#[test]
+fn test_update_fee() {
+    let mut deps = setup(None);
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(CREATOR, &[]),
+        ExecuteMsg::UpdateFee {
+            fee: native_fee(10),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Ownable(OwnershipError::NotOwner));
+
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateFee { fee: native_fee(0) },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroFee {});
+
+    execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(OWNER, &[]),
+        ExecuteMsg::UpdateFee {
+            fee: native_fee(10),
+        },
+    )
+    .unwrap();
+    let fee: Option<Fee> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Fee {}).unwrap()).unwrap();
+    assert_eq!(
+        fee,
+        Some(Fee {
+            denom: CheckedDenom::Native(DENOM.to_string()),
+            amount: Uint128::new(10),
+            recipient: Addr::unchecked(TREASURY),
+        })
+    );
+}
@@ -0,0 +1,119 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, IbcMsg, IbcTimeout, MessageInfo,
+    Response, StdResult, Uint64,
+};
+use cw2::set_contract_version;
+use dao_ibc_voice::msg::{Packet, ProposalRef};
+
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Outcome, CHANNEL, OUTCOMES, REMOTE_ADDRESS};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-ibc-note";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    let ownership = cw_ownable::initialize_owner(deps.storage, deps.api, msg.owner.as_deref())?;
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attributes(ownership.into_attributes()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Execute {
+            msgs,
+            proposal,
+            timeout_seconds,
+        } => execute_execute(deps, env, info, msgs, proposal, timeout_seconds),
+        ExecuteMsg::UpdateOwnership(action) => {
+            let ownership = cw_ownable::update_ownership(deps, &env.block, &info.sender, action)?;
+            Ok(Response::default().add_attributes(ownership.into_attributes()))
+        }
+    }
+}
+
+pub fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<CosmosMsg<Empty>>,
+    proposal: Option<ProposalRef>,
+    timeout_seconds: Uint64,
+) -> Result<Response, ContractError> {
+    cw_ownable::assert_owner(deps.storage, &info.sender)?;
+
+    if timeout_seconds.is_zero() {
+        return Err(ContractError::ZeroTimeout {});
+    }
+    let channel_id = CHANNEL
+        .may_load(deps.storage)?
+        .ok_or(ContractError::NoChannel {})?;
+
+    if let Some(ProposalRef {
+        ref proposal_module,
+        proposal_id,
+    }) = proposal
+    {
+        let proposal_module = deps.api.addr_validate(proposal_module)?;
+        if OUTCOMES.has(deps.storage, (&proposal_module, proposal_id)) {
+            return Err(ContractError::OutcomeExists {
+                proposal_module: proposal_module.into_string(),
+                proposal_id,
+            });
+        }
+        OUTCOMES.save(
+            deps.storage,
+            (&proposal_module, proposal_id),
+            &Outcome::Pending {},
+        )?;
+    }
+
+    let msg_count = msgs.len();
+    let packet = IbcMsg::SendPacket {
+        channel_id: channel_id.clone(),
+        data: to_binary(&Packet {
+            sender: info.sender.into_string(),
+            msgs,
+            proposal,
+        })?,
+        timeout: IbcTimeout::with_timestamp(env.block.time.plus_seconds(timeout_seconds.u64())),
+    };
+
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("channel_id", channel_id)
+        .add_attribute("msg_count", msg_count.to_string())
+        .add_message(packet))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Channel {} => to_binary(&CHANNEL.may_load(deps.storage)?),
+        QueryMsg::RemoteAddress {} => to_binary(&REMOTE_ADDRESS.may_load(deps.storage)?),
+        QueryMsg::Outcome {
+            proposal_module,
+            proposal_id,
+        } => {
+            let proposal_module = deps.api.addr_validate(&proposal_module)?;
+            to_binary(&OUTCOMES.may_load(deps.storage, (&proposal_module, proposal_id))?)
+        }
+        QueryMsg::Ownership {} => to_binary(&cw_ownable::get_ownership(deps.storage)?),
+    }
+}
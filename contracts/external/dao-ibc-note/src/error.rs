@@ -0,0 +1,36 @@
+use cosmwasm_std::StdError;
+use cw_ownable::OwnershipError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    Ownable(#[from] OwnershipError),
+
+    #[error("only unordered channels are supported")]
+    OrderedChannel {},
+
+    #[error("invalid IBC channel version. got ({actual}), expected ({expected})")]
+    InvalidVersion { actual: String, expected: String },
+
+    #[error("a channel is already open with this contract")]
+    ChannelAlreadyOpen {},
+
+    #[error("no channel is open with this contract")]
+    NoChannel {},
+
+    #[error("remote execution has already been requested for proposal ({proposal_id}) of ({proposal_module})")]
+    OutcomeExists {
+        proposal_module: String,
+        proposal_id: u64,
+    },
+
+    #[error("timeout must be non-zero")]
+    ZeroTimeout {},
+
+    #[error("this contract does not accept packets")]
+    NoReceive {},
+}
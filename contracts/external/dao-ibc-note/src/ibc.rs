@@ -0,0 +1,148 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Addr, DepsMut, Env, Ibc3ChannelOpenResponse, IbcBasicResponse,
+    IbcChannel, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcOrder, IbcPacket, IbcPacketAckMsg, IbcPacketReceiveMsg,
+    IbcPacketTimeoutMsg, IbcReceiveResponse, Never, StdResult,
+};
+use dao_ibc_voice::{
+    ibc::IBC_VERSION,
+    msg::{Ack, Packet, ProposalRef},
+};
+
+use crate::error::ContractError;
+use crate::state::{Outcome, CHANNEL, OUTCOMES, REMOTE_ADDRESS};
+
+fn validate_channel(
+    deps: &DepsMut,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::OrderedChannel {});
+    }
+    for version in std::iter::once(channel.version.as_str()).chain(counterparty_version) {
+        if version != IBC_VERSION {
+            return Err(ContractError::InvalidVersion {
+                actual: version.to_string(),
+                expected: IBC_VERSION.to_string(),
+            });
+        }
+    }
+    if CHANNEL.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::ChannelAlreadyOpen {});
+    }
+    Ok(())
+}
+
+/// Records OUTCOME against the proposal that PACKET was sent on
+/// behalf of, if any.
+fn record_outcome(deps: DepsMut, packet: &IbcPacket, outcome: Outcome) -> StdResult<()> {
+    let Packet { proposal, .. } = from_binary(&packet.data)?;
+    if let Some(ProposalRef {
+        proposal_module,
+        proposal_id,
+    }) = proposal
+    {
+        // Validated when the packet was sent.
+        let proposal_module = Addr::unchecked(proposal_module);
+        OUTCOMES.save(deps.storage, (&proposal_module, proposal_id), &outcome)?;
+    }
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_channel(&deps, msg.channel(), msg.counterparty_version())?;
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: IBC_VERSION.to_string(),
+    }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_channel(&deps, channel, msg.counterparty_version())?;
+    CHANNEL.save(deps.storage, &channel.endpoint.channel_id)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    CHANNEL.remove(deps.storage);
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_close")
+        .add_attribute("channel_id", &msg.channel().endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, Never> {
+    // The note only sends packets.
+    Ok(IbcReceiveResponse::new()
+        .add_attribute("method", "ibc_packet_receive")
+        .set_ack(
+            to_binary(&Ack::Error {
+                error: ContractError::NoReceive {}.to_string(),
+            })
+            .unwrap(),
+        ))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    // A malformed acknowledgement is recorded as an error rather than
+    // failing, as failing here would leave the packet unacknowledged.
+    let outcome = match from_binary(&msg.acknowledgement.data) {
+        Ok(Ack::Success { executor, data }) => {
+            REMOTE_ADDRESS.save(deps.storage, &executor)?;
+            Outcome::Success { data }
+        }
+        Ok(Ack::Error { error }) => Outcome::Error { error },
+        Err(error) => Outcome::Error {
+            error: error.to_string(),
+        },
+    };
+    let success = matches!(outcome, Outcome::Success { .. });
+    record_outcome(deps, &msg.original_packet, outcome)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_packet_ack")
+        .add_attribute("sequence", msg.original_packet.sequence.to_string())
+        .add_attribute("success", success.to_string()))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    record_outcome(deps, &msg.packet, Outcome::Timeout {})?;
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_packet_timeout")
+        .add_attribute("sequence", msg.packet.sequence.to_string()))
+}
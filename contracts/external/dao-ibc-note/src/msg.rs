@@ -0,0 +1,51 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{CosmosMsg, Empty, Uint64};
+use cw_ownable::cw_ownable_execute;
+use dao_ibc_voice::msg::ProposalRef;
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The owner of this contract, usually a DAO. Only the owner may
+    /// send messages to the remote chain.
+    pub owner: Option<String>,
+}
+
+#[cw_ownable_execute]
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Callable only by the owner. Sends MSGS to be executed by the
+    /// voice on the remote chain.
+    Execute {
+        msgs: Vec<CosmosMsg<Empty>>,
+        /// The proposal this execution is on behalf of. If set, the
+        /// outcome of execution is recorded and may be queried with
+        /// `QueryMsg::Outcome`.
+        proposal: Option<ProposalRef>,
+        /// Number of seconds after which the packet times out if it
+        /// has not been received.
+        timeout_seconds: Uint64,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the ID of the channel packets are sent over, if one is
+    /// open.
+    #[returns(Option<String>)]
+    Channel {},
+    /// Gets the address of the account controlled by this contract on
+    /// the remote chain. Unknown until a packet has been successfully
+    /// executed.
+    #[returns(Option<String>)]
+    RemoteAddress {},
+    /// Gets the outcome of remote execution for a proposal, if any
+    /// was requested.
+    #[returns(Option<crate::state::Outcome>)]
+    Outcome {
+        proposal_module: String,
+        proposal_id: u64,
+    },
+    #[returns(::cw_ownable::Ownership::<::cosmwasm_std::Addr>)]
+    Ownership {},
+}
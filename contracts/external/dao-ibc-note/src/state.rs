@@ -0,0 +1,25 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Binary};
+use cw_storage_plus::{Item, Map};
+
+/// The result of executing messages on the remote chain on behalf of
+/// a proposal.
+#[cw_serde]
+pub enum Outcome {
+    /// The packet has been sent but not yet acknowledged.
+    Pending {},
+    /// The messages were executed successfully.
+    Success { data: Option<Binary> },
+    /// The messages failed to execute.
+    Error { error: String },
+    /// The packet timed out before it was received.
+    Timeout {},
+}
+
+/// The currently open channel, if any.
+pub const CHANNEL: Item<String> = Item::new("channel");
+/// The address of the account on the remote chain. Set once the first
+/// packet is successfully executed.
+pub const REMOTE_ADDRESS: Item<String> = Item::new("remote_address");
+/// (proposal module, proposal ID) -> outcome of remote execution.
+pub const OUTCOMES: Map<(&Addr, u64), Outcome> = Map::new("outcomes");
@@ -0,0 +1,388 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+        mock_ibc_channel_open_init, mock_ibc_packet_ack, mock_ibc_packet_recv,
+        mock_ibc_packet_timeout, mock_info, MockApi, MockQuerier, MockStorage,
+    },
+    to_binary, Addr, BankMsg, Binary, CosmosMsg, Empty, IbcAcknowledgement, IbcMsg, IbcOrder,
+    IbcTimeout, OwnedDeps, Reply, SubMsgResponse, SubMsgResult, Uint64,
+};
+use dao_ibc_voice::{
+    contract::{instantiate as voice_instantiate, reply as voice_reply, EXECUTE_PACKET_REPLY_ID},
+    ibc::{
+        ibc_channel_connect as voice_channel_connect, ibc_channel_open as voice_channel_open,
+        ibc_packet_receive as voice_packet_receive, IBC_VERSION,
+    },
+    msg::{Ack, InstantiateMsg as VoiceInstantiateMsg, Packet, ProposalRef},
+};
+
+use crate::{
+    contract::{execute, instantiate, query},
+    ibc::{
+        ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_ack,
+        ibc_packet_timeout,
+    },
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    state::Outcome,
+    ContractError,
+};
+
+const DAO: &str = "dao";
+const PROPOSAL_MODULE: &str = "proposal_module";
+const NOTE_CHANNEL: &str = "channel-0";
+const VOICE_CHANNEL: &str = "channel-1";
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+fn setup_note() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            owner: Some(DAO.to_string()),
+        },
+    )
+    .unwrap();
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init(NOTE_CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(NOTE_CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    deps
+}
+
+fn setup_voice() -> Deps {
+    let mut deps = mock_dependencies();
+    voice_instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        VoiceInstantiateMsg {
+            controller_port: "their_port".to_string(),
+            controller_connection: None,
+        },
+    )
+    .unwrap();
+    voice_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init(VOICE_CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    voice_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(VOICE_CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    deps
+}
+
+fn proposal(proposal_id: u64) -> Option<ProposalRef> {
+    Some(ProposalRef {
+        proposal_module: PROPOSAL_MODULE.to_string(),
+        proposal_id,
+    })
+}
+
+fn remote_msgs() -> Vec<CosmosMsg<Empty>> {
+    vec![CosmosMsg::Bank(BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: cosmwasm_std::coins(10, "uosmo"),
+    })]
+}
+
+/// Executes REMOTE_MSGS on the note and returns the packet that was
+/// sent.
+fn send(deps: &mut Deps, proposal: Option<ProposalRef>) -> Packet {
+    let res = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Execute {
+            msgs: remote_msgs(),
+            proposal,
+            timeout_seconds: Uint64::new(600),
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    match &res.messages[0].msg {
+        CosmosMsg::Ibc(IbcMsg::SendPacket {
+            channel_id,
+            data,
+            timeout,
+        }) => {
+            assert_eq!(channel_id, NOTE_CHANNEL);
+            assert_eq!(
+                timeout,
+                &IbcTimeout::with_timestamp(mock_env().block.time.plus_seconds(600))
+            );
+            from_binary(data).unwrap()
+        }
+        _ => panic!("expected a packet to be sent"),
+    }
+}
+
+fn query_outcome(deps: &Deps, proposal_id: u64) -> Option<Outcome> {
+    from_binary(
+        &query(
+            deps.as_ref(),
+            mock_env(),
+            QueryMsg::Outcome {
+                proposal_module: PROPOSAL_MODULE.to_string(),
+                proposal_id,
+            },
+        )
+        .unwrap(),
+    )
+    .unwrap()
+}
+
+fn query_remote_address(deps: &Deps) -> Option<String> {
+    from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::RemoteAddress {}).unwrap()).unwrap()
+}
+
+/// Delivers PACKET to the voice, executes it with RESULT, and returns
+/// the acknowledgement the voice would write.
+fn relay(voice: &mut Deps, packet: &Packet, result: SubMsgResult) -> Binary {
+    let res = voice_packet_receive(
+        voice.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(VOICE_CHANNEL, packet).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(res.messages.len(), 1);
+    assert_eq!(res.messages[0].id, EXECUTE_PACKET_REPLY_ID);
+
+    let res = voice_reply(
+        voice.as_mut(),
+        mock_env(),
+        Reply {
+            id: EXECUTE_PACKET_REPLY_ID,
+            result,
+        },
+    )
+    .unwrap();
+    res.data.unwrap()
+}
+
+#[test]
+fn test_execute_end_to_end() {
+    let mut note = setup_note();
+    let mut voice = setup_voice();
+
+    let packet = send(&mut note, proposal(1));
+    assert_eq!(
+        packet,
+        Packet {
+            sender: DAO.to_string(),
+            msgs: remote_msgs(),
+            proposal: proposal(1),
+        }
+    );
+    assert_eq!(query_outcome(&note, 1), Some(Outcome::Pending {}));
+
+    let ack = relay(
+        &mut voice,
+        &packet,
+        SubMsgResult::Ok(SubMsgResponse {
+            events: vec![],
+            data: None,
+        }),
+    );
+    ibc_packet_ack(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(NOTE_CHANNEL, &packet, IbcAcknowledgement::new(ack)).unwrap(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        query_outcome(&note, 1),
+        Some(Outcome::Success { data: None })
+    );
+    assert_eq!(
+        query_remote_address(&note),
+        Some(mock_env().contract.address.into_string())
+    );
+
+    // Execution failures on the remote chain are recorded.
+    let packet = send(&mut note, proposal(2));
+    let ack = relay(
+        &mut voice,
+        &packet,
+        SubMsgResult::Err("insufficient funds".to_string()),
+    );
+    ibc_packet_ack(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(NOTE_CHANNEL, &packet, IbcAcknowledgement::new(ack)).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        query_outcome(&note, 2),
+        Some(Outcome::Error {
+            error: "insufficient funds".to_string()
+        })
+    );
+}
+
+#[test]
+fn test_timeout() {
+    let mut note = setup_note();
+    let packet = send(&mut note, proposal(1));
+    ibc_packet_timeout(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_packet_timeout(NOTE_CHANNEL, &packet).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(query_outcome(&note, 1), Some(Outcome::Timeout {}));
+    assert_eq!(query_remote_address(&note), None);
+}
+
+#[test]
+fn test_malformed_ack() {
+    let mut note = setup_note();
+    let packet = send(&mut note, proposal(1));
+    ibc_packet_ack(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(
+            NOTE_CHANNEL,
+            &packet,
+            IbcAcknowledgement::new(to_binary("hello").unwrap()),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(
+        query_outcome(&note, 1),
+        Some(Outcome::Error { .. })
+    ));
+}
+
+#[test]
+fn test_execute_without_proposal() {
+    let mut note = setup_note();
+    let packet = send(&mut note, None);
+    ibc_packet_ack(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_packet_ack(
+            NOTE_CHANNEL,
+            &packet,
+            IbcAcknowledgement::new(
+                to_binary(&Ack::Success {
+                    executor: "remote".to_string(),
+                    data: None,
+                })
+                .unwrap(),
+            ),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(query_remote_address(&note), Some("remote".to_string()));
+}
+
+#[test]
+fn test_execute_errors() {
+    let mut note = setup_note();
+
+    let err = execute(
+        note.as_mut(),
+        mock_env(),
+        mock_info("ekez", &[]),
+        ExecuteMsg::Execute {
+            msgs: remote_msgs(),
+            proposal: None,
+            timeout_seconds: Uint64::new(600),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::Ownable(cw_ownable::OwnershipError::NotOwner)
+    );
+
+    let err = execute(
+        note.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Execute {
+            msgs: remote_msgs(),
+            proposal: None,
+            timeout_seconds: Uint64::zero(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ZeroTimeout {});
+
+    // Outcomes may only be recorded once per proposal.
+    send(&mut note, proposal(1));
+    let err = execute(
+        note.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Execute {
+            msgs: remote_msgs(),
+            proposal: proposal(1),
+            timeout_seconds: Uint64::new(600),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::OutcomeExists {
+            proposal_module: PROPOSAL_MODULE.to_string(),
+            proposal_id: 1
+        }
+    );
+
+    ibc_channel_close(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_channel_close_init(NOTE_CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    let err = execute(
+        note.as_mut(),
+        mock_env(),
+        mock_info(DAO, &[]),
+        ExecuteMsg::Execute {
+            msgs: remote_msgs(),
+            proposal: None,
+            timeout_seconds: Uint64::new(600),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NoChannel {});
+}
+
+#[test]
+fn test_one_channel() {
+    let mut note = setup_note();
+    let err = ibc_channel_open(
+        note.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init("channel-2", IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ChannelAlreadyOpen {});
+
+    let ownership: cw_ownable::Ownership<Addr> =
+        from_binary(&query(note.as_ref(), mock_env(), QueryMsg::Ownership {}).unwrap()).unwrap();
+    assert_eq!(ownership.owner, Some(Addr::unchecked(DAO)));
+}
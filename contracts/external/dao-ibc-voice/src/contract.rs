@@ -0,0 +1,94 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
+    StdResult, SubMsgResponse, SubMsgResult,
+};
+use cw2::set_contract_version;
+
+use crate::error::ContractError;
+use crate::msg::{Ack, ExecuteMsg, InstantiateMsg, QueryMsg};
+use crate::state::{Controller, CHANNEL, CONTROLLER};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-ibc-voice";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Reply ID used when executing the messages in a packet.
+pub const EXECUTE_PACKET_REPLY_ID: u64 = 0;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    CONTROLLER.save(
+        deps.storage,
+        &Controller {
+            connection_id: msg.controller_connection,
+            port_id: msg.controller_port.clone(),
+        },
+    )?;
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender)
+        .add_attribute("controller_port", msg.controller_port))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    _deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Execute { msgs } => execute_execute(env, info, msgs),
+    }
+}
+
+pub fn execute_execute(
+    env: Env,
+    info: MessageInfo,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> Result<Response, ContractError> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized {});
+    }
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("msg_count", msgs.len().to_string())
+        .add_messages(msgs))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Channel {} => to_binary(&CHANNEL.may_load(deps.storage)?),
+        QueryMsg::Controller {} => to_binary(&CONTROLLER.load(deps.storage)?),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, env: Env, msg: Reply) -> Result<Response, ContractError> {
+    match msg.id {
+        EXECUTE_PACKET_REPLY_ID => {
+            // Data set here overrides the acknowledgement set while
+            // receiving the packet.
+            let ack = match msg.result {
+                SubMsgResult::Ok(SubMsgResponse { data, .. }) => Ack::Success {
+                    executor: env.contract.address.into_string(),
+                    data,
+                },
+                SubMsgResult::Err(error) => Ack::Error { error },
+            };
+            Ok(Response::default()
+                .add_attribute("method", "reply_execute_packet")
+                .add_attribute("success", matches!(ack, Ack::Success { .. }).to_string())
+                .set_data(to_binary(&ack)?))
+        }
+        _ => Err(ContractError::UnknownReplyID {}),
+    }
+}
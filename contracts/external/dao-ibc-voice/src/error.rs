@@ -0,0 +1,35 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("unauthorized")]
+    Unauthorized {},
+
+    #[error("only unordered channels are supported")]
+    OrderedChannel {},
+
+    #[error("invalid IBC channel version. got ({actual}), expected ({expected})")]
+    InvalidVersion { actual: String, expected: String },
+
+    #[error("this contract only accepts channels from port ({port_id})")]
+    WrongControllerPort { port_id: String },
+
+    #[error("this contract is bound to connection ({connection_id}) and port ({port_id})")]
+    WrongController {
+        connection_id: String,
+        port_id: String,
+    },
+
+    #[error("a channel is already open with this contract")]
+    ChannelAlreadyOpen {},
+
+    #[error("packet received on a channel this contract is not bound to")]
+    UnknownChannel {},
+
+    #[error("An unknown reply ID was received.")]
+    UnknownReplyID {},
+}
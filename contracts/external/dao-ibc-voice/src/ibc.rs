@@ -0,0 +1,172 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    from_binary, to_binary, Binary, DepsMut, Env, Ibc3ChannelOpenResponse, IbcBasicResponse,
+    IbcChannel, IbcChannelCloseMsg, IbcChannelConnectMsg, IbcChannelOpenMsg,
+    IbcChannelOpenResponse, IbcOrder, IbcPacketAckMsg, IbcPacketReceiveMsg, IbcPacketTimeoutMsg,
+    IbcReceiveResponse, Never, StdResult, SubMsg, WasmMsg,
+};
+
+use crate::contract::EXECUTE_PACKET_REPLY_ID;
+use crate::error::ContractError;
+use crate::msg::{Ack, ExecuteMsg, Packet};
+use crate::state::{Controller, CHANNEL, CONTROLLER};
+
+/// The IBC version spoken by note / voice channels.
+pub const IBC_VERSION: &str = "dao-ibc-1";
+
+fn ack_error(error: String) -> StdResult<Binary> {
+    to_binary(&Ack::Error { error })
+}
+
+fn validate_channel(
+    deps: &DepsMut,
+    channel: &IbcChannel,
+    counterparty_version: Option<&str>,
+) -> Result<(), ContractError> {
+    if channel.order != IbcOrder::Unordered {
+        return Err(ContractError::OrderedChannel {});
+    }
+    for version in std::iter::once(channel.version.as_str()).chain(counterparty_version) {
+        if version != IBC_VERSION {
+            return Err(ContractError::InvalidVersion {
+                actual: version.to_string(),
+                expected: IBC_VERSION.to_string(),
+            });
+        }
+    }
+    if CHANNEL.may_load(deps.storage)?.is_some() {
+        return Err(ContractError::ChannelAlreadyOpen {});
+    }
+    let Controller {
+        connection_id,
+        port_id,
+    } = CONTROLLER.load(deps.storage)?;
+    if port_id != channel.counterparty_endpoint.port_id {
+        return Err(ContractError::WrongControllerPort { port_id });
+    }
+    match connection_id {
+        Some(connection_id) if connection_id != channel.connection_id => {
+            Err(ContractError::WrongController {
+                connection_id,
+                port_id,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_open(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelOpenMsg,
+) -> Result<IbcChannelOpenResponse, ContractError> {
+    validate_channel(&deps, msg.channel(), msg.counterparty_version())?;
+    Ok(Some(Ibc3ChannelOpenResponse {
+        version: IBC_VERSION.to_string(),
+    }))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_connect(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelConnectMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    let channel = msg.channel();
+    validate_channel(&deps, channel, msg.counterparty_version())?;
+
+    CONTROLLER.update(deps.storage, |controller| -> StdResult<_> {
+        Ok(Controller {
+            connection_id: Some(channel.connection_id.clone()),
+            ..controller
+        })
+    })?;
+    CHANNEL.save(deps.storage, &channel.endpoint.channel_id)?;
+
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_connect")
+        .add_attribute("channel_id", &channel.endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_channel_close(
+    deps: DepsMut,
+    _env: Env,
+    msg: IbcChannelCloseMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    // The controller binding is kept so that a new channel may only
+    // be opened by the same note on the same connection.
+    CHANNEL.remove(deps.storage);
+    Ok(IbcBasicResponse::new()
+        .add_attribute("method", "ibc_channel_close")
+        .add_attribute("channel_id", &msg.channel().endpoint.channel_id))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, Never> {
+    // Errors here would cause the packet to never be acknowledged, so
+    // they are converted into error acknowledgements instead.
+    match do_ibc_packet_receive(deps, env, msg) {
+        Ok(response) => Ok(response),
+        Err(error) => Ok(IbcReceiveResponse::new()
+            .add_attribute("method", "ibc_packet_receive")
+            .add_attribute("error", error.to_string())
+            .set_ack(ack_error(error.to_string()).unwrap())),
+    }
+}
+
+fn do_ibc_packet_receive(
+    deps: DepsMut,
+    env: Env,
+    msg: IbcPacketReceiveMsg,
+) -> Result<IbcReceiveResponse, ContractError> {
+    let channel = CHANNEL.may_load(deps.storage)?;
+    if channel.as_deref() != Some(msg.packet.dest.channel_id.as_str()) {
+        return Err(ContractError::UnknownChannel {});
+    }
+
+    let Packet { sender, msgs, .. } = from_binary(&msg.packet.data)?;
+
+    // The reply handler overrides this acknowledgement with the
+    // result of execution.
+    let execute = SubMsg::reply_always(
+        WasmMsg::Execute {
+            contract_addr: env.contract.address.into_string(),
+            msg: to_binary(&ExecuteMsg::Execute { msgs })?,
+            funds: vec![],
+        },
+        EXECUTE_PACKET_REPLY_ID,
+    );
+
+    Ok(IbcReceiveResponse::new()
+        .set_ack(ack_error("execution did not complete".to_string())?)
+        .add_submessage(execute)
+        .add_attribute("method", "ibc_packet_receive")
+        .add_attribute("sender", sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_ack(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketAckMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    // The voice never sends packets.
+    Err(ContractError::Unauthorized {})
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn ibc_packet_timeout(
+    _deps: DepsMut,
+    _env: Env,
+    _msg: IbcPacketTimeoutMsg,
+) -> Result<IbcBasicResponse, ContractError> {
+    // The voice never sends packets.
+    Err(ContractError::Unauthorized {})
+}
@@ -0,0 +1,72 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, CosmosMsg, Empty};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    /// The port of the note that controls this contract. On wasm
+    /// chains this is `wasm.` followed by the note's address.
+    pub controller_port: String,
+    /// The connection the note's channel must be opened on. If not
+    /// set, this contract is bound to the connection of the first
+    /// channel opened from the note's port.
+    pub controller_connection: Option<String>,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Executes MSGS as this contract. Only this contract may call
+    /// this method, which it does while handling a packet so that
+    /// execution errors may be caught and acknowledged.
+    Execute { msgs: Vec<CosmosMsg<Empty>> },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    /// Gets the ID of the channel this contract currently accepts
+    /// packets on, if one is open.
+    #[returns(Option<String>)]
+    Channel {},
+    /// Gets the counterparty port and connection this contract is
+    /// bound to.
+    #[returns(crate::state::Controller)]
+    Controller {},
+}
+
+/// Identifies the proposal that caused a packet to be sent.
+#[cw_serde]
+pub struct ProposalRef {
+    /// The proposal module the proposal was created in.
+    pub proposal_module: String,
+    /// The ID of the proposal.
+    pub proposal_id: u64,
+}
+
+/// The data of a packet sent from a note to a voice.
+#[cw_serde]
+pub struct Packet {
+    /// The address on the controller chain that requested this
+    /// execution.
+    pub sender: String,
+    /// The messages to execute on this chain.
+    pub msgs: Vec<CosmosMsg<Empty>>,
+    /// The proposal on the controller chain that this execution
+    /// originates from. Not used by the voice, but returned to the
+    /// note as part of the original packet when the packet is
+    /// acknowledged or times out.
+    pub proposal: Option<ProposalRef>,
+}
+
+/// Acknowledgement sent back to the note.
+#[cw_serde]
+pub enum Ack {
+    /// The packet's messages were executed successfully.
+    Success {
+        /// The address that executed the messages (this contract).
+        executor: String,
+        /// Data returned from the execution, if any.
+        data: Option<Binary>,
+    },
+    /// The packet's messages could not be executed.
+    Error { error: String },
+}
@@ -0,0 +1,15 @@
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::Item;
+
+/// The counterparty port and connection that this contract accepts
+/// packets from. The port is set on instantiation, and the connection
+/// when the first channel is connected unless it was also set then.
+#[cw_serde]
+pub struct Controller {
+    pub connection_id: Option<String>,
+    pub port_id: String,
+}
+
+pub const CONTROLLER: Item<Controller> = Item::new("controller");
+/// The currently open channel, if any.
+pub const CHANNEL: Item<String> = Item::new("channel");
@@ -0,0 +1,332 @@
+use cosmwasm_std::{
+    from_binary,
+    testing::{
+        mock_dependencies, mock_env, mock_ibc_channel_close_init, mock_ibc_channel_connect_ack,
+        mock_ibc_channel_open_init, mock_ibc_channel_open_try, mock_ibc_packet_recv, mock_info,
+        MockApi, MockQuerier, MockStorage,
+    },
+    to_binary, BankMsg, Binary, CosmosMsg, IbcOrder, OwnedDeps, Reply, SubMsg, SubMsgResponse,
+    SubMsgResult, WasmMsg,
+};
+
+use crate::{
+    contract::{execute, instantiate, query, reply, EXECUTE_PACKET_REPLY_ID},
+    ibc::{
+        ibc_channel_close, ibc_channel_connect, ibc_channel_open, ibc_packet_receive, IBC_VERSION,
+    },
+    msg::{Ack, ExecuteMsg, InstantiateMsg, Packet, QueryMsg},
+    state::Controller,
+    ContractError,
+};
+
+const CHANNEL: &str = "channel-0";
+
+type Deps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+fn setup() -> Deps {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            controller_port: "their_port".to_string(),
+            controller_connection: None,
+        },
+    )
+    .unwrap();
+    deps
+}
+
+fn connect(deps: &mut Deps, channel: &str) {
+    ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init(channel, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+    ibc_channel_connect(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_connect_ack(channel, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+}
+
+fn packet() -> Packet {
+    Packet {
+        sender: "dao".to_string(),
+        msgs: vec![CosmosMsg::Bank(BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: cosmwasm_std::coins(10, "ujuno"),
+        })],
+        proposal: None,
+    }
+}
+
+#[test]
+fn test_channel_open_validation() {
+    let mut deps = setup();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init(CHANNEL, IbcOrder::Ordered, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::OrderedChannel {});
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, "ics20-1"),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InvalidVersion {
+            actual: "ics20-1".to_string(),
+            expected: IBC_VERSION.to_string()
+        }
+    );
+
+    connect(&mut deps, CHANNEL);
+
+    // Only one channel may be open at a time.
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init("channel-1", IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::ChannelAlreadyOpen {});
+
+    let channel: Option<String> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Channel {}).unwrap()).unwrap();
+    assert_eq!(channel, Some(CHANNEL.to_string()));
+}
+
+#[test]
+fn test_controller_binding() {
+    let mut deps = setup();
+    connect(&mut deps, CHANNEL);
+
+    ibc_channel_close(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_close_init(CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap();
+
+    let controller: Controller =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Controller {}).unwrap()).unwrap();
+    assert_eq!(
+        controller,
+        Controller {
+            connection_id: Some("connection-2".to_string()),
+            port_id: "their_port".to_string(),
+        }
+    );
+
+    // A channel from a different connection may not take over the
+    // account.
+    let mut open = mock_ibc_channel_open_init("channel-1", IbcOrder::Unordered, IBC_VERSION);
+    if let cosmwasm_std::IbcChannelOpenMsg::OpenInit { ref mut channel } = open {
+        channel.connection_id = "connection-9".to_string();
+    }
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongController {
+            connection_id: controller.connection_id.unwrap(),
+            port_id: controller.port_id,
+        }
+    );
+
+    // The same controller may reconnect.
+    connect(&mut deps, "channel-1");
+}
+
+#[test]
+fn test_foreign_controller_rejected() {
+    let mut deps = setup();
+
+    // A note other than the one named on instantiation may not
+    // connect first and take over the account.
+    let mut open = mock_ibc_channel_open_try(CHANNEL, IbcOrder::Unordered, IBC_VERSION);
+    if let cosmwasm_std::IbcChannelOpenMsg::OpenTry {
+        ref mut channel, ..
+    } = open
+    {
+        channel.counterparty_endpoint.port_id = "wasm.attacker".to_string();
+    }
+    let err = ibc_channel_open(deps.as_mut(), mock_env(), open).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongControllerPort {
+            port_id: "their_port".to_string()
+        }
+    );
+
+    let mut connect_msg = mock_ibc_channel_connect_ack(CHANNEL, IbcOrder::Unordered, IBC_VERSION);
+    if let cosmwasm_std::IbcChannelConnectMsg::OpenAck {
+        ref mut channel, ..
+    } = connect_msg
+    {
+        channel.counterparty_endpoint.port_id = "wasm.attacker".to_string();
+    }
+    let err = ibc_channel_connect(deps.as_mut(), mock_env(), connect_msg).unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongControllerPort {
+            port_id: "their_port".to_string()
+        }
+    );
+
+    let channel: Option<String> =
+        from_binary(&query(deps.as_ref(), mock_env(), QueryMsg::Channel {}).unwrap()).unwrap();
+    assert_eq!(channel, None);
+}
+
+#[test]
+fn test_configured_connection() {
+    let mut deps = mock_dependencies();
+    instantiate(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("creator", &[]),
+        InstantiateMsg {
+            controller_port: "their_port".to_string(),
+            controller_connection: Some("connection-9".to_string()),
+        },
+    )
+    .unwrap();
+
+    let err = ibc_channel_open(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_channel_open_init(CHANNEL, IbcOrder::Unordered, IBC_VERSION),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::WrongController {
+            connection_id: "connection-9".to_string(),
+            port_id: "their_port".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_execute_only_self() {
+    let mut deps = setup();
+    let err = execute(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("dao", &[]),
+        ExecuteMsg::Execute { msgs: vec![] },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::Unauthorized {});
+
+    let env = mock_env();
+    let res = execute(
+        deps.as_mut(),
+        env.clone(),
+        mock_info(env.contract.address.as_str(), &[]),
+        ExecuteMsg::Execute {
+            msgs: packet().msgs,
+        },
+    )
+    .unwrap();
+    assert_eq!(res.messages, vec![SubMsg::new(packet().msgs[0].clone())]);
+}
+
+#[test]
+fn test_receive_packet() {
+    let mut deps = setup();
+
+    // Packets on unknown channels are acknowledged with an error.
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        mock_env(),
+        mock_ibc_packet_recv(CHANNEL, &packet()).unwrap(),
+    )
+    .unwrap();
+    assert!(res.messages.is_empty());
+    let ack: Ack = from_binary(&res.acknowledgement).unwrap();
+    assert_eq!(
+        ack,
+        Ack::Error {
+            error: ContractError::UnknownChannel {}.to_string()
+        }
+    );
+
+    connect(&mut deps, CHANNEL);
+
+    let env = mock_env();
+    let res = ibc_packet_receive(
+        deps.as_mut(),
+        env.clone(),
+        mock_ibc_packet_recv(CHANNEL, &packet()).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        res.messages,
+        vec![SubMsg::reply_always(
+            WasmMsg::Execute {
+                contract_addr: env.contract.address.to_string(),
+                msg: to_binary(&ExecuteMsg::Execute {
+                    msgs: packet().msgs
+                })
+                .unwrap(),
+                funds: vec![],
+            },
+            EXECUTE_PACKET_REPLY_ID
+        )]
+    );
+}
+
+#[test]
+fn test_reply_sets_ack() {
+    let mut deps = setup();
+    let env = mock_env();
+
+    let res = reply(
+        deps.as_mut(),
+        env.clone(),
+        Reply {
+            id: EXECUTE_PACKET_REPLY_ID,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: Some(Binary::from(b"data")),
+            }),
+        },
+    )
+    .unwrap();
+    let ack: Ack = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        ack,
+        Ack::Success {
+            executor: env.contract.address.to_string(),
+            data: Some(Binary::from(b"data")),
+        }
+    );
+
+    let res = reply(
+        deps.as_mut(),
+        env,
+        Reply {
+            id: EXECUTE_PACKET_REPLY_ID,
+            result: SubMsgResult::Err("insufficient funds".to_string()),
+        },
+    )
+    .unwrap();
+    let ack: Ack = from_binary(&res.data.unwrap()).unwrap();
+    assert_eq!(
+        ack,
+        Ack::Error {
+            error: "insufficient funds".to_string()
+        }
+    );
+}
@@ -47,13 +47,20 @@ pub fn query_proposal_v1(
         proposer: proposal.proposer,
         start_height: proposal.start_height,
         min_voting_period: proposal.min_voting_period.map(v1_expiration_to_v2),
+        early_execution: None,
         expiration: v1_expiration_to_v2(proposal.expiration),
+        reveal_deadline: None,
+        execution_deadline: None,
+        veto_threshold: None,
         threshold: v1_threshold_to_v2(proposal.threshold),
         total_power: proposal.total_power,
         msgs: proposal.msgs,
         status: v1_status_to_v2(proposal.status),
         votes: v1_votes_to_v2(proposal.votes),
         allow_revoting: proposal.allow_revoting,
+        execution_attempts: 0,
+        execution_failures: vec![],
+        ballot_version: 0,
     };
 
     (proposal_count, proposal)
@@ -0,0 +1,68 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult};
+use cw2::set_contract_version;
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
+
+use crate::msg::{
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
+};
+use crate::state::PreProposeApproval;
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-multiple";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessage>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, PreProposeError> {
+    let resp = PreProposeApproval::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, PreProposeError> {
+    PreProposeApproval::default().execute(deps, env, info, msg)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let approval = PreProposeApproval::default();
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::Approver {} => approval.query_approver(deps),
+            QueryExt::PendingProposal { id } => approval.query_pending_proposal(deps, id),
+            QueryExt::PendingProposals { start_after, limit } => {
+                approval.query_pending_proposals(deps, start_after, limit, Order::Descending)
+            }
+            QueryExt::ReversePendingProposals {
+                start_before,
+                limit,
+            } => approval.query_pending_proposals(deps, start_before, limit, Order::Ascending),
+            QueryExt::ApprovalCommittee {} => approval.query_approval_committee(deps),
+            QueryExt::PendingExpiration {} => approval.query_pending_expiration(deps),
+            QueryExt::ProposalDecisions {
+                id,
+                start_after,
+                limit,
+            } => approval.query_proposal_decisions(deps, id, start_after, limit),
+            QueryExt::ApproverDecisions {
+                address,
+                start_after,
+                limit,
+            } => approval.query_approver_decisions(deps, address, start_after, limit),
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
@@ -0,0 +1,92 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use dao_pre_propose_approval_base::msg::{
+    ApprovalProposeMessage, ExecuteExt as ExecuteExtBase, ExecuteMsg as ExecuteBase,
+};
+use dao_pre_propose_base::msg::QueryMsg as QueryBase;
+use dao_voting::{
+    multiple_choice::MultipleChoiceOptions,
+    proposal::{MultipleChoiceProposeMsg as ProposeMsg, ProposalDependency},
+};
+
+pub use dao_pre_propose_approval_base::msg::{
+    ApproverProposeMessage, InstantiateExt, InstantiateMsg, UncheckedApprovalCommittee,
+    UncheckedApprovers,
+};
+
+#[cw_serde]
+pub enum ProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        choices: MultipleChoiceOptions,
+        depends_on: Option<Vec<ProposalDependency>>,
+    },
+}
+
+impl ApprovalProposeMessage for ProposeMessage {
+    type ProposeMsg = ProposeMsg;
+
+    fn into_propose_msg(self, proposer: &Addr) -> ProposeMsg {
+        match self {
+            ProposeMessage::Propose {
+                title,
+                description,
+                choices,
+                depends_on,
+            } => ProposeMsg {
+                title,
+                description,
+                choices,
+                proposer: Some(proposer.to_string()),
+                depends_on,
+            },
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// List the approver address
+    #[returns(cosmwasm_std::Addr)]
+    Approver {},
+    /// A pending proposal
+    #[returns(crate::state::PendingProposal)]
+    PendingProposal { id: u64 },
+    /// List of proposals awaiting approval
+    #[returns(Vec<crate::state::PendingProposal>)]
+    PendingProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    #[returns(Vec<crate::state::PendingProposal>)]
+    ReversePendingProposals {
+        start_before: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// The approval committee, if one is configured
+    #[returns(Option<crate::state::ApprovalCommittee>)]
+    ApprovalCommittee {},
+    /// How long proposals may remain pending, if limited
+    #[returns(Option<cw_utils::Duration>)]
+    PendingExpiration {},
+    /// Committee decisions on a pending proposal
+    #[returns(Vec<crate::state::ApproverDecision>)]
+    ProposalDecisions {
+        id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Decisions made by a committee member
+    #[returns(Vec<crate::state::ApproverDecision>)]
+    ApproverDecisions {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+pub type ExecuteExt = ExecuteExtBase<ProposeMessage>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage>;
+pub type QueryMsg = QueryBase<QueryExt>;
@@ -0,0 +1,12 @@
+use dao_pre_propose_approval_base::state::PreProposeApprovalContract;
+use dao_voting::proposal::MultipleChoiceProposeMsg as ProposeMsg;
+
+use crate::msg::ProposeMessage;
+
+pub use dao_pre_propose_approval_base::state::{
+    ApprovalCommittee, ApproverDecision, Approvers, Decision,
+};
+
+pub type PendingProposal = dao_pre_propose_approval_base::state::PendingProposal<ProposeMsg>;
+
+pub type PreProposeApproval = PreProposeApprovalContract<ProposeMessage>;
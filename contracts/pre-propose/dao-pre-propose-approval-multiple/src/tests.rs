@@ -0,0 +1,1489 @@
+use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Decimal, Empty, Uint128};
+use cpm::query::ProposalResponse;
+use cw2::ContractVersion;
+use cw20::Cw20Coin;
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor};
+use cw_utils::Duration;
+use dao_core::state::ProposalModule;
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse};
+use dao_proposal_multiple as cpm;
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    deposit::{DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy,
+    },
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    status::Status,
+    threshold::PercentageThreshold,
+};
+
+use crate::{
+    contract::*,
+    msg::*,
+    state::{ApprovalCommittee, ApproverDecision, Approvers, Decision, PendingProposal},
+};
+
+fn cw_dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cpm::contract::execute,
+        cpm::contract::instantiate,
+        cpm::contract::query,
+    )
+    .with_reply(cpm::contract::reply);
+    Box::new(contract)
+}
+
+fn cw_pre_propose_base_proposal_multiple() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+fn cw20_base_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cw20_base::contract::execute,
+        cw20_base::contract::instantiate,
+        cw20_base::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn get_default_proposal_module_instantiate(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    open_proposal_submission: bool,
+) -> cpm::msg::InstantiateMsg {
+    let pre_propose_id = app.store_code(cw_pre_propose_base_proposal_multiple());
+
+    cpm::msg::InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        max_voting_period: Duration::Time(86400),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_binary(&InstantiateMsg {
+                    deposit_info,
+                    open_proposal_submission,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
+                    extension: InstantiateExt {
+                        approver: "approver".to_string(),
+                        committee: None,
+                        pending_expiration: None,
+                    },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                label: "baby's first pre-propose module".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        early_execution: None,
+    }
+}
+
+fn instantiate_cw20_base_default(app: &mut App) -> Addr {
+    let cw20_id = app.store_code(cw20_base_contract());
+    let cw20_instantiate = cw20_base::msg::InstantiateMsg {
+        name: "cw20 token".to_string(),
+        symbol: "cwtwenty".to_string(),
+        decimals: 6,
+        initial_balances: vec![Cw20Coin {
+            address: "ekez".to_string(),
+            amount: Uint128::new(10),
+        }],
+        mint: None,
+        marketing: None,
+    };
+    app.instantiate_contract(
+        cw20_id,
+        Addr::unchecked("ekez"),
+        &cw20_instantiate,
+        &[],
+        "cw20-base",
+        None,
+    )
+    .unwrap()
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    proposal_multiple: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(
+    app: &mut App,
+    deposit_info: Option<UncheckedDepositInfo>,
+    open_proposal_submission: bool,
+) -> DefaultTestSetup {
+    let dao_proposal_multiple_id = app.store_code(cw_dao_proposal_multiple_contract());
+
+    let proposal_module_instantiate =
+        get_default_proposal_module_instantiate(app, deposit_info, open_proposal_submission);
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        dao_proposal_multiple_id,
+        to_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_multiple = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &cpm::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    // Make sure things were set up correctly.
+    assert_eq!(
+        proposal_multiple,
+        get_proposal_module(app, pre_propose.clone())
+    );
+    assert_eq!(core_addr, get_dao(app, pre_propose.clone()));
+
+    DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    }
+}
+
+fn default_choices() -> MultipleChoiceOptions {
+    MultipleChoiceOptions {
+        options: vec![
+            MultipleChoiceOption {
+                title: "title".to_string(),
+                description: "multiple choice option 1".to_string(),
+                msgs: vec![],
+            },
+            MultipleChoiceOption {
+                title: "title".to_string(),
+                description: "multiple choice option 2".to_string(),
+                msgs: vec![],
+            },
+        ],
+    }
+}
+
+fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[Coin]) -> u64 {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: default_choices(),
+                depends_on: None,
+            },
+        },
+        funds,
+    )
+    .unwrap();
+
+    // Query for pending proposal and return latest id
+    let mut pending: Vec<PendingProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+
+    // Return last item in list, id is first element of tuple
+    pending.pop().unwrap().approval_id
+}
+
+fn mint_natives(app: &mut App, receiver: &str, coins: Vec<Coin>) {
+    // Mint some ekez tokens for ekez so we can pay the deposit.
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: receiver.to_string(),
+        amount: coins,
+    }))
+    .unwrap();
+}
+
+fn increase_allowance(app: &mut App, sender: &str, receiver: &Addr, cw20: Addr, amount: Uint128) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        cw20,
+        &cw20::Cw20ExecuteMsg::IncreaseAllowance {
+            spender: receiver.to_string(),
+            amount,
+            expires: None,
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn get_balance_cw20<T: Into<String>, U: Into<String>>(
+    app: &App,
+    contract_addr: T,
+    address: U,
+) -> Uint128 {
+    let msg = cw20::Cw20QueryMsg::Balance {
+        address: address.into(),
+    };
+    let result: cw20::BalanceResponse = app.wrap().query_wasm_smart(contract_addr, &msg).unwrap();
+    result.balance
+}
+
+fn get_balance_native(app: &App, who: &str, denom: &str) -> Uint128 {
+    let res = app.wrap().query_balance(who, denom).unwrap();
+    res.amount
+}
+
+fn vote(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    id: u64,
+    position: MultipleChoiceVote,
+) -> Status {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module.clone(),
+        &cpm::msg::ExecuteMsg::Vote {
+            proposal_id: id,
+            vote: position,
+            rationale: None,
+        },
+        &[],
+    )
+    .unwrap();
+
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(module, &cpm::msg::QueryMsg::Proposal { proposal_id: id })
+        .unwrap();
+
+    proposal.proposal.status
+}
+
+fn get_dao(app: &App, module: Addr) -> Addr {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::Dao {})
+        .unwrap()
+}
+
+fn get_proposal_module(app: &App, module: Addr) -> Addr {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::ProposalModule {})
+        .unwrap()
+}
+
+fn get_deposit_info(app: &App, module: Addr, id: u64) -> DepositInfoResponse {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::DepositInfo { proposal_id: id })
+        .unwrap()
+}
+
+fn close_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &cpm::msg::ExecuteMsg::Close { proposal_id },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &cpm::msg::ExecuteMsg::Execute { proposal_id },
+        &[],
+    )
+    .unwrap();
+}
+
+fn approve_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) -> u64 {
+    let res = app
+        .execute_contract(
+            Addr::unchecked(sender),
+            module,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::Approve { id: proposal_id },
+            },
+            &[],
+        )
+        .unwrap();
+
+    // Parse attrs from approve_proposal response
+    let attrs = res.custom_attrs(res.events.len() - 1);
+    // Return ID
+    attrs[attrs.len() - 2].value.parse().unwrap()
+}
+
+fn reject_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::Reject { id: proposal_id },
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn update_approval_settings(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    committee: Option<UncheckedApprovalCommittee>,
+    pending_expiration: Option<Duration>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateApprovalSettings {
+                committee,
+                pending_expiration,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_extension(app: &mut App, module: Addr, sender: &str, msg: ExecuteExt) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_extension_should_fail(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    msg: ExecuteExt,
+) -> PreProposeError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+fn get_pending_proposals(app: &App, module: Addr) -> Vec<PendingProposal> {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap()
+}
+
+enum ApprovalStatus {
+    Approved,
+    Rejected,
+}
+
+enum EndStatus {
+    Passed,
+    Failed,
+}
+
+enum RefundReceiver {
+    Proposer,
+    Dao,
+}
+
+fn test_native_permutation(
+    end_status: EndStatus,
+    refund_policy: DepositRefundPolicy,
+    receiver: RefundReceiver,
+    approval_status: ApprovalStatus,
+) {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // Make sure it went away.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::zero());
+
+    // Approver approves or rejects proposal
+    match approval_status {
+        ApprovalStatus::Approved => {
+            // Approver approves, new proposal id is returned
+            let id = approve_proposal(&mut app, pre_propose, "approver", pre_propose_id);
+
+            // Voting happens on newly created proposal
+            #[allow(clippy::type_complexity)]
+            let (position, expected_status, trigger_refund): (
+                _,
+                _,
+                fn(&mut App, Addr, &str, u64) -> (),
+            ) = match end_status {
+                EndStatus::Passed => (
+                    MultipleChoiceVote { option_id: 0 },
+                    Status::Passed,
+                    execute_proposal,
+                ),
+                EndStatus::Failed => (
+                    MultipleChoiceVote { option_id: 2 },
+                    Status::Rejected,
+                    close_proposal,
+                ),
+            };
+            let new_status = vote(&mut app, proposal_multiple.clone(), "ekez", id, position);
+            assert_eq!(new_status, expected_status);
+
+            // Close or execute the proposal to trigger a refund.
+            trigger_refund(&mut app, proposal_multiple, "ekez", id);
+        }
+        ApprovalStatus::Rejected => {
+            // Proposal is rejected by approver
+            // No proposal is created so there is no voting
+            reject_proposal(&mut app, pre_propose, "approver", pre_propose_id);
+        }
+    };
+
+    let (dao_expected, proposer_expected) = match receiver {
+        RefundReceiver::Proposer => (0, 10),
+        RefundReceiver::Dao => (10, 0),
+    };
+
+    let proposer_balance = get_balance_native(&app, "ekez", "ujuno");
+    let dao_balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
+    assert_eq!(proposer_expected, proposer_balance.u128());
+    assert_eq!(dao_expected, dao_balance.u128())
+}
+
+fn test_cw20_permutation(
+    end_status: EndStatus,
+    refund_policy: DepositRefundPolicy,
+    receiver: RefundReceiver,
+    approval_status: ApprovalStatus,
+) {
+    let mut app = App::default();
+
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
+    );
+    let pre_propose_id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+
+    // Make sure it went await.
+    let balance = get_balance_cw20(&app, cw20_address.clone(), "ekez");
+    assert_eq!(balance, Uint128::zero());
+
+    // Approver approves or rejects proposal
+    match approval_status {
+        ApprovalStatus::Approved => {
+            // Approver approves, new proposal id is returned
+            let id = approve_proposal(&mut app, pre_propose.clone(), "approver", pre_propose_id);
+
+            // Voting happens on newly created proposal
+            #[allow(clippy::type_complexity)]
+            let (position, expected_status, trigger_refund): (
+                _,
+                _,
+                fn(&mut App, Addr, &str, u64) -> (),
+            ) = match end_status {
+                EndStatus::Passed => (
+                    MultipleChoiceVote { option_id: 0 },
+                    Status::Passed,
+                    execute_proposal,
+                ),
+                EndStatus::Failed => (
+                    MultipleChoiceVote { option_id: 2 },
+                    Status::Rejected,
+                    close_proposal,
+                ),
+            };
+            let new_status = vote(&mut app, proposal_multiple.clone(), "ekez", id, position);
+            assert_eq!(new_status, expected_status);
+
+            // Close or execute the proposal to trigger a refund.
+            trigger_refund(&mut app, proposal_multiple, "ekez", id);
+        }
+        ApprovalStatus::Rejected => {
+            // Proposal is rejected by approver
+            // No proposal is created so there is no voting
+            reject_proposal(&mut app, pre_propose.clone(), "approver", pre_propose_id);
+        }
+    };
+
+    let (dao_expected, proposer_expected) = match receiver {
+        RefundReceiver::Proposer => (0, 10),
+        RefundReceiver::Dao => (10, 0),
+    };
+
+    let proposer_balance = get_balance_cw20(&app, &cw20_address, "ekez");
+    let dao_balance = get_balance_cw20(&app, &cw20_address, core_addr);
+    assert_eq!(proposer_expected, proposer_balance.u128());
+    assert_eq!(dao_expected, dao_balance.u128())
+}
+
+#[test]
+fn test_native_rejected_always_refund() {
+    test_native_permutation(
+        EndStatus::Failed,
+        DepositRefundPolicy::Always,
+        RefundReceiver::Proposer,
+        ApprovalStatus::Rejected,
+    )
+}
+
+#[test]
+fn test_cw20_rejected_always_refund() {
+    test_cw20_permutation(
+        EndStatus::Failed,
+        DepositRefundPolicy::Always,
+        RefundReceiver::Proposer,
+        ApprovalStatus::Rejected,
+    )
+}
+
+#[test]
+fn test_native_passed_never_refund() {
+    test_native_permutation(
+        EndStatus::Passed,
+        DepositRefundPolicy::Never,
+        RefundReceiver::Dao,
+        ApprovalStatus::Approved,
+    )
+}
+
+#[test]
+fn test_cw20_failed_passed_refund() {
+    test_cw20_permutation(
+        EndStatus::Failed,
+        DepositRefundPolicy::OnlyPassed,
+        RefundReceiver::Dao,
+        ApprovalStatus::Approved,
+    )
+}
+
+#[test]
+fn test_multiple_open_proposals() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let first_pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    // Approver approves prop, balance remains the same
+    let first_id = approve_proposal(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        first_pre_propose_id,
+    );
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    let second_pre_propose_id =
+        make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    // Approver approves prop, balance remains the same
+    let second_id = approve_proposal(&mut app, pre_propose, "approver", second_pre_propose_id);
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    // Finish up the first proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_multiple.clone(),
+        "ekez",
+        first_id,
+        MultipleChoiceVote { option_id: 0 },
+    );
+    assert_eq!(Status::Passed, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    execute_proposal(&mut app, proposal_multiple.clone(), "ekez", first_id);
+
+    // First proposal refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    // Finish up the second proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_multiple.clone(),
+        "ekez",
+        second_id,
+        MultipleChoiceVote { option_id: 2 },
+    );
+    assert_eq!(Status::Rejected, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    close_proposal(&mut app, proposal_multiple, "ekez", second_id);
+
+    // All deposits have been refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(20, balance.u128());
+}
+
+#[test]
+fn test_pending_proposal_queries() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // Query for individual proposal
+    let prop1: PendingProposal = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposal { id: 1 },
+            },
+        )
+        .unwrap();
+    assert_eq!(prop1.approval_id, 1);
+
+    // Query for the pre-propose proposals
+    let pre_propose_props: Vec<PendingProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(pre_propose_props.len(), 2);
+    assert_eq!(pre_propose_props[0].approval_id, 2);
+
+    // Query props in reverse
+    let reverse_pre_propose_props: Vec<PendingProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ReversePendingProposals {
+                    start_before: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+
+    assert_eq!(reverse_pre_propose_props.len(), 2);
+    assert_eq!(reverse_pre_propose_props[0].approval_id, 1);
+}
+
+#[test]
+fn test_set_version() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    let info: ContractVersion = from_slice(
+        &app.wrap()
+            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
+            .unwrap()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string()
+        },
+        info
+    )
+}
+
+#[test]
+fn test_permissions() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false, // no open proposal submission.
+    );
+
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose.clone(),
+            &ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+                vetoed: false,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotModule {});
+
+    // Non-members may not propose when open_propose_submission is
+    // disabled.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose,
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "I would like to join the DAO".to_string(),
+                    description: "though, I am currently not a member.".to_string(),
+                    choices: default_choices(),
+                    depends_on: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotMember {});
+}
+
+#[test]
+fn test_approval_and_rejection_permissions() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        true, // yes, open proposal submission.
+    );
+
+    // Non-member proposes.
+    mint_natives(&mut app, "nonmember", coins(10, "ujuno"));
+    let pre_propose_id = make_pre_proposal(
+        &mut app,
+        pre_propose.clone(),
+        "nonmember",
+        &coins(10, "ujuno"),
+    );
+
+    // Only approver can propose
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::Approve { id: pre_propose_id },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // Only approver can propose
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose,
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::Reject { id: pre_propose_id },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::Unauthorized {});
+}
+
+#[test]
+fn test_approval_committee() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    let committee = UncheckedApprovalCommittee {
+        approvers: UncheckedApprovers::Addresses {
+            addresses: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+        },
+        threshold: 4,
+    };
+
+    // Threshold may not exceed the number of approvers.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        ExecuteExt::UpdateApprovalSettings {
+            committee: Some(committee.clone()),
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    // Only the approver or the DAO may update approval settings.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::UpdateApprovalSettings {
+            committee: None,
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(UncheckedApprovalCommittee {
+            threshold: 2,
+            ..committee
+        }),
+        None,
+    );
+    let committee: Option<ApprovalCommittee> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApprovalCommittee {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        committee,
+        Some(ApprovalCommittee {
+            approvers: Approvers::Addresses {
+                addresses: vec![
+                    Addr::unchecked("alice"),
+                    Addr::unchecked("bob"),
+                    Addr::unchecked("carol")
+                ]
+            },
+            threshold: 2,
+        })
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let first = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // Non-members of the committee may not approve.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "dave",
+        ExecuteExt::Approve { id: first },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // One approval is not enough.
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id: first },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+
+    // Approvers may only decide once.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Reject { id: first },
+    );
+    assert_eq!(err, PreProposeError::AlreadyDecided {});
+
+    // The second approval creates the proposal.
+    let id = approve_proposal(&mut app, pre_propose.clone(), "bob", first);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+    let info = get_deposit_info(&app, pre_propose.clone(), id);
+    assert_eq!(info.proposer, Addr::unchecked("ekez"));
+
+    // With three approvers and a threshold of two, two rejections
+    // make approval impossible and reject the proposal.
+    let second = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Reject { id: second },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+    reject_proposal(&mut app, pre_propose.clone(), "carol", second);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // Deposit was refunded on rejection.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+
+    let decisions: Vec<ApproverDecision> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ProposalDecisions {
+                    id: second,
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        decisions,
+        vec![
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Reject,
+            },
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("carol"),
+                decision: Decision::Reject,
+            },
+        ]
+    );
+
+    let decisions: Vec<ApproverDecision> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApproverDecisions {
+                    address: "alice".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        decisions,
+        vec![
+            ApproverDecision {
+                approval_id: first,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Approve,
+            },
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Reject,
+            },
+        ]
+    );
+
+    // The approver may still decide alone, and its decision is final
+    // even after a committee member has approved.
+    let third = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id: third },
+    );
+    reject_proposal(&mut app, pre_propose.clone(), "approver", third);
+    assert!(get_pending_proposals(&app, pre_propose).is_empty());
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+}
+
+#[test]
+fn test_cw4_approval_committee() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let cw4_id = app.store_code(dao_testing::contracts::cw4_group_contract());
+    let group = app
+        .instantiate_contract(
+            cw4_id,
+            Addr::unchecked("ekez"),
+            &cw4_group::msg::InstantiateMsg {
+                admin: Some("ekez".to_string()),
+                members: vec![
+                    cw4::Member {
+                        addr: "alice".to_string(),
+                        weight: 1,
+                    },
+                    cw4::Member {
+                        addr: "bob".to_string(),
+                        weight: 3,
+                    },
+                    cw4::Member {
+                        addr: "carol".to_string(),
+                        weight: 0,
+                    },
+                ],
+            },
+            &[],
+            "approvers",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+
+    // Threshold may not exceed the group's total weight. Zero weight
+    // members are not approvers.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        ExecuteExt::UpdateApprovalSettings {
+            committee: Some(UncheckedApprovalCommittee {
+                approvers: UncheckedApprovers::Cw4Group {
+                    group: group.to_string(),
+                },
+                threshold: 5,
+            }),
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    // The approver may also configure a committee.
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        Some(UncheckedApprovalCommittee {
+            approvers: UncheckedApprovers::Cw4Group {
+                group: group.to_string(),
+            },
+            threshold: 4,
+        }),
+        None,
+    );
+
+    // Approvals are weighted by group membership.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "carol",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // Membership is read at the height the pending proposal was
+    // submitted. Members who join later may not decide on it, and
+    // members who leave keep their decisions.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id },
+    );
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        group.clone(),
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec!["alice".to_string()],
+            add: vec![cw4::Member {
+                addr: "dave".to_string(),
+                weight: 5,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "dave",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // If the group's total weight is below the threshold, the
+    // approval of every member is enough.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec!["dave".to_string()],
+            add: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // The approver may still decide alone.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approve_proposal(&mut app, pre_propose.clone(), "approver", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose).len(), 0);
+}
+
+#[test]
+fn test_pending_proposal_expiration() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_multiple: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        None,
+        Some(Duration::Height(10)),
+    );
+    let expiration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingExpiration {},
+            },
+        )
+        .unwrap();
+    assert_eq!(expiration, Some(Duration::Height(10)));
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let expires = cw_utils::Expiration::AtHeight(app.block_info().height + 10);
+    assert_eq!(
+        get_pending_proposals(&app, pre_propose.clone())[0].expiration,
+        Some(expires)
+    );
+
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Expire { id },
+    );
+    assert_eq!(err, PreProposeError::PendingProposalNotExpired {});
+
+    app.update_block(|b| b.height += 10);
+
+    // Expired proposals may no longer be approved or rejected.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(
+        err,
+        PreProposeError::PendingProposalExpired {
+            expiration: expires
+        }
+    );
+
+    // Anyone may expire the proposal. The deposit is refunded even
+    // though the refund policy is `Never`.
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "someone",
+        ExecuteExt::Expire { id },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+
+    let err =
+        execute_extension_should_fail(&mut app, pre_propose, "someone", ExecuteExt::Expire { id });
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+}
+
+#[test]
+fn test_edit_choices() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_multiple,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Return {
+            id,
+            comment: "add an option".to_string(),
+        },
+    );
+
+    let mut choices = default_choices();
+    choices.options.push(MultipleChoiceOption {
+        title: "title".to_string(),
+        description: "multiple choice option 3".to_string(),
+        msgs: vec![],
+    });
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Edit {
+            id,
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: choices.clone(),
+                depends_on: None,
+            },
+        },
+    );
+    let pending = get_pending_proposals(&app, pre_propose.clone());
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending[0].msg.choices, choices);
+    assert_eq!(pending[0].return_comment, None);
+
+    // The edited proposal has a new approval ID.
+    let new_id = pending[0].approval_id;
+    assert_eq!(new_id, id + 1);
+
+    // The proposal is created with the edited choices, along with
+    // the "none of the above" option.
+    let proposal_id = approve_proposal(&mut app, pre_propose, "approver", new_id);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &cpm::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.choices.len(), 4);
+    assert_eq!(
+        proposal.proposal.choices[2].description,
+        "multiple choice option 3"
+    );
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+}
@@ -1,22 +1,13 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
-use cosmwasm_std::{
-    to_binary, Binary, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response, StdResult, SubMsg,
-    WasmMsg,
-};
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult};
 use cw2::set_contract_version;
-use cw_paginate::paginate_map_values;
-use dao_pre_propose_base::{
-    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
-};
-use dao_voting::deposit::DepositRefundPolicy;
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_pre_propose_base::{error::PreProposeError, state::PreProposeContract};
 
 use crate::msg::{
-    ApproverProposeMessage, ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage,
-    ProposeMessageInternal, QueryExt, QueryMsg,
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
 };
-use crate::state::{advance_approval_id, PendingProposal, APPROVER, PENDING_PROPOSALS};
+use crate::state::PreProposeApproval;
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-approval-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -30,12 +21,9 @@ pub fn instantiate(
     info: MessageInfo,
     msg: InstantiateMsg,
 ) -> Result<Response, PreProposeError> {
-    let approver = deps.api.addr_validate(&msg.extension.approver)?;
-    APPROVER.save(deps.storage, &approver)?;
-
-    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    let resp = PreProposeApproval::default().instantiate(deps.branch(), env, info, msg)?;
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
-    Ok(resp.add_attribute("approver", approver.to_string()))
+    Ok(resp)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
@@ -45,278 +33,35 @@ pub fn execute(
     info: MessageInfo,
     msg: ExecuteMsg,
 ) -> Result<Response, PreProposeError> {
-    match msg {
-        ExecuteMsg::Propose { msg } => execute_propose(deps, env, info, msg),
-
-        ExecuteMsg::AddProposalSubmittedHook { address } => {
-            execute_add_approver_hook(deps, info, address)
-        }
-        ExecuteMsg::RemoveProposalSubmittedHook { address } => {
-            execute_remove_approver_hook(deps, info, address)
-        }
-
-        ExecuteMsg::Extension { msg } => match msg {
-            ExecuteExt::Approve { id } => execute_approve(deps, info, id),
-            ExecuteExt::Reject { id } => execute_reject(deps, info, id),
-            ExecuteExt::UpdateApprover { address } => execute_update_approver(deps, info, address),
-        },
-        // Default pre-propose-base behavior for all other messages
-        _ => PrePropose::default().execute(deps, env, info, msg),
-    }
-}
-
-pub fn execute_propose(
-    deps: DepsMut,
-    env: Env,
-    info: MessageInfo,
-    msg: ProposeMessage,
-) -> Result<Response, PreProposeError> {
-    let pre_propose_base = PrePropose::default();
-    let config = pre_propose_base.config.load(deps.storage)?;
-
-    pre_propose_base.check_can_submit(deps.as_ref(), info.sender.clone())?;
-
-    // Take deposit, if configured.
-    let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-        deposit_info.check_native_deposit_paid(&info)?;
-        deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
-    } else {
-        vec![]
-    };
-
-    let approval_id = advance_approval_id(deps.storage)?;
-
-    let propose_msg_internal = match msg {
-        ProposeMessage::Propose {
-            title,
-            description,
-            msgs,
-        } => ProposeMsg {
-            title,
-            description,
-            msgs,
-            proposer: Some(info.sender.to_string()),
-        },
-    };
-
-    // Prepare proposal submitted hooks msg to notify approver.  Make
-    // a proposal on the approver DAO to approve this pre-proposal
-    let hooks_msgs =
-        pre_propose_base
-            .proposal_submitted_hooks
-            .prepare_hooks(deps.storage, |a| {
-                let execute_msg = WasmMsg::Execute {
-                    contract_addr: a.into_string(),
-                    msg: to_binary(&ExecuteBase::<ApproverProposeMessage, Empty>::Propose {
-                        msg: ApproverProposeMessage::Propose {
-                            title: propose_msg_internal.title.clone(),
-                            description: propose_msg_internal.description.clone(),
-                            approval_id,
-                        },
-                    })?,
-                    funds: vec![],
-                };
-                Ok(SubMsg::new(execute_msg))
-            })?;
-
-    // Save the proposal and its information as pending.
-    PENDING_PROPOSALS.save(
-        deps.storage,
-        approval_id,
-        &PendingProposal {
-            approval_id,
-            proposer: info.sender,
-            msg: propose_msg_internal,
-            deposit: config.deposit_info,
-        },
-    )?;
-
-    Ok(Response::default()
-        .add_messages(deposit_messages)
-        .add_submessages(hooks_msgs)
-        .add_attribute("method", "pre-propose")
-        .add_attribute("id", approval_id.to_string()))
-}
-
-pub fn execute_approve(
-    deps: DepsMut,
-    info: MessageInfo,
-    id: u64,
-) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
-    }
-
-    // Load proposal and send propose message to the proposal module
-    let proposal = PENDING_PROPOSALS.may_load(deps.storage, id)?;
-    match proposal {
-        Some(proposal) => {
-            let proposal_module = PrePropose::default().proposal_module.load(deps.storage)?;
-
-            // Snapshot the deposit for the proposal that we're about
-            // to create.
-            let proposal_id = deps.querier.query_wasm_smart(
-                &proposal_module,
-                &dao_interface::proposal::Query::NextProposalId {},
-            )?;
-            PrePropose::default().deposits.save(
-                deps.storage,
-                proposal_id,
-                &(proposal.deposit, proposal.proposer),
-            )?;
-
-            let propose_messsage = WasmMsg::Execute {
-                contract_addr: proposal_module.into_string(),
-                msg: to_binary(&ProposeMessageInternal::Propose(proposal.msg))?,
-                funds: vec![],
-            };
-            PENDING_PROPOSALS.remove(deps.storage, id);
-
-            Ok(Response::default()
-                .add_message(propose_messsage)
-                .add_attribute("method", "proposal_approved")
-                .add_attribute("approval_id", id.to_string())
-                .add_attribute("proposal_id", proposal_id.to_string()))
-        }
-        None => Err(PreProposeError::ProposalNotFound {}),
-    }
-}
-
-pub fn execute_reject(
-    deps: DepsMut,
-    info: MessageInfo,
-    id: u64,
-) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
-    }
-
-    let PendingProposal {
-        deposit, proposer, ..
-    } = PENDING_PROPOSALS
-        .may_load(deps.storage, id)?
-        .ok_or(PreProposeError::ProposalNotFound {})?;
-
-    PENDING_PROPOSALS.remove(deps.storage, id);
-
-    let messages = if let Some(ref deposit_info) = deposit {
-        // Refund can be issued if proposal if deposits are always
-        // refunded. `OnlyPassed` and `Never` refund deposit policies
-        // do not apply here.
-        if deposit_info.refund_policy == DepositRefundPolicy::Always {
-            deposit_info.get_return_deposit_message(&proposer)?
-        } else {
-            // If the proposer doesn't get the deposit, the DAO does.
-            let dao = PrePropose::default().dao.load(deps.storage)?;
-            deposit_info.get_return_deposit_message(&dao)?
-        }
-    } else {
-        vec![]
-    };
-
-    Ok(Response::default()
-        .add_attribute("method", "proposal_rejected")
-        .add_attribute("proposal", id.to_string())
-        .add_attribute("deposit_info", to_binary(&deposit)?.to_string())
-        .add_messages(messages))
-}
-
-pub fn execute_update_approver(
-    deps: DepsMut,
-    info: MessageInfo,
-    address: String,
-) -> Result<Response, PreProposeError> {
-    // Check sender is the approver
-    let approver = APPROVER.load(deps.storage)?;
-    if approver != info.sender {
-        return Err(PreProposeError::Unauthorized {});
-    }
-
-    // Validate address and save new approver
-    let addr = deps.api.addr_validate(&address)?;
-    APPROVER.save(deps.storage, &addr)?;
-
-    Ok(Response::default())
-}
-
-pub fn execute_add_approver_hook(
-    deps: DepsMut,
-    info: MessageInfo,
-    address: String,
-) -> Result<Response, PreProposeError> {
-    let pre_propose_base = PrePropose::default();
-
-    let dao = pre_propose_base.dao.load(deps.storage)?;
-    let approver = APPROVER.load(deps.storage)?;
-
-    // Check sender is the approver or the parent DAO
-    if approver != info.sender && dao != info.sender {
-        return Err(PreProposeError::Unauthorized {});
-    }
-
-    let addr = deps.api.addr_validate(&address)?;
-    pre_propose_base
-        .proposal_submitted_hooks
-        .add_hook(deps.storage, addr)?;
-
-    Ok(Response::default())
-}
-
-pub fn execute_remove_approver_hook(
-    deps: DepsMut,
-    info: MessageInfo,
-    address: String,
-) -> Result<Response, PreProposeError> {
-    let pre_propose_base = PrePropose::default();
-
-    let dao = pre_propose_base.dao.load(deps.storage)?;
-    let approver = APPROVER.load(deps.storage)?;
-
-    // Check sender is the approver or the parent DAO
-    if approver != info.sender && dao != info.sender {
-        return Err(PreProposeError::Unauthorized {});
-    }
-
-    // Validate address
-    let addr = deps.api.addr_validate(&address)?;
-
-    // remove hook
-    pre_propose_base
-        .proposal_submitted_hooks
-        .remove_hook(deps.storage, addr)?;
-
-    Ok(Response::default())
+    PreProposeApproval::default().execute(deps, env, info, msg)
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    let approval = PreProposeApproval::default();
     match msg {
         QueryMsg::QueryExtension { msg } => match msg {
-            QueryExt::Approver {} => to_binary(&APPROVER.load(deps.storage)?),
-            QueryExt::PendingProposal { id } => {
-                to_binary(&PENDING_PROPOSALS.load(deps.storage, id)?)
+            QueryExt::Approver {} => approval.query_approver(deps),
+            QueryExt::PendingProposal { id } => approval.query_pending_proposal(deps, id),
+            QueryExt::PendingProposals { start_after, limit } => {
+                approval.query_pending_proposals(deps, start_after, limit, Order::Descending)
             }
-            QueryExt::PendingProposals { start_after, limit } => to_binary(&paginate_map_values(
-                deps,
-                &PENDING_PROPOSALS,
-                start_after,
-                limit,
-                Order::Descending,
-            )?),
             QueryExt::ReversePendingProposals {
                 start_before,
                 limit,
-            } => to_binary(&paginate_map_values(
-                deps,
-                &PENDING_PROPOSALS,
-                start_before,
+            } => approval.query_pending_proposals(deps, start_before, limit, Order::Ascending),
+            QueryExt::ApprovalCommittee {} => approval.query_approval_committee(deps),
+            QueryExt::PendingExpiration {} => approval.query_pending_expiration(deps),
+            QueryExt::ProposalDecisions {
+                id,
+                start_after,
+                limit,
+            } => approval.query_proposal_decisions(deps, id, start_after, limit),
+            QueryExt::ApproverDecisions {
+                address,
+                start_after,
                 limit,
-                Order::Ascending,
-            )?),
+            } => approval.query_approver_decisions(deps, address, start_after, limit),
         },
         _ => PrePropose::default().query(deps, env, msg),
     }
@@ -1,18 +1,15 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CosmosMsg, Empty};
-use dao_pre_propose_base::msg::{
-    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+use cosmwasm_std::{Addr, CosmosMsg, Empty};
+use dao_pre_propose_approval_base::msg::{
+    ApprovalProposeMessage, ExecuteExt as ExecuteExtBase, ExecuteMsg as ExecuteBase,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_pre_propose_base::msg::QueryMsg as QueryBase;
+use dao_voting::proposal::{ProposalDependency, SingleChoiceProposeMsg as ProposeMsg};
 
-#[cw_serde]
-pub enum ApproverProposeMessage {
-    Propose {
-        title: String,
-        description: String,
-        approval_id: u64,
-    },
-}
+pub use dao_pre_propose_approval_base::msg::{
+    ApproverProposeMessage, InstantiateExt, InstantiateMsg, UncheckedApprovalCommittee,
+    UncheckedApprovers,
+};
 
 #[cw_serde]
 pub enum ProposeMessage {
@@ -20,22 +17,30 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        depends_on: Option<Vec<ProposalDependency>>,
     },
 }
 
-#[cw_serde]
-pub struct InstantiateExt {
-    pub approver: String,
-}
+impl ApprovalProposeMessage for ProposeMessage {
+    type ProposeMsg = ProposeMsg;
 
-#[cw_serde]
-pub enum ExecuteExt {
-    /// Approve a proposal, only callable by approver
-    Approve { id: u64 },
-    /// Reject a proposal, only callable by approver
-    Reject { id: u64 },
-    /// Updates the approver, can only be called the current approver
-    UpdateApprover { address: String },
+    fn into_propose_msg(self, proposer: &Addr) -> ProposeMsg {
+        match self {
+            ProposeMessage::Propose {
+                title,
+                description,
+                msgs,
+                depends_on,
+            } => ProposeMsg {
+                title,
+                description,
+                msgs,
+                proposer: Some(proposer.to_string()),
+                threshold: None,
+                depends_on,
+            },
+        }
+    }
 }
 
 #[cw_serde]
@@ -58,16 +63,28 @@ pub enum QueryExt {
         start_before: Option<u64>,
         limit: Option<u32>,
     },
+    /// The approval committee, if one is configured
+    #[returns(Option<crate::state::ApprovalCommittee>)]
+    ApprovalCommittee {},
+    /// How long proposals may remain pending, if limited
+    #[returns(Option<cw_utils::Duration>)]
+    PendingExpiration {},
+    /// Committee decisions on a pending proposal
+    #[returns(Vec<crate::state::ApproverDecision>)]
+    ProposalDecisions {
+        id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// Decisions made by a committee member
+    #[returns(Vec<crate::state::ApproverDecision>)]
+    ApproverDecisions {
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
 }
 
-pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
-pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type ExecuteExt = ExecuteExtBase<ProposeMessage>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage>;
 pub type QueryMsg = QueryBase<QueryExt>;
-
-/// Internal version of the propose message that includes the
-/// `proposer` field. The module will fill this in based on the sender
-/// of the external message.
-#[cw_serde]
-pub(crate) enum ProposeMessageInternal {
-    Propose(ProposeMsg),
-}
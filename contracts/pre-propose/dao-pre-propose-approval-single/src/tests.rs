@@ -2,7 +2,7 @@ use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Empty, Uint128};
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
-use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor};
 use cw_utils::Duration;
 use dao_core::state::ProposalModule;
 use dao_interface::{Admin, ModuleInstantiateInfo};
@@ -12,12 +12,17 @@ use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
     deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    proposal::{CheckedProposalDependency, ProposalDependency},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
     voting::Vote,
 };
 
-use crate::{contract::*, msg::*, state::PendingProposal};
+use crate::{
+    contract::*,
+    msg::*,
+    state::{ApprovalCommittee, ApproverDecision, Approvers, Decision, PendingProposal},
+};
 
 fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
@@ -65,8 +70,15 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
                     extension: InstantiateExt {
                         approver: "approver".to_string(),
+                        committee: None,
+                        pending_expiration: None,
                     },
                 })
                 .unwrap(),
@@ -75,6 +87,12 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
@@ -182,6 +200,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                depends_on: None,
             },
         },
         funds,
@@ -307,6 +326,11 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -328,6 +352,11 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -413,6 +442,68 @@ fn reject_proposal(app: &mut App, module: Addr, sender: &str, proposal_id: u64)
     .unwrap();
 }
 
+fn update_approval_settings(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    committee: Option<UncheckedApprovalCommittee>,
+    pending_expiration: Option<Duration>,
+) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateApprovalSettings {
+                committee,
+                pending_expiration,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_extension(app: &mut App, module: Addr, sender: &str, msg: ExecuteExt) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap();
+}
+
+fn execute_extension_should_fail(
+    app: &mut App,
+    module: Addr,
+    sender: &str,
+    msg: ExecuteExt,
+) -> PreProposeError {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        module,
+        &ExecuteMsg::Extension { msg },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+fn get_pending_proposals(app: &App, module: Addr) -> Vec<PendingProposal> {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap()
+}
+
 enum ApprovalStatus {
     Approved,
     Rejected,
@@ -448,6 +539,7 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -522,6 +614,7 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -775,6 +868,7 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -862,6 +956,7 @@ fn test_pending_proposal_queries() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -932,6 +1027,7 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -968,6 +1064,7 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false, // no open proposal submission.
     );
@@ -979,6 +1076,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                vetoed: false,
             },
             &[],
         )
@@ -998,6 +1096,7 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    depends_on: None,
                 },
             },
             &[],
@@ -1023,6 +1122,7 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1082,6 +1182,7 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1146,6 +1247,7 @@ fn test_no_deposit_required_members_submission() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    depends_on: None,
                 },
             },
             &[],
@@ -1192,10 +1294,18 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            committee: None,
+                            pending_expiration: None,
                         },
                     })
                     .unwrap(),
@@ -1204,6 +1314,12 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     };
 
@@ -1255,10 +1371,18 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: InstantiateExt {
                             approver: "approver".to_string(),
+                            committee: None,
+                            pending_expiration: None,
                         },
                     })
                     .unwrap(),
@@ -1267,6 +1391,12 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     };
 
@@ -1302,7 +1432,12 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1321,6 +1456,7 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
         }),
         true,
     );
@@ -1332,9 +1468,15 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             open_proposal_submission: true,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1345,6 +1487,7 @@ fn test_update_config() {
         DepositInfoResponse {
             deposit_info: None,
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1368,9 +1511,11 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1429,6 +1574,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1476,6 +1622,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1548,3 +1695,741 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+#[test]
+fn test_approval_committee() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    let committee = UncheckedApprovalCommittee {
+        approvers: UncheckedApprovers::Addresses {
+            addresses: vec!["alice".to_string(), "bob".to_string(), "carol".to_string()],
+        },
+        threshold: 4,
+    };
+
+    // Threshold may not exceed the number of approvers.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        ExecuteExt::UpdateApprovalSettings {
+            committee: Some(committee.clone()),
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    // Only the approver or the DAO may update approval settings.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::UpdateApprovalSettings {
+            committee: None,
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(UncheckedApprovalCommittee {
+            threshold: 2,
+            ..committee
+        }),
+        None,
+    );
+    let committee: Option<ApprovalCommittee> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApprovalCommittee {},
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        committee,
+        Some(ApprovalCommittee {
+            approvers: Approvers::Addresses {
+                addresses: vec![
+                    Addr::unchecked("alice"),
+                    Addr::unchecked("bob"),
+                    Addr::unchecked("carol")
+                ]
+            },
+            threshold: 2,
+        })
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let first = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // Non-members of the committee may not approve.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "dave",
+        ExecuteExt::Approve { id: first },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+
+    // One approval is not enough.
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id: first },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+
+    // Approvers may only decide once.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Reject { id: first },
+    );
+    assert_eq!(err, PreProposeError::AlreadyDecided {});
+
+    // The second approval creates the proposal.
+    let id = approve_proposal(&mut app, pre_propose.clone(), "bob", first);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+    let info = get_deposit_info(&app, pre_propose.clone(), id);
+    assert_eq!(info.proposer, Addr::unchecked("ekez"));
+
+    // With three approvers and a threshold of two, two rejections
+    // make approval impossible and reject the proposal.
+    let second = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Reject { id: second },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+    reject_proposal(&mut app, pre_propose.clone(), "carol", second);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // Deposit was refunded on rejection.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+
+    let decisions: Vec<ApproverDecision> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ProposalDecisions {
+                    id: second,
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        decisions,
+        vec![
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Reject,
+            },
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("carol"),
+                decision: Decision::Reject,
+            },
+        ]
+    );
+
+    let decisions: Vec<ApproverDecision> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApproverDecisions {
+                    address: "alice".to_string(),
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        decisions,
+        vec![
+            ApproverDecision {
+                approval_id: first,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Approve,
+            },
+            ApproverDecision {
+                approval_id: second,
+                approver: Addr::unchecked("alice"),
+                decision: Decision::Reject,
+            },
+        ]
+    );
+    // Decisions are paginated.
+    let decisions: Vec<ApproverDecision> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::ApproverDecisions {
+                    address: "alice".to_string(),
+                    start_after: Some(first),
+                    limit: Some(1),
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(decisions.len(), 1);
+    assert_eq!(decisions[0].approval_id, second);
+
+    // The approver may still decide alone, and its decision is final
+    // even after a committee member has approved.
+    let third = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id: third },
+    );
+    reject_proposal(&mut app, pre_propose.clone(), "approver", third);
+    assert!(get_pending_proposals(&app, pre_propose).is_empty());
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+}
+
+#[test]
+fn test_cw4_approval_committee() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let cw4_id = app.store_code(dao_testing::contracts::cw4_group_contract());
+    let group = app
+        .instantiate_contract(
+            cw4_id,
+            Addr::unchecked("ekez"),
+            &cw4_group::msg::InstantiateMsg {
+                admin: Some("ekez".to_string()),
+                members: vec![
+                    cw4::Member {
+                        addr: "alice".to_string(),
+                        weight: 1,
+                    },
+                    cw4::Member {
+                        addr: "bob".to_string(),
+                        weight: 3,
+                    },
+                    cw4::Member {
+                        addr: "carol".to_string(),
+                        weight: 0,
+                    },
+                ],
+            },
+            &[],
+            "approvers",
+            None,
+        )
+        .unwrap();
+    app.update_block(next_block);
+
+    // Threshold may not exceed the group's total weight. Zero weight
+    // members are not approvers.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        ExecuteExt::UpdateApprovalSettings {
+            committee: Some(UncheckedApprovalCommittee {
+                approvers: UncheckedApprovers::Cw4Group {
+                    group: group.to_string(),
+                },
+                threshold: 5,
+            }),
+            pending_expiration: None,
+        },
+    );
+    assert_eq!(err, PreProposeError::InvalidApprovalThreshold {});
+
+    // The approver may also configure a committee.
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        Some(UncheckedApprovalCommittee {
+            approvers: UncheckedApprovers::Cw4Group {
+                group: group.to_string(),
+            },
+            threshold: 4,
+        }),
+        None,
+    );
+
+    // Approvals are weighted by group membership.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "carol",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // Membership is read at the height the pending proposal was
+    // submitted. Members who join later may not decide on it, and
+    // members who leave keep their decisions.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id },
+    );
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        group.clone(),
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec!["alice".to_string()],
+            add: vec![cw4::Member {
+                addr: "dave".to_string(),
+                weight: 5,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "dave",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // If the group's total weight is below the threshold, the
+    // approval of every member is enough.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers {
+            remove: vec!["dave".to_string()],
+            add: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approve_proposal(&mut app, pre_propose.clone(), "bob", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+
+    // The approver may still decide alone.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    approve_proposal(&mut app, pre_propose.clone(), "approver", id);
+    assert_eq!(get_pending_proposals(&app, pre_propose).len(), 0);
+}
+
+#[test]
+fn test_pending_proposal_expiration() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        None,
+        Some(Duration::Height(10)),
+    );
+    let expiration: Option<Duration> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::PendingExpiration {},
+            },
+        )
+        .unwrap();
+    assert_eq!(expiration, Some(Duration::Height(10)));
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let expires = cw_utils::Expiration::AtHeight(app.block_info().height + 10);
+    assert_eq!(
+        get_pending_proposals(&app, pre_propose.clone())[0].expiration,
+        Some(expires)
+    );
+
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Expire { id },
+    );
+    assert_eq!(err, PreProposeError::PendingProposalNotExpired {});
+
+    app.update_block(|b| b.height += 10);
+
+    // Expired proposals may no longer be approved or rejected.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(
+        err,
+        PreProposeError::PendingProposalExpired {
+            expiration: expires
+        }
+    );
+
+    // Anyone may expire the proposal. The deposit is refunded even
+    // though the refund policy is `Never`.
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "someone",
+        ExecuteExt::Expire { id },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 0);
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+
+    let err =
+        execute_extension_should_fail(&mut app, pre_propose, "someone", ExecuteExt::Expire { id });
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+}
+
+#[test]
+fn test_edit_withdraw_and_return() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+
+    // The approver returns the proposal with a comment.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Return {
+            id,
+            comment: "needs work".to_string(),
+        },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Return {
+            id,
+            comment: "needs work".to_string(),
+        },
+    );
+    let pending = get_pending_proposals(&app, pre_propose.clone());
+    assert_eq!(pending[0].return_comment, Some("needs work".to_string()));
+
+    // Returned proposals may not be approved until edited.
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::PendingProposalReturned {});
+
+    // Only the proposer may edit.
+    let edit = ProposeMessage::Propose {
+        title: "better title".to_string(),
+        description: "better description".to_string(),
+        msgs: vec![],
+        depends_on: None,
+    };
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "keze",
+        ExecuteExt::Edit {
+            id,
+            msg: edit.clone(),
+        },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Edit { id, msg: edit },
+    );
+    let pending = get_pending_proposals(&app, pre_propose.clone());
+    assert_eq!(pending[0].msg.title, "better title");
+    assert_eq!(pending[0].msg.proposer, Some("ekez".to_string()));
+    assert_eq!(pending[0].return_comment, None);
+
+    // The edited proposal is moved to a new approval ID, and the old
+    // one may no longer be approved.
+    let new_id = pending[0].approval_id;
+    assert_eq!(new_id, id + 1);
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::Approve { id },
+    );
+    assert_eq!(err, PreProposeError::ProposalNotFound {});
+
+    // The edited proposal may be approved.
+    let proposal_id = approve_proposal(&mut app, pre_propose.clone(), "approver", new_id);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.title, "better title");
+
+    // Withdrawing refunds the deposit even though the refund policy
+    // is `Never`.
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &coins(10, "ujuno"));
+    let err = execute_extension_should_fail(
+        &mut app,
+        pre_propose.clone(),
+        "approver",
+        ExecuteExt::WithdrawProposal { id },
+    );
+    assert_eq!(err, PreProposeError::Unauthorized {});
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::WithdrawProposal { id },
+    );
+    assert!(get_pending_proposals(&app, pre_propose.clone()).is_empty());
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+    let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
+    assert_eq!(balance, Uint128::zero());
+}
+
+#[test]
+fn test_edit_resets_committee_approvals() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    update_approval_settings(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(UncheckedApprovalCommittee {
+            approvers: UncheckedApprovers::Addresses {
+                addresses: vec!["alice".to_string(), "bob".to_string()],
+            },
+            threshold: 2,
+        }),
+        None,
+    );
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id },
+    );
+
+    // Committee members may return proposals.
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "bob",
+        ExecuteExt::Return {
+            id,
+            comment: "please add messages".to_string(),
+        },
+    );
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "ekez",
+        ExecuteExt::Edit {
+            id,
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                depends_on: None,
+            },
+        },
+    );
+
+    // The earlier approval was cleared by the edit, and the edited
+    // proposal has a new approval ID.
+    let new_id = get_pending_proposals(&app, pre_propose.clone())[0].approval_id;
+    assert_eq!(new_id, id + 1);
+    for id in [id, new_id] {
+        let decisions: Vec<ApproverDecision> = app
+            .wrap()
+            .query_wasm_smart(
+                pre_propose.clone(),
+                &QueryMsg::QueryExtension {
+                    msg: QueryExt::ProposalDecisions {
+                        id,
+                        start_after: None,
+                        limit: None,
+                    },
+                },
+            )
+            .unwrap();
+        assert!(decisions.is_empty());
+    }
+
+    execute_extension(
+        &mut app,
+        pre_propose.clone(),
+        "alice",
+        ExecuteExt::Approve { id: new_id },
+    );
+    assert_eq!(get_pending_proposals(&app, pre_propose.clone()).len(), 1);
+    approve_proposal(&mut app, pre_propose.clone(), "bob", new_id);
+    assert!(get_pending_proposals(&app, pre_propose).is_empty());
+}
+
+#[test]
+fn test_proposal_dependencies() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let dependency_id = approve_proposal(&mut app, pre_propose.clone(), "approver", id);
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                depends_on: Some(vec![ProposalDependency {
+                    proposal_module: proposal_single.to_string(),
+                    proposal_id: dependency_id,
+                }]),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let pending = get_pending_proposals(&app, pre_propose.clone());
+    assert_eq!(
+        pending[0].msg.depends_on,
+        Some(vec![ProposalDependency {
+            proposal_module: proposal_single.to_string(),
+            proposal_id: dependency_id,
+        }])
+    );
+
+    // Dependencies are passed on to the proposal module once the
+    // proposal is approved.
+    let proposal_id = approve_proposal(&mut app, pre_propose, "approver", pending[0].approval_id);
+    let blocking: Vec<CheckedProposalDependency> = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single.clone(),
+            &dao_proposal_single::msg::QueryMsg::BlockingDependencies { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(
+        blocking,
+        vec![CheckedProposalDependency {
+            proposal_module: proposal_single,
+            proposal_id: dependency_id,
+        }]
+    );
+}
@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Response, StdResult,
-    WasmMsg,
+    to_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response,
+    StdResult, SubMsg, SubMsgResult, WasmMsg,
 };
 use cw2::set_contract_version;
 
@@ -23,6 +23,10 @@ pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 type PrePropose = PreProposeContract<Empty, Empty, QueryExt, ApproverProposeMessage>;
 
+/// Reply ID used when approving or rejecting a pending proposal in
+/// the approval contract.
+pub const DECISION_REPLY_ID: u64 = 0;
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn instantiate(
     mut deps: DepsMut,
@@ -35,6 +39,11 @@ pub fn instantiate(
     let base_instantiate_msg = BaseInstantiateMsg {
         deposit_info: None,
         open_proposal_submission: false,
+        submission_policy: None,
+        proposal_limits: None,
+        alternative_deposits: vec![],
+        nft_deposit: None,
+        submission_fee: None,
         extension: Empty {},
     };
     // Default pre-propose-base instantiation
@@ -85,6 +94,7 @@ pub fn execute(
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            ..
         } => execute_proposal_completed(deps, info, proposal_id, new_status),
         _ => PrePropose::default().execute(deps, env, info, msg),
     }
@@ -154,7 +164,7 @@ pub fn execute_proposal_completed(
 
     // On completion send rejection or approval message
     let msg = match new_status {
-        Status::Closed => Some(WasmMsg::Execute {
+        Status::Closed | Status::Expired => Some(WasmMsg::Execute {
             contract_addr: approval_contract.into_string(),
             msg: to_binary(&PreProposeApprovalExecuteMsg::Extension {
                 msg: ApprovalExt::Reject { id: pre_propose_id },
@@ -171,16 +181,30 @@ pub fn execute_proposal_completed(
         _ => None,
     };
 
-    // If Status is not Executed or Closed, throw error
+    // If Status is not Executed or Closed, throw error. The pending
+    // proposal may have been withdrawn, returned, edited, or expired
+    // since this proposal was created, in which case the decision
+    // fails. That failure is caught so that this hook does not fail
+    // and cause the proposal module to remove this module.
     match msg {
         Some(msg) => Ok(Response::default()
-            .add_message(msg)
+            .add_submessage(SubMsg::reply_on_error(msg, DECISION_REPLY_ID))
             .add_attribute("method", "execute_proposal_completed_hook")
             .add_attribute("proposal", proposal_id.to_string())),
         None => Err(PreProposeError::NotClosedOrExecuted { status: new_status }),
     }
 }
 
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, PreProposeError> {
+    match (msg.id, msg.result) {
+        (DECISION_REPLY_ID, SubMsgResult::Err(error)) => Ok(Response::default()
+            .add_attribute("method", "reply_decision")
+            .add_attribute("error", error)),
+        _ => Err(PreProposeError::UnknownReplyID {}),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
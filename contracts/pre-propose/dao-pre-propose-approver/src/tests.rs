@@ -10,6 +10,7 @@ use dao_interface::{Admin, ModuleInstantiateInfo};
 use dao_pre_propose_approval_single::{
     msg::{
         ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, QueryExt, QueryMsg,
+        UncheckedApprovalCommittee, UncheckedApprovers,
     },
     state::PendingProposal,
 };
@@ -64,7 +65,8 @@ fn pre_propose_approver_contract() -> Box<dyn Contract<Empty>> {
         crate::contract::execute,
         crate::contract::instantiate,
         crate::contract::query,
-    );
+    )
+    .with_reply(crate::contract::reply);
     Box::new(contract)
 }
 
@@ -89,8 +91,15 @@ fn get_proposal_module_approval_single_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
                     extension: InstantiateExt {
                         approver: APPROVER.to_string(),
+                        committee: None,
+                        pending_expiration: None,
                     },
                 })
                 .unwrap(),
@@ -99,6 +108,12 @@ fn get_proposal_module_approval_single_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
@@ -130,6 +145,12 @@ fn get_proposal_module_approver_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
@@ -301,6 +322,7 @@ fn make_pre_proposal(app: &mut App, pre_propose: Addr, proposer: &str, funds: &[
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                depends_on: None,
             },
         },
         funds,
@@ -450,6 +472,11 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -471,6 +498,11 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -574,6 +606,7 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -682,6 +715,7 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -974,6 +1008,7 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1071,6 +1106,7 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1113,6 +1149,7 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false, // no open proposal submission.
     );
@@ -1124,6 +1161,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                vetoed: false,
             },
             &[],
         )
@@ -1143,6 +1181,7 @@ fn test_permissions() {
                     title: "I would like to join the DAO".to_string(),
                     description: "though, I am currently not a member.".to_string(),
                     msgs: vec![],
+                    depends_on: None,
                 },
             },
             &[],
@@ -1175,6 +1214,7 @@ fn test_approval_and_rejection_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1241,6 +1281,7 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -1281,7 +1322,12 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1307,6 +1353,7 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
         }),
         true,
     );
@@ -1318,9 +1365,15 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             open_proposal_submission: true,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1331,6 +1384,7 @@ fn test_update_config() {
         DepositInfoResponse {
             deposit_info: None,
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1356,9 +1410,11 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1423,6 +1479,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1472,6 +1529,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1541,3 +1599,408 @@ fn test_withdraw() {
     let balance = get_balance_native(&app, core_addr.as_str(), "ujuno");
     assert_eq!(balance, Uint128::new(30));
 }
+
+fn pre_propose_approval_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_pre_propose_approval_multiple::contract::execute,
+        dao_pre_propose_approval_multiple::contract::instantiate,
+        dao_pre_propose_approval_multiple::contract::query,
+    );
+    Box::new(contract)
+}
+
+fn cw_dao_proposal_multiple_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_multiple::contract::execute,
+        dao_proposal_multiple::contract::instantiate,
+        dao_proposal_multiple::contract::query,
+    )
+    .with_reply(dao_proposal_multiple::contract::reply);
+    Box::new(contract)
+}
+
+#[test]
+fn test_approval_multiple() {
+    use dao_pre_propose_approval_multiple::msg as pam;
+    use dao_voting::multiple_choice::{
+        MultipleChoiceOption, MultipleChoiceOptions, VotingStrategy,
+    };
+
+    let mut app = App::default();
+    let cps_id = app.store_code(cw_dao_proposal_single_contract());
+    let cpm_id = app.store_code(cw_dao_proposal_multiple_contract());
+    let approval_id = app.store_code(pre_propose_approval_multiple_contract());
+    let members = Some(vec![
+        cw20::Cw20Coin {
+            address: "ekez".to_string(),
+            amount: Uint128::new(9),
+        },
+        cw20::Cw20Coin {
+            address: "keze".to_string(),
+            amount: Uint128::new(8),
+        },
+    ]);
+
+    // Instantiate a DAO whose multiple choice proposals require
+    // approval.
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        cpm_id,
+        to_binary(&dao_proposal_multiple::msg::InstantiateMsg {
+            voting_strategy: VotingStrategy::SingleChoice {
+                quorum: PercentageThreshold::Majority {},
+            },
+            max_voting_period: cw_utils::Duration::Time(86400),
+            min_voting_period: None,
+            only_members_execute: false,
+            allow_revoting: false,
+            pre_propose_info: PreProposeInfo::ModuleMayPropose {
+                info: ModuleInstantiateInfo {
+                    code_id: approval_id,
+                    msg: to_binary(&pam::InstantiateMsg {
+                        deposit_info: None,
+                        open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
+                        extension: pam::InstantiateExt {
+                            // The approver DAO is the 6th contract
+                            // instantiated, it sets this contract's
+                            // approver to the approver contract.
+                            approver: "contract5".to_string(),
+                            committee: None,
+                            pending_expiration: None,
+                        },
+                    })
+                    .unwrap(),
+                    admin: Some(Admin::CoreModule {}),
+                    label: "multiple choice approval".to_string(),
+                },
+            },
+            close_proposal_on_execution_failure: false,
+            early_execution: None,
+        })
+        .unwrap(),
+        members.clone(),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr,
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_multiple = proposal_modules.into_iter().next().unwrap().address;
+    let pre_propose = match app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple.clone(),
+            &dao_proposal_multiple::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap()
+    {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    // Instantiate the approver DAO for the approval contract.
+    let proposal_module_instantiate =
+        get_proposal_module_approver_instantiate(&mut app, None, false, pre_propose.to_string());
+    let approver_core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        cps_id,
+        to_binary(&proposal_module_instantiate).unwrap(),
+        members,
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            approver_core_addr,
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_single_approver = proposal_modules.into_iter().next().unwrap().address;
+
+    // Submitting a multiple choice proposal creates an approval
+    // proposal in the approver DAO.
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &pam::ExecuteMsg::Propose {
+            msg: pam::ProposeMessage::Propose {
+                title: "budget".to_string(),
+                description: "how much should we spend?".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            title: "a little".to_string(),
+                            description: "a little".to_string(),
+                            msgs: vec![],
+                        },
+                        MultipleChoiceOption {
+                            title: "a lot".to_string(),
+                            description: "a lot".to_string(),
+                            msgs: vec![],
+                        },
+                    ],
+                },
+                depends_on: None,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    let approval_proposal = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver.clone(),
+            &cps::msg::QueryMsg::Proposal {
+                proposal_id: approval_proposal,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.title, "budget");
+
+    // Approving it creates the multiple choice proposal.
+    approve_proposal(
+        &mut app,
+        proposal_single_approver,
+        "ekez",
+        approval_proposal,
+    );
+    let pending: Vec<dao_pre_propose_approval_multiple::state::PendingProposal> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &pam::QueryMsg::QueryExtension {
+                msg: pam::QueryExt::PendingProposals {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert!(pending.is_empty());
+
+    let proposal: dao_proposal_multiple::query::ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_multiple,
+            &dao_proposal_multiple::msg::QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.title, "budget");
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    assert_eq!(proposal.proposal.choices.len(), 3);
+}
+
+#[test]
+fn test_decision_on_withdrawn_proposal() {
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+        _approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver,
+    } = setup_default_test(&mut app, None, false);
+
+    // Withdraw two pending proposals while the approver DAO's
+    // proposals to decide on them are still open.
+    make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let approve_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let reject_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    for id in [1, 2] {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::WithdrawProposal { id },
+            },
+            &[],
+        )
+        .unwrap();
+    }
+
+    // Completing the approver DAO's proposals does not fail, and the
+    // approver remains the approver DAO's proposal creation policy.
+    approve_proposal(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        approve_id,
+    );
+    vote(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        reject_id,
+        Vote::No,
+    );
+    close_proposal(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        reject_id,
+    );
+
+    let policy: ProposalCreationPolicy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver,
+            &cps::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+    assert_eq!(
+        policy,
+        ProposalCreationPolicy::Module {
+            addr: pre_propose_approver
+        }
+    );
+    assert!(get_proposals(&app, proposal_single).proposals.is_empty());
+}
+
+#[test]
+fn test_edit_resubmits_to_approver() {
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+        _approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver: _,
+    } = setup_default_test(&mut app, None, false);
+
+    let id = make_pre_proposal(&mut app, pre_propose.clone(), "ekez", &[]);
+    let old_approver_prop_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::Edit {
+                id,
+                msg: ProposeMessage::Propose {
+                    title: "edited".to_string(),
+                    description: "description".to_string(),
+                    msgs: vec![],
+                    depends_on: None,
+                },
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The edit creates a new proposal on the approver DAO.
+    let new_approver_prop_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    assert_eq!(new_approver_prop_id, old_approver_prop_id + 1);
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver.clone(),
+            &cps::msg::QueryMsg::Proposal {
+                proposal_id: new_approver_prop_id,
+            },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.title, "edited");
+
+    // Passing the approver DAO's proposal for the old content does
+    // not approve the edited proposal.
+    approve_proposal(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        old_approver_prop_id,
+    );
+    assert!(get_proposals(&app, proposal_single.clone())
+        .proposals
+        .is_empty());
+
+    approve_proposal(
+        &mut app,
+        proposal_single_approver,
+        "ekez",
+        new_approver_prop_id,
+    );
+    let proposals = get_proposals(&app, proposal_single);
+    assert_eq!(proposals.proposals.len(), 1);
+    assert_eq!(proposals.proposals[0].proposal.title, "edited");
+}
+
+#[test]
+fn test_approve_with_committee() {
+    let mut app = App::default();
+
+    // Need to instantiate this so contract addresses match with cw20 test cases
+    let _ = instantiate_cw20_base_default(&mut app);
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+        _approver_core_addr: _,
+        proposal_single_approver,
+        pre_propose_approver: _,
+    } = setup_default_test(&mut app, None, false);
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::UpdateApprovalSettings {
+                committee: Some(UncheckedApprovalCommittee {
+                    approvers: UncheckedApprovers::Addresses {
+                        addresses: vec!["alice".to_string(), "bob".to_string()],
+                    },
+                    threshold: 2,
+                }),
+                pending_expiration: None,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    // The approver DAO may still approve proposals once a committee
+    // is configured.
+    make_pre_proposal(&mut app, pre_propose, "ekez", &[]);
+    let approver_prop_id = get_latest_proposal_id(&app, proposal_single_approver.clone());
+    approve_proposal(
+        &mut app,
+        proposal_single_approver.clone(),
+        "ekez",
+        approver_prop_id,
+    );
+    assert_eq!(get_proposals(&app, proposal_single).proposals.len(), 1);
+
+    let policy: ProposalCreationPolicy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single_approver,
+            &cps::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+    assert!(matches!(policy, ProposalCreationPolicy::Module { .. }));
+}
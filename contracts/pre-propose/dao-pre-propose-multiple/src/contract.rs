@@ -9,7 +9,7 @@ use dao_pre_propose_base::{
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::multiple_choice::MultipleChoiceOptions;
+use dao_voting::{multiple_choice::MultipleChoiceOptions, proposal::ProposalDependency};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-multiple";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -20,6 +20,7 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         choices: MultipleChoiceOptions,
+        depends_on: Option<Vec<ProposalDependency>>,
     },
 }
 
@@ -37,6 +38,7 @@ enum ProposeMessageInternal {
         description: String,
         choices: MultipleChoiceOptions,
         proposer: Option<String>,
+        depends_on: Option<Vec<ProposalDependency>>,
     },
 }
 
@@ -73,6 +75,7 @@ pub fn execute(
                     title,
                     description,
                     choices,
+                    depends_on,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose {
@@ -80,16 +83,29 @@ pub fn execute(
                 title,
                 description,
                 choices,
+                depends_on,
             },
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
+        ExecuteMsg::ReceiveNft(msg) => ExecuteInternal::ReceiveNft(msg),
+        ExecuteMsg::ReturnNft {} => ExecuteInternal::ReturnNft {},
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -97,12 +113,17 @@ pub fn execute(
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteBase::RefundDeposits { proposal_ids } => {
+            ExecuteInternal::RefundDeposits { proposal_ids }
+        }
         ExecuteBase::ProposalCompletedHook {
             proposal_id,
             new_status,
+            vetoed,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            vetoed,
         },
     };
 
@@ -68,6 +68,11 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -76,6 +81,7 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        early_execution: None,
     }
 }
 
@@ -201,6 +207,7 @@ fn make_proposal(
                         },
                     ],
                 },
+                depends_on: None,
             },
         },
         funds,
@@ -361,6 +368,11 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -382,6 +394,11 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -465,6 +482,7 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -537,6 +555,7 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -713,6 +732,7 @@ fn test_multiple_open_proposals() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -795,6 +815,7 @@ fn test_set_version() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -831,6 +852,7 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false, // no open proposal submission.
     );
@@ -842,6 +864,7 @@ fn test_permissions() {
             &ExecuteMsg::ProposalCompletedHook {
                 proposal_id: 1,
                 new_status: Status::Closed,
+                vetoed: false,
             },
             &[],
         )
@@ -867,6 +890,7 @@ fn test_permissions() {
                             title: "title".to_string(),
                         }],
                     },
+                    depends_on: None,
                 },
             },
             &[],
@@ -892,6 +916,7 @@ fn test_propose_open_proposal_submission() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         true, // yes, open proposal submission.
     );
@@ -973,6 +998,7 @@ fn test_no_deposit_required_members_submission() {
                             title: "title".to_string(),
                         }],
                     },
+                    depends_on: None,
                 },
             },
             &[],
@@ -1053,8 +1079,14 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1063,6 +1095,7 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
         }
     };
 
@@ -1114,8 +1147,14 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1124,6 +1163,7 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
         }
     };
 
@@ -1159,7 +1199,12 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1181,6 +1226,7 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
         }),
         true,
     );
@@ -1192,9 +1238,15 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             open_proposal_submission: true,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1205,6 +1257,7 @@ fn test_update_config() {
         DepositInfoResponse {
             deposit_info: None,
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1224,9 +1277,11 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1297,6 +1352,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1341,6 +1397,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
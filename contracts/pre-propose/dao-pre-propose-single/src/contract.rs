@@ -11,7 +11,7 @@ use dao_pre_propose_base::{
     msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase},
     state::PreProposeContract,
 };
-use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+use dao_voting::proposal::{ProposalDependency, SingleChoiceProposeMsg as ProposeMsg};
 
 pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-single";
 pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -26,6 +26,7 @@ pub enum ProposeMessage {
         title: String,
         description: String,
         msgs: Vec<CosmosMsg<Empty>>,
+        depends_on: Option<Vec<ProposalDependency>>,
     },
 }
 
@@ -74,24 +75,39 @@ pub fn execute(
                     title,
                     description,
                     msgs,
+                    depends_on,
                 },
         } => ExecuteInternal::Propose {
             msg: ProposeMessageInternal::Propose(ProposeMsg {
                 // Fill in proposer based on message sender.
                 proposer: Some(info.sender.to_string()),
+                threshold: None,
                 title,
                 description,
                 msgs,
+                depends_on,
             }),
         },
         ExecuteMsg::Extension { msg } => ExecuteInternal::Extension { msg },
         ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
+        ExecuteMsg::ReceiveNft(msg) => ExecuteInternal::ReceiveNft(msg),
+        ExecuteMsg::ReturnNft {} => ExecuteInternal::ReturnNft {},
         ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
         } => ExecuteInternal::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
         },
         ExecuteMsg::AddProposalSubmittedHook { address } => {
             ExecuteInternal::AddProposalSubmittedHook { address }
@@ -99,12 +115,17 @@ pub fn execute(
         ExecuteMsg::RemoveProposalSubmittedHook { address } => {
             ExecuteInternal::RemoveProposalSubmittedHook { address }
         }
+        ExecuteMsg::RefundDeposits { proposal_ids } => {
+            ExecuteInternal::RefundDeposits { proposal_ids }
+        }
         ExecuteMsg::ProposalCompletedHook {
             proposal_id,
             new_status,
+            vetoed,
         } => ExecuteInternal::ProposalCompletedHook {
             proposal_id,
             new_status,
+            vetoed,
         },
     };
 
@@ -1,17 +1,27 @@
-use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Empty, Uint128};
+use cosmwasm_std::{coins, from_slice, to_binary, Addr, Coin, Decimal, Empty, Uint128};
 use cps::query::ProposalResponse;
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
 use cw_denom::UncheckedDenom;
 use cw_multi_test::{App, BankSudo, Contract, ContractWrapper, Executor};
-use cw_utils::Duration;
+use cw_utils::{Duration, Expiration};
 use dao_core::state::ProposalModule;
 use dao_interface::{Admin, ModuleInstantiateInfo};
-use dao_pre_propose_base::{error::PreProposeError, msg::DepositInfoResponse, state::Config};
+use dao_pre_propose_base::{
+    error::PreProposeError,
+    msg::{
+        DepositInfoResponse, OutstandingDeposit, OutstandingDepositTotal, ProposerUsageResponse,
+        UncheckedSubmissionPolicy,
+    },
+    state::{Config, DepositOutcome, EscrowedNft, NftDeposit, ProposalLimits, SubmissionPolicy},
+};
 use dao_proposal_single as cps;
 use dao_testing::helpers::instantiate_with_cw4_groups_governance;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, DepositRefundPolicy, DepositToken, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, CheckedSubmissionFee, DepositError, DepositRefundPolicy, DepositToken,
+        FeeRecipient, UncheckedDepositInfo, UncheckedNftDepositInfo, UncheckedSubmissionFee,
+    },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
@@ -66,6 +76,11 @@ fn get_default_proposal_module_instantiate(
                 msg: to_binary(&InstantiateMsg {
                     deposit_info,
                     open_proposal_submission,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -74,6 +89,12 @@ fn get_default_proposal_module_instantiate(
             },
         },
         close_proposal_on_execution_failure: false,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
@@ -186,6 +207,7 @@ fn make_proposal(
                 title: "title".to_string(),
                 description: "description".to_string(),
                 msgs: vec![],
+                depends_on: None,
             },
         },
         funds,
@@ -342,6 +364,11 @@ fn update_config(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -363,6 +390,11 @@ fn update_config_should_fail(
         &ExecuteMsg::UpdateConfig {
             deposit_info,
             open_proposal_submission,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         },
         &[],
     )
@@ -446,6 +478,7 @@ fn test_native_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -510,6 +543,7 @@ fn test_cw20_permutation(
             },
             amount: Uint128::new(10),
             refund_policy,
+            slash_recipient: None,
         }),
         false,
     );
@@ -553,7 +587,7 @@ fn test_cw20_permutation(
         RefundReceiver::Dao => (10, 0),
     };
 
-    let proposer_balance = get_balance_cw20(&app, &cw20_address, "ekez");
+    let proposer_balance = get_balance_cw20(&app, cw20_address.clone(), "ekez");
     let dao_balance = get_balance_cw20(&app, &cw20_address, core_addr);
     assert_eq!(proposer_expected, proposer_balance.u128());
     assert_eq!(dao_expected, dao_balance.u128())
@@ -663,12 +697,17 @@ fn test_cw20_failed_passed_refund() {
 }
 
 // See: <https://github.com/DA0-DA0/dao-contracts/pull/465#discussion_r960092321>
+fn get_deposit_outcome(app: &App, module: &Addr, id: u64) -> Option<DepositOutcome> {
+    app.wrap()
+        .query_wasm_smart(module, &QueryMsg::DepositOutcome { proposal_id: id })
+        .unwrap()
+}
+
 #[test]
-fn test_multiple_open_proposals() {
+fn test_partial_refund_slash_recipient() {
     let mut app = App::default();
-
     let DefaultTestSetup {
-        core_addr: _,
+        core_addr,
         proposal_single,
         pre_propose,
     } = setup_default_test(
@@ -678,80 +717,55 @@ fn test_multiple_open_proposals() {
                 denom: UncheckedDenom::Native("ujuno".to_string()),
             },
             amount: Uint128::new(10),
-            refund_policy: DepositRefundPolicy::Always,
+            refund_policy: DepositRefundPolicy::Partial {
+                percent: Decimal::percent(30),
+            },
+            slash_recipient: Some("treasury".to_string()),
         }),
         false,
     );
 
-    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
-    let first_id = make_proposal(
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_proposal(
         &mut app,
         pre_propose.clone(),
         proposal_single.clone(),
         "ekez",
         &coins(10, "ujuno"),
     );
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
+    assert_eq!(get_deposit_outcome(&app, &pre_propose, id), None);
 
-    let second_id = make_proposal(
-        &mut app,
-        pre_propose,
-        proposal_single.clone(),
-        "ekez",
-        &coins(10, "ujuno"),
-    );
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(0, balance.u128());
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single, "ekez", id);
 
-    // Finish up the first proposal.
-    let new_status = vote(
-        &mut app,
-        proposal_single.clone(),
-        "ekez",
-        first_id,
-        Vote::Yes,
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(3));
+    assert_eq!(
+        get_balance_native(&app, "treasury", "ujuno"),
+        Uint128::new(7)
     );
-    assert_eq!(Status::Passed, new_status);
-
-    // Still zero.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(0, balance.u128());
-
-    execute_proposal(&mut app, proposal_single.clone(), "ekez", first_id);
-
-    // First proposal refunded.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
-
-    // Finish up the second proposal.
-    let new_status = vote(
-        &mut app,
-        proposal_single.clone(),
-        "ekez",
-        second_id,
-        Vote::No,
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::zero()
+    );
+    assert_eq!(
+        get_deposit_outcome(&app, &pre_propose, id),
+        Some(DepositOutcome {
+            status: Status::Closed,
+            proposer: Addr::unchecked("ekez"),
+            refunded: Uint128::new(3),
+            slash_recipient: Addr::unchecked("treasury"),
+            slashed: Uint128::new(7),
+        })
     );
-    assert_eq!(Status::Rejected, new_status);
-
-    // Still zero.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(10, balance.u128());
-
-    close_proposal(&mut app, proposal_single, "ekez", second_id);
-
-    // All deposits have been refunded.
-    let balance = get_balance_native(&app, "ekez", "ujuno");
-    assert_eq!(20, balance.u128());
 }
 
 #[test]
-fn test_set_version() {
+fn test_slash_on_spam() {
     let mut app = App::default();
-
     let DefaultTestSetup {
-        core_addr: _,
-        proposal_single: _,
+        core_addr,
+        proposal_single,
         pre_propose,
     } = setup_default_test(
         &mut app,
@@ -760,34 +774,62 @@ fn test_set_version() {
                 denom: UncheckedDenom::Native("ujuno".to_string()),
             },
             amount: Uint128::new(10),
-            refund_policy: DepositRefundPolicy::Always,
+            refund_policy: DepositRefundPolicy::SlashOnSpam {
+                no_threshold: Decimal::percent(50),
+            },
+            slash_recipient: None,
         }),
         false,
     );
 
-    let info: ContractVersion = from_slice(
-        &app.wrap()
-            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
-            .unwrap()
-            .unwrap(),
-    )
-    .unwrap();
+    // Rejected with only "no" votes. This is spam and the deposit
+    // goes to the DAO.
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", id);
+
     assert_eq!(
-        ContractVersion {
-            contract: CONTRACT_NAME.to_string(),
-            version: CONTRACT_VERSION.to_string()
-        },
-        info
-    )
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+    let outcome = get_deposit_outcome(&app, &pre_propose, id).unwrap();
+    assert_eq!(outcome.slashed, Uint128::new(10));
+    assert_eq!(outcome.slash_recipient, core_addr);
+
+    // Rejected because of abstentions. This is not spam and the
+    // deposit is refunded.
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Abstain);
+    let new_status = vote(&mut app, proposal_single.clone(), "keze", id, Vote::Abstain);
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single, "ekez", id);
+
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    let outcome = get_deposit_outcome(&app, &pre_propose, id).unwrap();
+    assert_eq!(outcome.refunded, Uint128::new(10));
+    assert_eq!(outcome.slashed, Uint128::zero());
 }
 
 #[test]
-fn test_permissions() {
+fn test_vetoed_deposit_slashed() {
     let mut app = App::default();
-
     let DefaultTestSetup {
         core_addr,
-        proposal_single: _,
+        proposal_single,
         pre_propose,
     } = setup_default_test(
         &mut app,
@@ -797,84 +839,749 @@ fn test_permissions() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: Some("community_pool".to_string()),
         }),
-        false, // no open proposal submission.
+        false,
     );
 
-    let err: PreProposeError = app
-        .execute_contract(
-            core_addr,
-            pre_propose.clone(),
-            &ExecuteMsg::ProposalCompletedHook {
-                proposal_id: 1,
-                new_status: Status::Closed,
+    let config: cps::state::Config = app
+        .wrap()
+        .query_wasm_smart(&proposal_single, &cps::msg::QueryMsg::Config {})
+        .unwrap();
+    app.execute_contract(
+        core_addr,
+        proposal_single.clone(),
+        &cps::msg::ExecuteMsg::UpdateConfig {
+            threshold: config.threshold,
+            max_voting_period: config.max_voting_period,
+            min_voting_period: config.min_voting_period,
+            only_members_execute: config.only_members_execute,
+            allow_revoting: config.allow_revoting,
+            dao: config.dao.into_string(),
+            close_proposal_on_execution_failure: config.close_proposal_on_execution_failure,
+            early_execution: config.early_execution,
+            execution_window: config.execution_window,
+            retry_policy: config.retry_policy,
+            amendment_window: config.amendment_window,
+            reveal_period: config.reveal_period,
+            veto_threshold: Some(PercentageThreshold::Percent(Decimal::percent(33))),
+        },
+        &[],
+    )
+    .unwrap();
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+
+    // A majority has voted yes, but the outstanding votes could
+    // still veto the proposal.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(new_status, Status::Open);
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "keze",
+        id,
+        Vote::NoWithVeto,
+    );
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single, "ekez", id);
+
+    // The deposit is not refunded despite the refund policy.
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        get_balance_native(&app, "community_pool", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(
+        get_deposit_outcome(&app, &pre_propose, id),
+        Some(DepositOutcome {
+            status: Status::Closed,
+            proposer: Addr::unchecked("ekez"),
+            refunded: Uint128::zero(),
+            slash_recipient: Addr::unchecked("community_pool"),
+            slashed: Uint128::new(10),
+        })
+    );
+}
+
+fn update_deposits(
+    app: &mut App,
+    core_addr: &Addr,
+    pre_propose: &Addr,
+    deposit_info: Option<UncheckedDepositInfo>,
+    alternative_deposits: Vec<UncheckedDepositInfo>,
+    nft_deposit: Option<UncheckedNftDepositInfo>,
+) -> Result<Config, PreProposeError> {
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info,
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee: None,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast::<PreProposeError>().unwrap())?;
+    Ok(get_config(app, pre_propose.clone()))
+}
+
+fn instantiate_cw721_base(app: &mut App, tokens: &[(&str, &str)]) -> Addr {
+    let cw721_id = app.store_code(dao_testing::contracts::cw721_base_contract());
+    let cw721 = app
+        .instantiate_contract(
+            cw721_id,
+            Addr::unchecked("ekez"),
+            &cw721_base::msg::InstantiateMsg {
+                name: "ekez".to_string(),
+                symbol: "ekez".to_string(),
+                minter: "ekez".to_string(),
             },
             &[],
+            "cw721",
+            None,
         )
-        .unwrap_err()
-        .downcast()
         .unwrap();
-    assert_eq!(err, PreProposeError::NotModule {});
+    for (token_id, owner) in tokens {
+        app.execute_contract(
+            Addr::unchecked("ekez"),
+            cw721.clone(),
+            &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::Mint(cw721_base::msg::MintMsg::<
+                Option<Empty>,
+            > {
+                token_id: token_id.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: None,
+            }),
+            &[],
+        )
+        .unwrap();
+    }
+    cw721
+}
 
-    // Non-members may not propose when open_propose_submission is
-    // disabled.
-    let err: PreProposeError = app
-        .execute_contract(
-            Addr::unchecked("nonmember"),
-            pre_propose,
-            &ExecuteMsg::Propose {
-                msg: ProposeMessage::Propose {
-                    title: "I would like to join the DAO".to_string(),
-                    description: "though, I am currently not a member.".to_string(),
-                    msgs: vec![],
-                },
+fn escrow_nft(app: &mut App, cw721: &Addr, sender: &str, pre_propose: &Addr, token_id: &str) {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        cw721.clone(),
+        &cw721_base::msg::ExecuteMsg::<Option<Empty>, Empty>::SendNft {
+            contract: pre_propose.to_string(),
+            token_id: token_id.to_string(),
+            msg: to_binary("").unwrap(),
+        },
+        &[],
+    )
+    .unwrap();
+}
+
+fn get_nft_owner(app: &App, cw721: &Addr, token_id: &str) -> String {
+    let res: cw721::OwnerOfResponse = app
+        .wrap()
+        .query_wasm_smart(
+            cw721,
+            &cw721::Cw721QueryMsg::OwnerOf {
+                token_id: token_id.to_string(),
+                include_expired: None,
             },
-            &[],
         )
-        .unwrap_err()
-        .downcast()
         .unwrap();
-    assert_eq!(err, PreProposeError::NotMember {})
+    res.owner
 }
 
 #[test]
-fn test_propose_open_proposal_submission() {
+fn test_alternative_deposits() {
     let mut app = App::default();
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+    let native_deposit = UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::Always,
+        slash_recipient: None,
+    };
     let DefaultTestSetup {
-        core_addr: _,
+        core_addr,
         proposal_single,
         pre_propose,
-    } = setup_default_test(
+    } = setup_default_test(&mut app, Some(native_deposit.clone()), false);
+
+    let err = update_deposits(
         &mut app,
-        Some(UncheckedDepositInfo {
+        &core_addr,
+        &pre_propose,
+        Some(native_deposit.clone()),
+        vec![native_deposit.clone()],
+        None,
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::DuplicateDepositDenom {});
+
+    update_deposits(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        Some(native_deposit),
+        vec![UncheckedDepositInfo {
             denom: DepositToken::Token {
-                denom: UncheckedDenom::Native("ujuno".to_string()),
+                denom: UncheckedDenom::Cw20(cw20_address.to_string()),
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
-        }),
-        true, // yes, open proposal submission.
+            slash_recipient: None,
+        }],
+        None,
+    )
+    .unwrap();
+
+    // Neither deposit paid.
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(err, PreProposeError::NoMatchingDeposit {});
+
+    // Pay with the cw20 alternative.
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(10),
+    );
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), "ekez"),
+        Uint128::zero()
+    );
+    let deposit = get_deposit_info(&app, pre_propose.clone(), id);
+    assert_eq!(
+        deposit.deposit_info.unwrap().denom,
+        cw_denom::CheckedDenom::Cw20(cw20_address.clone())
     );
 
-    // Non-member proposes.
-    mint_natives(&mut app, "nonmember", coins(10, "ujuno"));
+    // Pay with the native deposit.
+    mint_natives(&mut app, "keze", coins(10, "ujuno"));
     let id = make_proposal(
         &mut app,
-        pre_propose,
+        pre_propose.clone(),
         proposal_single.clone(),
-        "nonmember",
+        "keze",
         &coins(10, "ujuno"),
     );
-    // Member votes.
-    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
-    assert_eq!(Status::Passed, new_status)
+    let deposit = get_deposit_info(&app, pre_propose, id);
+    assert_eq!(
+        deposit.deposit_info.unwrap().denom,
+        cw_denom::CheckedDenom::Native("ujuno".to_string())
+    );
 }
 
-#[test]
-fn test_no_deposit_required_open_submission() {
-    let mut app = App::default();
-    let DefaultTestSetup {
-        core_addr: _,
+fn update_submission_fee(
+    app: &mut App,
+    core_addr: &Addr,
+    pre_propose: &Addr,
+    deposit_info: Option<UncheckedDepositInfo>,
+    submission_fee: Option<UncheckedSubmissionFee>,
+) -> Result<Config, PreProposeError> {
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info,
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast::<PreProposeError>().unwrap())?;
+    Ok(get_config(app, pre_propose.clone()))
+}
+
+#[test]
+fn test_submission_fee() {
+    let mut app = App::default();
+    let cw20_address = instantiate_cw20_base_default(&mut app);
+    let native_deposit = UncheckedDepositInfo {
+        denom: DepositToken::Token {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+        },
+        amount: Uint128::new(10),
+        refund_policy: DepositRefundPolicy::Always,
+        slash_recipient: None,
+    };
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, Some(native_deposit.clone()), false);
+
+    let err = update_submission_fee(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        Some(native_deposit.clone()),
+        Some(UncheckedSubmissionFee {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::zero(),
+            recipient: FeeRecipient::Dao {},
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::ZeroSubmissionFee)
+    );
+
+    let config = update_submission_fee(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        Some(native_deposit),
+        Some(UncheckedSubmissionFee {
+            denom: UncheckedDenom::Native("ujuno".to_string()),
+            amount: Uint128::new(5),
+            recipient: FeeRecipient::Dao {},
+        }),
+    )
+    .unwrap();
+    let native_fee = CheckedSubmissionFee {
+        denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+        amount: Uint128::new(5),
+        recipient: FeeRecipient::Dao {},
+    };
+    assert_eq!(config.submission_fee, Some(native_fee.clone()));
+
+    // The fee must be paid in addition to the deposit.
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(
+        err,
+        PreProposeError::Deposit(DepositError::InvalidSubmissionFee {
+            actual: Uint128::zero(),
+            expected: Uint128::new(5)
+        })
+    );
+
+    mint_natives(&mut app, "ekez", coins(15, "ujuno"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(15, "ujuno"),
+    );
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+    assert_eq!(
+        get_balance_native(&app, pre_propose.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+    let deposit = get_deposit_info(&app, pre_propose.clone(), id);
+    assert_eq!(deposit.submission_fee, Some(native_fee));
+
+    // Only the deposit is refunded.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(5)
+    );
+
+    // cw20 fees are taken from the proposer's allowance and may be
+    // burned.
+    update_submission_fee(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        None,
+        Some(UncheckedSubmissionFee {
+            denom: UncheckedDenom::Cw20(cw20_address.to_string()),
+            amount: Uint128::new(4),
+            recipient: FeeRecipient::Burn {},
+        }),
+    )
+    .unwrap();
+    increase_allowance(
+        &mut app,
+        "ekez",
+        &pre_propose,
+        cw20_address.clone(),
+        Uint128::new(4),
+    );
+    let id = make_proposal(&mut app, pre_propose.clone(), proposal_single, "ekez", &[]);
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), "ekez"),
+        Uint128::new(6)
+    );
+    assert_eq!(
+        get_balance_cw20(&app, cw20_address.clone(), core_addr),
+        Uint128::zero()
+    );
+    let token_info: cw20::TokenInfoResponse = app
+        .wrap()
+        .query_wasm_smart(cw20_address, &cw20::Cw20QueryMsg::TokenInfo {})
+        .unwrap();
+    assert_eq!(token_info.total_supply, Uint128::new(6));
+    let deposit = get_deposit_info(&app, pre_propose, id);
+    assert_eq!(deposit.deposit_info, None);
+    assert_eq!(deposit.submission_fee.unwrap().amount, Uint128::new(4));
+}
+
+#[test]
+fn test_nft_deposit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+    let cw721 = instantiate_cw721_base(&mut app, &[("1", "ekez"), ("2", "keze")]);
+
+    update_deposits(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        None,
+        vec![],
+        Some(UncheckedNftDepositInfo {
+            collection: cw721.to_string(),
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
+        }),
+    )
+    .unwrap();
+
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(err, PreProposeError::NftDepositRequired {});
+
+    escrow_nft(&mut app, &cw721, "ekez", &pre_propose, "1");
+    let escrowed: Option<EscrowedNft> = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::EscrowedNft {
+                address: "ekez".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        escrowed,
+        Some(EscrowedNft {
+            collection: cw721.clone(),
+            token_id: "1".to_string()
+        })
+    );
+
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    let nft_deposit: Option<NftDeposit> = app
+        .wrap()
+        .query_wasm_smart(&pre_propose, &QueryMsg::NftDeposit { proposal_id: id })
+        .unwrap();
+    assert_eq!(nft_deposit.unwrap().token_id, "1");
+    assert_eq!(get_nft_owner(&app, &cw721, "1"), pre_propose.to_string());
+
+    // Passed proposals get their NFT back.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(new_status, Status::Passed);
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(get_nft_owner(&app, &cw721, "1"), "ekez");
+
+    // The returned NFT is no longer reported as held and its outcome
+    // is recorded.
+    let nft_deposit: Option<NftDeposit> = app
+        .wrap()
+        .query_wasm_smart(&pre_propose, &QueryMsg::NftDeposit { proposal_id: id })
+        .unwrap();
+    assert_eq!(nft_deposit, None);
+    let outcome: Option<DepositOutcome> = app
+        .wrap()
+        .query_wasm_smart(&pre_propose, &QueryMsg::DepositOutcome { proposal_id: id })
+        .unwrap();
+    let outcome = outcome.unwrap();
+    assert_eq!(outcome.refunded, Uint128::one());
+    assert_eq!(outcome.slashed, Uint128::zero());
+
+    // A repeated completion hook does nothing. Were it to transfer
+    // the NFT again it would fail as the NFT is no longer held.
+    app.execute_contract(
+        proposal_single.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::ProposalCompletedHook {
+            proposal_id: id,
+            new_status: Status::Executed,
+            vetoed: false,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_nft_owner(&app, &cw721, "1"), "ekez");
+
+    // Unused NFTs may be returned.
+    escrow_nft(&mut app, &cw721, "keze", &pre_propose, "2");
+    app.execute_contract(
+        Addr::unchecked("keze"),
+        pre_propose.clone(),
+        &ExecuteMsg::ReturnNft {},
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_nft_owner(&app, &cw721, "2"), "keze");
+
+    // Rejected proposals' NFTs go to the DAO.
+    escrow_nft(&mut app, &cw721, "keze", &pre_propose, "2");
+    let id = make_proposal(&mut app, pre_propose, proposal_single.clone(), "keze", &[]);
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    assert_eq!(new_status, Status::Rejected);
+    close_proposal(&mut app, proposal_single, "keze", id);
+    assert_eq!(get_nft_owner(&app, &cw721, "2"), core_addr.to_string());
+}
+
+#[test]
+fn test_multiple_open_proposals() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let first_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    let second_id = make_proposal(
+        &mut app,
+        pre_propose,
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    // Finish up the first proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        first_id,
+        Vote::Yes,
+    );
+    assert_eq!(Status::Passed, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(0, balance.u128());
+
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", first_id);
+
+    // First proposal refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    // Finish up the second proposal.
+    let new_status = vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        second_id,
+        Vote::No,
+    );
+    assert_eq!(Status::Rejected, new_status);
+
+    // Still zero.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(10, balance.u128());
+
+    close_proposal(&mut app, proposal_single, "ekez", second_id);
+
+    // All deposits have been refunded.
+    let balance = get_balance_native(&app, "ekez", "ujuno");
+    assert_eq!(20, balance.u128());
+}
+
+#[test]
+fn test_set_version() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    let info: ContractVersion = from_slice(
+        &app.wrap()
+            .query_wasm_raw(pre_propose, "contract_info".as_bytes())
+            .unwrap()
+            .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        ContractVersion {
+            contract: CONTRACT_NAME.to_string(),
+            version: CONTRACT_VERSION.to_string()
+        },
+        info
+    )
+}
+
+#[test]
+fn test_permissions() {
+    let mut app = App::default();
+
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single: _,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false, // no open proposal submission.
+    );
+
+    let err: PreProposeError = app
+        .execute_contract(
+            core_addr,
+            pre_propose.clone(),
+            &ExecuteMsg::ProposalCompletedHook {
+                proposal_id: 1,
+                new_status: Status::Closed,
+                vetoed: false,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotModule {});
+
+    // Non-members may not propose when open_propose_submission is
+    // disabled.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose,
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "I would like to join the DAO".to_string(),
+                    description: "though, I am currently not a member.".to_string(),
+                    msgs: vec![],
+                    depends_on: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotMember {})
+}
+
+#[test]
+fn test_propose_open_proposal_submission() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        true, // yes, open proposal submission.
+    );
+
+    // Non-member proposes.
+    mint_natives(&mut app, "nonmember", coins(10, "ujuno"));
+    let id = make_proposal(
+        &mut app,
+        pre_propose,
+        proposal_single.clone(),
+        "nonmember",
+        &coins(10, "ujuno"),
+    );
+    // Member votes.
+    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
+    assert_eq!(Status::Passed, new_status)
+}
+
+#[test]
+fn test_no_deposit_required_open_submission() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
         proposal_single,
         pre_propose,
     } = setup_default_test(
@@ -886,47 +1593,407 @@ fn test_no_deposit_required_open_submission() {
         &mut app,
         pre_propose,
         proposal_single.clone(),
-        "nonmember",
+        "nonmember",
+        &[],
+    );
+    // Member votes.
+    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
+    assert_eq!(Status::Passed, new_status)
+}
+
+#[test]
+fn test_no_deposit_required_members_submission() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app, None, false, // no open proposal submission.
+    );
+
+    // Non-member proposes and this fails.
+    let err: PreProposeError = app
+        .execute_contract(
+            Addr::unchecked("nonmember"),
+            pre_propose.clone(),
+            &ExecuteMsg::Propose {
+                msg: ProposeMessage::Propose {
+                    title: "I would like to join the DAO".to_string(),
+                    description: "though, I am currently not a member.".to_string(),
+                    msgs: vec![],
+                    depends_on: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(err, PreProposeError::NotMember {});
+
+    let id = make_proposal(&mut app, pre_propose, proposal_single.clone(), "ekez", &[]);
+    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
+    assert_eq!(Status::Passed, new_status)
+}
+
+fn update_submission_policy(
+    app: &mut App,
+    core_addr: &Addr,
+    pre_propose: &Addr,
+    open_proposal_submission: bool,
+    submission_policy: Option<UncheckedSubmissionPolicy>,
+) -> Result<Config, PreProposeError> {
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission,
+            submission_policy,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast::<PreProposeError>().unwrap())?;
+    Ok(get_config(app, pre_propose.clone()))
+}
+
+fn propose_should_fail(app: &mut App, pre_propose: &Addr, proposer: &str) -> PreProposeError {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &ExecuteMsg::Propose {
+            msg: ProposeMessage::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                depends_on: None,
+            },
+        },
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+#[test]
+fn test_submission_policy() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    // ekez has 9 voting power and keze has 8.
+    update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        false,
+        Some(UncheckedSubmissionPolicy::MinVotingPower {
+            power: Uint128::new(9),
+        }),
+    )
+    .unwrap();
+    let err = propose_should_fail(&mut app, &pre_propose, "keze");
+    assert_eq!(err, PreProposeError::SubmissionNotPermitted {});
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+
+    // 9 / 17 > 50% and 8 / 17 < 50%.
+    update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        false,
+        Some(UncheckedSubmissionPolicy::MinVotingPowerPercent {
+            percent: Decimal::percent(50),
+        }),
+    )
+    .unwrap();
+    let err = propose_should_fail(&mut app, &pre_propose, "keze");
+    assert_eq!(err, PreProposeError::SubmissionNotPermitted {});
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+
+    let err = update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        false,
+        Some(UncheckedSubmissionPolicy::MinVotingPowerPercent {
+            percent: Decimal::zero(),
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::InvalidSubmissionPercent {});
+
+    let config = update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        false,
+        Some(UncheckedSubmissionPolicy::Allowlist {
+            addresses: vec!["keze".to_string()],
+        }),
+    )
+    .unwrap();
+    assert_eq!(
+        config.submission_policy,
+        Some(SubmissionPolicy::Allowlist {
+            addresses: vec![Addr::unchecked("keze")]
+        })
+    );
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(err, PreProposeError::SubmissionNotPermitted {});
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "keze",
+        &[],
+    );
+
+    // Allowlisting does not override the membership requirement.
+    update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        false,
+        Some(UncheckedSubmissionPolicy::Allowlist {
+            addresses: vec!["nonmember".to_string()],
+        }),
+    )
+    .unwrap();
+    let err = propose_should_fail(&mut app, &pre_propose, "nonmember");
+    assert_eq!(err, PreProposeError::NotMember {});
+
+    // Denylists apply to open proposal submission as well.
+    update_submission_policy(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        true,
+        Some(UncheckedSubmissionPolicy::Denylist {
+            addresses: vec!["keze".to_string()],
+        }),
+    )
+    .unwrap();
+    let err = propose_should_fail(&mut app, &pre_propose, "keze");
+    assert_eq!(err, PreProposeError::SubmissionNotPermitted {});
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "nonmember",
+        &[],
+    );
+
+    // Removing the policy lets everyone propose again.
+    update_submission_policy(&mut app, &core_addr, &pre_propose, true, None).unwrap();
+    make_proposal(&mut app, pre_propose, proposal_single, "keze", &[]);
+}
+
+fn update_proposal_limits(
+    app: &mut App,
+    core_addr: &Addr,
+    pre_propose: &Addr,
+    proposal_limits: Option<ProposalLimits>,
+) -> Result<Config, PreProposeError> {
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::UpdateConfig {
+            deposit_info: None,
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast::<PreProposeError>().unwrap())?;
+    Ok(get_config(app, pre_propose.clone()))
+}
+
+fn get_proposer_usage(app: &App, module: &Addr, address: &str) -> ProposerUsageResponse {
+    app.wrap()
+        .query_wasm_smart(
+            module,
+            &QueryMsg::ProposerUsage {
+                address: address.to_string(),
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn test_proposal_limits() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, false);
+
+    let err = update_proposal_limits(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        Some(ProposalLimits {
+            max_open_proposals: Some(0),
+            cooldown: None,
+        }),
+    )
+    .unwrap_err();
+    assert_eq!(err, PreProposeError::InvalidProposalLimits {});
+
+    // Usage is tracked before limits are configured.
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez"),
+        ProposerUsageResponse {
+            open_proposals: 1,
+            cooldown_expires: None,
+        }
+    );
+
+    update_proposal_limits(
+        &mut app,
+        &core_addr,
+        &pre_propose,
+        Some(ProposalLimits {
+            max_open_proposals: Some(2),
+            cooldown: Some(Duration::Height(5)),
+        }),
+    )
+    .unwrap();
+
+    let available = Expiration::AtHeight(app.block_info().height + 5);
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").cooldown_expires,
+        Some(available)
+    );
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(err, PreProposeError::ProposalCooldown { available });
+
+    // Limits are per-proposer.
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "keze",
+        &[],
+    );
+
+    app.update_block(|b| b.height += 5);
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+
+    app.update_block(|b| b.height += 5);
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez");
+    assert_eq!(err, PreProposeError::TooManyOpenProposals { max: 2 });
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez"),
+        ProposerUsageResponse {
+            open_proposals: 2,
+            cooldown_expires: None,
+        }
+    );
+
+    // Executing a proposal releases it.
+    let new_status = vote(&mut app, proposal_single.clone(), "ekez", id, Vote::Yes);
+    assert_eq!(new_status, Status::Passed);
+    app.execute_contract(
+        Addr::unchecked("ekez"),
+        proposal_single.clone(),
+        &cps::msg::ExecuteMsg::Execute { proposal_id: id },
         &[],
+    )
+    .unwrap();
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").open_proposals,
+        1
     );
-    // Member votes.
-    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
-    assert_eq!(Status::Passed, new_status)
+    make_proposal(&mut app, pre_propose, proposal_single, "ekez", &[]);
 }
 
 #[test]
-fn test_no_deposit_required_members_submission() {
+fn test_repeated_completion_hook_without_deposit() {
     let mut app = App::default();
     let DefaultTestSetup {
         core_addr: _,
         proposal_single,
         pre_propose,
-    } = setup_default_test(
-        &mut app, None, false, // no open proposal submission.
-    );
+    } = setup_default_test(&mut app, None, false);
 
-    // Non-member proposes and this fails.
-    let err: PreProposeError = app
-        .execute_contract(
-            Addr::unchecked("nonmember"),
-            pre_propose.clone(),
-            &ExecuteMsg::Propose {
-                msg: ProposeMessage::Propose {
-                    title: "I would like to join the DAO".to_string(),
-                    description: "though, I am currently not a member.".to_string(),
-                    msgs: vec![],
-                },
-            },
-            &[],
-        )
-        .unwrap_err()
-        .downcast()
-        .unwrap();
-    assert_eq!(err, PreProposeError::NotMember {});
+    let id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+    vote(&mut app, proposal_single.clone(), "ekez", id, Vote::No);
+    close_proposal(&mut app, proposal_single.clone(), "ekez", id);
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").open_proposals,
+        1
+    );
 
-    let id = make_proposal(&mut app, pre_propose, proposal_single.clone(), "ekez", &[]);
-    let new_status = vote(&mut app, proposal_single, "ekez", id, Vote::Yes);
-    assert_eq!(Status::Passed, new_status)
+    // A repeated completion hook for a proposal without a deposit
+    // does not release the proposer's submission again.
+    app.execute_contract(
+        proposal_single,
+        pre_propose.clone(),
+        &ExecuteMsg::ProposalCompletedHook {
+            proposal_id: id,
+            new_status: Status::Closed,
+            vetoed: false,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").open_proposals,
+        1
+    );
 }
 
 #[test]
@@ -989,8 +2056,14 @@ fn test_instantiate_with_zero_native_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -999,6 +2072,12 @@ fn test_instantiate_with_zero_native_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     };
 
@@ -1050,8 +2129,14 @@ fn test_instantiate_with_zero_cw20_deposit() {
                             },
                             amount: Uint128::zero(),
                             refund_policy: DepositRefundPolicy::OnlyPassed,
+                            slash_recipient: None,
                         }),
                         open_proposal_submission: false,
+                        submission_policy: None,
+                        proposal_limits: None,
+                        alternative_deposits: vec![],
+                        nft_deposit: None,
+                        submission_fee: None,
                         extension: Empty::default(),
                     })
                     .unwrap(),
@@ -1060,6 +2145,12 @@ fn test_instantiate_with_zero_cw20_deposit() {
                 },
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     };
 
@@ -1095,7 +2186,12 @@ fn test_update_config() {
         config,
         Config {
             deposit_info: None,
-            open_proposal_submission: false
+            open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1117,6 +2213,7 @@ fn test_update_config() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Never,
+            slash_recipient: None,
         }),
         true,
     );
@@ -1128,9 +2225,15 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             open_proposal_submission: true,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -1141,6 +2244,7 @@ fn test_update_config() {
         DepositInfoResponse {
             deposit_info: None,
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1160,9 +2264,11 @@ fn test_update_config() {
             deposit_info: Some(CheckedDepositInfo {
                 denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
                 amount: Uint128::new(10),
-                refund_policy: DepositRefundPolicy::Never
+                refund_policy: DepositRefundPolicy::Never,
+                slash_recipient: None
             }),
             proposer: Addr::unchecked("ekez"),
+            submission_fee: None,
         }
     );
 
@@ -1221,6 +2327,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1265,6 +2372,7 @@ fn test_withdraw() {
             },
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1336,6 +2444,309 @@ fn test_withdraw() {
     assert_eq!(balance, Uint128::new(30));
 }
 
+fn refund_deposits(
+    app: &mut App,
+    pre_propose: &Addr,
+    sender: &str,
+    proposal_ids: Vec<u64>,
+) -> Result<(), PreProposeError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        pre_propose.clone(),
+        &ExecuteMsg::RefundDeposits { proposal_ids },
+        &[],
+    )
+    .map_err(|e| e.downcast::<PreProposeError>().unwrap())?;
+    Ok(())
+}
+
+fn list_deposits(app: &App, pre_propose: &Addr) -> Vec<OutstandingDeposit> {
+    app.wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::ListDeposits {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap()
+}
+
+fn get_outstanding_deposit_total(app: &App, pre_propose: &Addr) -> Vec<OutstandingDepositTotal> {
+    app.wrap()
+        .query_wasm_smart(pre_propose, &QueryMsg::OutstandingDepositTotal {})
+        .unwrap()
+}
+
+#[test]
+fn test_refund_deposits() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(20, "ujuno"));
+    let executed_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    let closed_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+
+    let deposits = list_deposits(&app, &pre_propose);
+    assert_eq!(
+        deposits.iter().map(|d| d.proposal_id).collect::<Vec<_>>(),
+        vec![executed_id, closed_id]
+    );
+    assert_eq!(deposits[0].proposer, Addr::unchecked("ekez"));
+    let deposits: Vec<OutstandingDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::ListDeposits {
+                start_after: Some(executed_id),
+                limit: Some(1),
+            },
+        )
+        .unwrap();
+    assert_eq!(deposits.len(), 1);
+    assert_eq!(deposits[0].proposal_id, closed_id);
+
+    // Deposits for open proposals may not be refunded.
+    let err = refund_deposits(
+        &mut app,
+        &pre_propose,
+        core_addr.as_str(),
+        vec![executed_id],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::NotClosedOrExecuted {
+            status: Status::Open
+        }
+    );
+
+    // Withdrawing the deposits causes the proposal completed hooks to
+    // fail, and this module to be removed from the proposal module.
+    withdraw(&mut app, pre_propose.clone(), core_addr.as_str(), None);
+    assert_eq!(
+        get_outstanding_deposit_total(&app, &pre_propose),
+        vec![OutstandingDepositTotal {
+            denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+            outstanding: Uint128::new(20),
+            balance: Uint128::zero(),
+        }]
+    );
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        executed_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", executed_id);
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        closed_id,
+        Vote::No,
+    );
+    close_proposal(&mut app, proposal_single, "ekez", closed_id);
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(list_deposits(&app, &pre_propose).len(), 2);
+
+    // Once the DAO returns the funds it may refund the deposits.
+    let err = refund_deposits(&mut app, &pre_propose, "ekez", vec![executed_id]).unwrap_err();
+    assert_eq!(err, PreProposeError::NotDao {});
+
+    mint_natives(&mut app, pre_propose.as_str(), coins(20, "ujuno"));
+    refund_deposits(
+        &mut app,
+        &pre_propose,
+        core_addr.as_str(),
+        vec![executed_id, closed_id],
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(20));
+    assert_eq!(list_deposits(&app, &pre_propose), vec![]);
+    assert_eq!(get_outstanding_deposit_total(&app, &pre_propose), vec![]);
+    let outcome = get_deposit_outcome(&app, &pre_propose, closed_id).unwrap();
+    assert_eq!(outcome.status, Status::Closed);
+    assert_eq!(outcome.refunded, Uint128::new(10));
+
+    // Deposits may only be refunded once.
+    let err = refund_deposits(
+        &mut app,
+        &pre_propose,
+        core_addr.as_str(),
+        vec![executed_id],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        PreProposeError::NoOutstandingDeposit { id: executed_id }
+    );
+}
+
+#[test]
+fn test_refund_deposits_without_deposit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: DepositToken::Token {
+                denom: UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
+        }),
+        false,
+    );
+
+    mint_natives(&mut app, "ekez", coins(10, "ujuno"));
+    let paid_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &coins(10, "ujuno"),
+    );
+    update_config(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        None,
+        false,
+    );
+    let free_id = make_proposal(
+        &mut app,
+        pre_propose.clone(),
+        proposal_single.clone(),
+        "ekez",
+        &[],
+    );
+
+    let deposits = list_deposits(&app, &pre_propose);
+    assert_eq!(
+        deposits.iter().map(|d| d.proposal_id).collect::<Vec<_>>(),
+        vec![paid_id, free_id]
+    );
+    assert_eq!(deposits[1].deposit_info, None);
+    assert_eq!(deposits[1].nft_deposit, None);
+    assert_eq!(
+        get_outstanding_deposit_total(&app, &pre_propose),
+        vec![OutstandingDepositTotal {
+            denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+            outstanding: Uint128::new(10),
+            balance: Uint128::new(10),
+        }]
+    );
+
+    // Failing to refund the first deposit removes this module from
+    // the proposal module, so the second proposal's completion is
+    // never reported.
+    withdraw(
+        &mut app,
+        pre_propose.clone(),
+        core_addr.as_str(),
+        Some(UncheckedDenom::Native("ujuno".to_string())),
+    );
+    vote(
+        &mut app,
+        proposal_single.clone(),
+        "ekez",
+        paid_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, proposal_single.clone(), "ekez", paid_id);
+    vote(&mut app, proposal_single.clone(), "ekez", free_id, Vote::No);
+    close_proposal(&mut app, proposal_single, "ekez", free_id);
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").open_proposals,
+        2
+    );
+
+    mint_natives(&mut app, pre_propose.as_str(), coins(10, "ujuno"));
+    refund_deposits(
+        &mut app,
+        &pre_propose,
+        core_addr.as_str(),
+        vec![paid_id, free_id],
+    )
+    .unwrap();
+    assert_eq!(get_balance_native(&app, "ekez", "ujuno"), Uint128::new(10));
+    assert_eq!(
+        get_proposer_usage(&app, &pre_propose, "ekez").open_proposals,
+        0
+    );
+    assert_eq!(list_deposits(&app, &pre_propose), vec![]);
+
+    let err =
+        refund_deposits(&mut app, &pre_propose, core_addr.as_str(), vec![free_id]).unwrap_err();
+    assert_eq!(err, PreProposeError::NoOutstandingDeposit { id: free_id });
+}
+
+#[test]
+fn test_list_deposits_limit() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr: _,
+        proposal_single,
+        pre_propose,
+    } = setup_default_test(&mut app, None, true);
+
+    for _ in 0..12 {
+        make_proposal(
+            &mut app,
+            pre_propose.clone(),
+            proposal_single.clone(),
+            "ekez",
+            &[],
+        );
+    }
+
+    assert_eq!(list_deposits(&app, &pre_propose).len(), 10);
+    let deposits: Vec<OutstandingDeposit> = app
+        .wrap()
+        .query_wasm_smart(
+            &pre_propose,
+            &QueryMsg::ListDeposits {
+                start_after: None,
+                limit: Some(100),
+            },
+        )
+        .unwrap();
+    assert_eq!(deposits.len(), 12);
+}
+
 #[test]
 fn test_hook_management() {
     let app = &mut App::default();
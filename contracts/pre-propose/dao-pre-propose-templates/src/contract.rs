@@ -0,0 +1,203 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+};
+use cw2::set_contract_version;
+use cw_paginate::paginate_map_values;
+
+use dao_pre_propose_base::{
+    error::PreProposeError, msg::ExecuteMsg as ExecuteBase, state::PreProposeContract,
+};
+use dao_voting::proposal::SingleChoiceProposeMsg as ProposeMsg;
+
+use crate::error::ContractError;
+use crate::msg::{
+    ExecuteExt, ExecuteMsg, InstantiateExt, InstantiateMsg, ProposeMessage, ProposeMessageInternal,
+    QueryExt, QueryMsg, UncheckedTemplate,
+};
+use crate::state::TEMPLATES;
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-pre-propose-templates";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+type PrePropose = PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposeMessageInternal>;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    for template in msg.extension.templates.iter().cloned() {
+        save_template(deps.branch(), template)?;
+    }
+
+    let resp = PrePropose::default().instantiate(deps.branch(), env, info, msg)?;
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+    Ok(resp)
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    // We don't want to expose the `proposer` or `threshold` fields on
+    // the propose message externally as those are to be set by this
+    // module. Here, we check the external message against its
+    // template and transform it into an internal message which sets
+    // them.
+    type ExecuteInternal = ExecuteBase<ProposeMessageInternal, ExecuteExt>;
+    let internalized = match msg {
+        ExecuteMsg::Propose {
+            msg:
+                ProposeMessage::Propose {
+                    title,
+                    description,
+                    msgs,
+                    template,
+                    depends_on,
+                },
+        } => {
+            let template = TEMPLATES
+                .may_load(deps.storage, template.clone())?
+                .ok_or(ContractError::TemplateNotFound { name: template })?;
+            if let Some(index) = template.first_disallowed(&msgs) {
+                return Err(ContractError::MessageNotAllowed {
+                    index: index as u64,
+                    template: template.name,
+                });
+            }
+            ExecuteInternal::Propose {
+                msg: ProposeMessageInternal::Propose(ProposeMsg {
+                    // Fill in proposer based on message sender.
+                    proposer: Some(info.sender.to_string()),
+                    title,
+                    description,
+                    msgs,
+                    threshold: template.threshold,
+                    depends_on,
+                }),
+            }
+        }
+        ExecuteMsg::Extension { msg } => {
+            return match msg {
+                ExecuteExt::AddTemplate { template } => execute_add_template(deps, info, template),
+                ExecuteExt::RemoveTemplate { name } => execute_remove_template(deps, info, name),
+            }
+        }
+        ExecuteMsg::Withdraw { denom } => ExecuteInternal::Withdraw { denom },
+        ExecuteMsg::ReceiveNft(msg) => ExecuteInternal::ReceiveNft(msg),
+        ExecuteMsg::ReturnNft {} => ExecuteInternal::ReturnNft {},
+        ExecuteMsg::UpdateConfig {
+            deposit_info,
+            open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
+        } => ExecuteInternal::UpdateConfig {
+            deposit_info,
+            open_proposal_submission,
+            submission_policy,
+            proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
+        },
+        ExecuteMsg::AddProposalSubmittedHook { address } => {
+            ExecuteInternal::AddProposalSubmittedHook { address }
+        }
+        ExecuteMsg::RemoveProposalSubmittedHook { address } => {
+            ExecuteInternal::RemoveProposalSubmittedHook { address }
+        }
+        ExecuteMsg::RefundDeposits { proposal_ids } => {
+            ExecuteInternal::RefundDeposits { proposal_ids }
+        }
+        ExecuteMsg::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+            vetoed,
+        } => ExecuteInternal::ProposalCompletedHook {
+            proposal_id,
+            new_status,
+            vetoed,
+        },
+    };
+
+    Ok(PrePropose::default().execute(deps, env, info, internalized)?)
+}
+
+pub fn execute_add_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    template: UncheckedTemplate,
+) -> Result<Response, ContractError> {
+    check_dao(deps.as_ref(), &info)?;
+    let name = template.name.clone();
+    save_template(deps, template)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "add_template")
+        .add_attribute("name", name))
+}
+
+pub fn execute_remove_template(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    check_dao(deps.as_ref(), &info)?;
+    if !TEMPLATES.has(deps.storage, name.clone()) {
+        return Err(ContractError::TemplateNotFound { name });
+    }
+    TEMPLATES.remove(deps.storage, name.clone());
+
+    Ok(Response::default()
+        .add_attribute("method", "remove_template")
+        .add_attribute("name", name))
+}
+
+fn check_dao(deps: Deps, info: &MessageInfo) -> Result<(), ContractError> {
+    let dao = PrePropose::default().dao.load(deps.storage)?;
+    if dao != info.sender {
+        return Err(PreProposeError::NotDao {}.into());
+    }
+    Ok(())
+}
+
+fn save_template(deps: DepsMut, template: UncheckedTemplate) -> Result<(), ContractError> {
+    let template = template.into_checked(deps.api)?;
+    if let Some(ref threshold) = template.threshold {
+        threshold.validate()?;
+    }
+    if TEMPLATES.has(deps.storage, template.name.clone()) {
+        return Err(ContractError::TemplateExists {
+            name: template.name,
+        });
+    }
+    TEMPLATES.save(deps.storage, template.name.clone(), &template)?;
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::QueryExtension { msg } => match msg {
+            QueryExt::Template { name } => to_binary(&TEMPLATES.may_load(deps.storage, name)?),
+            QueryExt::Templates { start_after, limit } => to_binary(&paginate_map_values(
+                deps,
+                &TEMPLATES,
+                start_after,
+                limit,
+                Order::Ascending,
+            )?),
+        },
+        _ => PrePropose::default().query(deps, env, msg),
+    }
+}
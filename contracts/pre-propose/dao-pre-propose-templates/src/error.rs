@@ -0,0 +1,25 @@
+use cosmwasm_std::StdError;
+use dao_pre_propose_base::error::PreProposeError;
+use dao_voting::threshold::ThresholdError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error(transparent)]
+    PreProposeError(#[from] PreProposeError),
+
+    #[error(transparent)]
+    ThresholdError(#[from] ThresholdError),
+
+    #[error("no template named ({name})")]
+    TemplateNotFound { name: String },
+
+    #[error("a template named ({name}) already exists")]
+    TemplateExists { name: String },
+
+    #[error("message ({index}) is not allowed by template ({template})")]
+    MessageNotAllowed { index: u64, template: String },
+}
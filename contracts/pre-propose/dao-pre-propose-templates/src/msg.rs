@@ -0,0 +1,128 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Api, CosmosMsg, Empty, StdResult, Uint128};
+use dao_pre_propose_base::msg::{
+    ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase, QueryMsg as QueryBase,
+};
+use dao_voting::{
+    proposal::{ProposalDependency, SingleChoiceProposeMsg as ProposeMsg},
+    threshold::Threshold,
+};
+
+use crate::state::{MessagePattern, Template};
+
+#[cw_serde]
+pub enum ProposeMessage {
+    /// The propose message used to make a proposal to this
+    /// module. Note that this is identical to the propose message
+    /// used by dao-proposal-single, except that it omits the
+    /// `proposer` and `threshold` fields which it fills in, and names
+    /// the template the proposal follows.
+    Propose {
+        title: String,
+        description: String,
+        msgs: Vec<CosmosMsg<Empty>>,
+        template: String,
+        depends_on: Option<Vec<ProposalDependency>>,
+    },
+}
+
+#[cw_serde]
+pub enum UncheckedMessagePattern {
+    /// Bank sends of at most `max_amount` of `denom` in total across
+    /// the proposal.
+    BankSend { denom: String, max_amount: Uint128 },
+    /// Transfers of at most `max_amount` of the cw20 `token` in total
+    /// across the proposal.
+    Cw20Transfer { token: String, max_amount: Uint128 },
+    /// Executing `contract` without sending funds. If `methods` is
+    /// non-empty, only those methods may be executed.
+    WasmExecute {
+        contract: String,
+        methods: Vec<String>,
+    },
+}
+
+#[cw_serde]
+pub struct UncheckedTemplate {
+    pub name: String,
+    pub description: String,
+    pub allowed: Vec<UncheckedMessagePattern>,
+    pub threshold: Option<Threshold>,
+}
+
+impl UncheckedMessagePattern {
+    pub fn into_checked(self, api: &dyn Api) -> StdResult<MessagePattern> {
+        Ok(match self {
+            UncheckedMessagePattern::BankSend { denom, max_amount } => {
+                MessagePattern::BankSend { denom, max_amount }
+            }
+            UncheckedMessagePattern::Cw20Transfer { token, max_amount } => {
+                MessagePattern::Cw20Transfer {
+                    token: api.addr_validate(&token)?,
+                    max_amount,
+                }
+            }
+            UncheckedMessagePattern::WasmExecute { contract, methods } => {
+                MessagePattern::WasmExecute {
+                    contract: api.addr_validate(&contract)?,
+                    methods,
+                }
+            }
+        })
+    }
+}
+
+impl UncheckedTemplate {
+    pub fn into_checked(self, api: &dyn Api) -> StdResult<Template> {
+        Ok(Template {
+            name: self.name,
+            description: self.description,
+            allowed: self
+                .allowed
+                .into_iter()
+                .map(|pattern| pattern.into_checked(api))
+                .collect::<StdResult<_>>()?,
+            threshold: self.threshold,
+        })
+    }
+}
+
+#[cw_serde]
+pub struct InstantiateExt {
+    /// The templates proposals may follow.
+    pub templates: Vec<UncheckedTemplate>,
+}
+
+#[cw_serde]
+pub enum ExecuteExt {
+    /// Adds a template, only callable by the DAO
+    AddTemplate { template: UncheckedTemplate },
+    /// Removes a template, only callable by the DAO
+    RemoveTemplate { name: String },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryExt {
+    /// A template by name
+    #[returns(Option<crate::state::Template>)]
+    Template { name: String },
+    /// List of templates
+    #[returns(Vec<crate::state::Template>)]
+    Templates {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg = ExecuteBase<ProposeMessage, ExecuteExt>;
+pub type QueryMsg = QueryBase<QueryExt>;
+
+/// Internal version of the propose message that includes the
+/// `proposer` and `threshold` fields. The module will fill these in
+/// based on the sender of the external message and its template.
+#[cw_serde]
+pub(crate) enum ProposeMessageInternal {
+    Propose(ProposeMsg),
+}
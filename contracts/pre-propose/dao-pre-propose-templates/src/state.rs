@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{from_binary, Addr, BankMsg, Binary, CosmosMsg, Empty, Uint128, WasmMsg};
+use cw20::Cw20ExecuteMsg;
+use cw_storage_plus::Map;
+use dao_voting::threshold::Threshold;
+use serde::de::IgnoredAny;
+
+#[cw_serde]
+pub enum MessagePattern {
+    /// Bank sends of at most `max_amount` of `denom` in total across
+    /// the proposal.
+    BankSend { denom: String, max_amount: Uint128 },
+    /// Transfers of at most `max_amount` of the cw20 `token` in total
+    /// across the proposal.
+    Cw20Transfer { token: Addr, max_amount: Uint128 },
+    /// Executing `contract` without sending funds. If `methods` is
+    /// non-empty, only those methods may be executed.
+    WasmExecute {
+        contract: Addr,
+        methods: Vec<String>,
+    },
+}
+
+/// A kind of proposal the DAO accepts, described by the messages it
+/// may contain.
+#[cw_serde]
+pub struct Template {
+    pub name: String,
+    pub description: String,
+    /// Every message in a proposal using this template must match at
+    /// least one of these patterns. If empty, only proposals without
+    /// messages may use this template.
+    pub allowed: Vec<MessagePattern>,
+    /// If set, proposals using this template are created with this
+    /// threshold instead of the proposal module's.
+    pub threshold: Option<Threshold>,
+}
+
+/// A token whose spending is capped by a pattern.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Token<'a> {
+    Native(&'a str),
+    Cw20(&'a Addr),
+}
+
+impl MessagePattern {
+    /// The token this pattern caps spending of, along with the cap.
+    fn cap(&self) -> Option<(Token<'_>, Uint128)> {
+        match self {
+            MessagePattern::BankSend { denom, max_amount } => {
+                Some((Token::Native(denom), *max_amount))
+            }
+            MessagePattern::Cw20Transfer { token, max_amount } => {
+                Some((Token::Cw20(token), *max_amount))
+            }
+            MessagePattern::WasmExecute { .. } => None,
+        }
+    }
+
+    /// If `msg` has the form this pattern allows, returns the amount
+    /// of the pattern's token it spends. Caps are not checked here,
+    /// as they apply to the proposal as a whole.
+    pub fn spends(&self, msg: &CosmosMsg<Empty>) -> Option<Uint128> {
+        match (self, msg) {
+            (
+                MessagePattern::BankSend { denom, .. },
+                CosmosMsg::Bank(BankMsg::Send { amount, .. }),
+            ) => {
+                if !amount.iter().all(|coin| coin.denom == *denom) {
+                    return None;
+                }
+                amount.iter().try_fold(Uint128::zero(), |total, coin| {
+                    total.checked_add(coin.amount).ok()
+                })
+            }
+            (
+                MessagePattern::Cw20Transfer { token, .. },
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }),
+            ) if contract_addr == token.as_str() && funds.is_empty() => match from_binary(msg) {
+                Ok(Cw20ExecuteMsg::Transfer { amount, .. }) => Some(amount),
+                _ => None,
+            },
+            (
+                MessagePattern::WasmExecute { contract, methods },
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr,
+                    msg,
+                    funds,
+                }),
+            ) => {
+                let allowed = contract_addr == contract.as_str()
+                    && funds.is_empty()
+                    && (methods.is_empty()
+                        || execute_method(msg).map_or(false, |method| methods.contains(&method)));
+                allowed.then(Uint128::zero)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Template {
+    /// Returns the index of the first message not allowed by this
+    /// template, if any. A message is not allowed if it matches none
+    /// of the template's patterns, or if it brings the total spent of
+    /// a token over that token's cap. If several patterns cap the
+    /// same token, the largest cap applies.
+    pub fn first_disallowed(&self, msgs: &[CosmosMsg<Empty>]) -> Option<usize> {
+        let mut caps: BTreeMap<Token, Uint128> = BTreeMap::new();
+        for (token, cap) in self.allowed.iter().filter_map(MessagePattern::cap) {
+            let entry = caps.entry(token).or_default();
+            *entry = (*entry).max(cap);
+        }
+
+        let mut spent: BTreeMap<Token, Uint128> = BTreeMap::new();
+        msgs.iter().position(|msg| {
+            let allowed =
+                self.allowed
+                    .iter()
+                    .any(|pattern| match (pattern.spends(msg), pattern.cap()) {
+                        (None, _) => false,
+                        (Some(_), None) => true,
+                        (Some(amount), Some((token, _))) => {
+                            let total = spent
+                                .get(&token)
+                                .copied()
+                                .unwrap_or_default()
+                                .checked_add(amount);
+                            match total {
+                                Ok(total) if total <= caps[&token] => {
+                                    spent.insert(token, total);
+                                    true
+                                }
+                                _ => false,
+                            }
+                        }
+                    });
+            !allowed
+        })
+    }
+}
+
+/// Returns the name of the method an execute message calls, that is
+/// the only key of the JSON object it serializes to.
+fn execute_method(msg: &Binary) -> Option<String> {
+    let object: BTreeMap<String, IgnoredAny> = from_binary(msg).ok()?;
+    let mut keys = object.into_keys();
+    match (keys.next(), keys.next()) {
+        (Some(method), None) => Some(method),
+        _ => None,
+    }
+}
+
+pub const TEMPLATES: Map<String, Template> = Map::new("templates");
@@ -0,0 +1,524 @@
+use cosmwasm_std::{coins, to_binary, Addr, BankMsg, CosmosMsg, Decimal, Empty, Uint128, WasmMsg};
+use cps::query::ProposalResponse;
+use cw_multi_test::{App, Contract, ContractWrapper, Executor};
+use dao_core::state::ProposalModule;
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_pre_propose_base::error::PreProposeError;
+use dao_proposal_single as cps;
+use dao_testing::helpers::instantiate_with_cw4_groups_governance;
+use dao_voting::{
+    pre_propose::{PreProposeInfo, ProposalCreationPolicy},
+    threshold::{PercentageThreshold, Threshold},
+};
+
+use crate::contract::*;
+use crate::msg::*;
+use crate::state::{MessagePattern, Template};
+use crate::ContractError;
+
+fn cw_dao_proposal_single_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        cps::contract::execute,
+        cps::contract::instantiate,
+        cps::contract::query,
+    )
+    .with_migrate(cps::contract::migrate)
+    .with_reply(cps::contract::reply);
+    Box::new(contract)
+}
+
+fn pre_propose_templates_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(execute, instantiate, query);
+    Box::new(contract)
+}
+
+fn treasury_template() -> UncheckedTemplate {
+    UncheckedTemplate {
+        name: "treasury".to_string(),
+        description: "small spends from the treasury".to_string(),
+        allowed: vec![UncheckedMessagePattern::BankSend {
+            denom: "ujuno".to_string(),
+            max_amount: Uint128::new(100),
+        }],
+        threshold: None,
+    }
+}
+
+fn upgrade_template() -> UncheckedTemplate {
+    UncheckedTemplate {
+        name: "upgrade".to_string(),
+        description: "configuration changes".to_string(),
+        allowed: vec![UncheckedMessagePattern::WasmExecute {
+            contract: "target".to_string(),
+            methods: vec!["update_config".to_string()],
+        }],
+        threshold: Some(Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Percent(Decimal::percent(80)),
+        }),
+    }
+}
+
+struct DefaultTestSetup {
+    core_addr: Addr,
+    proposal_single: Addr,
+    pre_propose: Addr,
+}
+
+fn setup_default_test(app: &mut App, templates: Vec<UncheckedTemplate>) -> DefaultTestSetup {
+    let cps_id = app.store_code(cw_dao_proposal_single_contract());
+    let pre_propose_id = app.store_code(pre_propose_templates_contract());
+
+    let proposal_module_instantiate = cps::msg::InstantiateMsg {
+        threshold: Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        },
+        max_voting_period: cw_utils::Duration::Time(86400),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::ModuleMayPropose {
+            info: ModuleInstantiateInfo {
+                code_id: pre_propose_id,
+                msg: to_binary(&InstantiateMsg {
+                    deposit_info: None,
+                    open_proposal_submission: false,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
+                    extension: InstantiateExt { templates },
+                })
+                .unwrap(),
+                admin: Some(Admin::CoreModule {}),
+                label: "templates pre-propose module".to_string(),
+            },
+        },
+        close_proposal_on_execution_failure: false,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
+    };
+
+    let core_addr = instantiate_with_cw4_groups_governance(
+        app,
+        cps_id,
+        to_binary(&proposal_module_instantiate).unwrap(),
+        Some(vec![
+            cw20::Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(9),
+            },
+            cw20::Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(8),
+            },
+        ]),
+    );
+    let proposal_modules: Vec<ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core_addr.clone(),
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let proposal_single = proposal_modules.into_iter().next().unwrap().address;
+    let proposal_creation_policy = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_single.clone(),
+            &cps::msg::QueryMsg::ProposalCreationPolicy {},
+        )
+        .unwrap();
+    let pre_propose = match proposal_creation_policy {
+        ProposalCreationPolicy::Module { addr } => addr,
+        _ => panic!("expected a module for the proposal creation policy"),
+    };
+
+    DefaultTestSetup {
+        core_addr,
+        proposal_single,
+        pre_propose,
+    }
+}
+
+fn propose_msg(template: &str, msgs: Vec<CosmosMsg>) -> ExecuteMsg {
+    ExecuteMsg::Propose {
+        msg: ProposeMessage::Propose {
+            title: "title".to_string(),
+            description: "description".to_string(),
+            msgs,
+            template: template.to_string(),
+            depends_on: None,
+        },
+    }
+}
+
+fn propose(
+    app: &mut App,
+    pre_propose: &Addr,
+    proposer: &str,
+    template: &str,
+    msgs: Vec<CosmosMsg>,
+) {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &propose_msg(template, msgs),
+        &[],
+    )
+    .unwrap();
+}
+
+fn propose_should_fail(
+    app: &mut App,
+    pre_propose: &Addr,
+    proposer: &str,
+    template: &str,
+    msgs: Vec<CosmosMsg>,
+) -> ContractError {
+    app.execute_contract(
+        Addr::unchecked(proposer),
+        pre_propose.clone(),
+        &propose_msg(template, msgs),
+        &[],
+    )
+    .unwrap_err()
+    .downcast()
+    .unwrap()
+}
+
+fn get_proposal(app: &App, proposal_single: &Addr, proposal_id: u64) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(
+            proposal_single,
+            &cps::msg::QueryMsg::Proposal { proposal_id },
+        )
+        .unwrap()
+}
+
+fn get_template(app: &App, pre_propose: &Addr, name: &str) -> Option<Template> {
+    app.wrap()
+        .query_wasm_smart(
+            pre_propose,
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Template {
+                    name: name.to_string(),
+                },
+            },
+        )
+        .unwrap()
+}
+
+fn bank_send(amount: u128, denom: &str) -> CosmosMsg {
+    BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(amount, denom),
+    }
+    .into()
+}
+
+fn wasm_execute(contract: &str, msg: &str) -> CosmosMsg {
+    WasmMsg::Execute {
+        contract_addr: contract.to_string(),
+        msg: cosmwasm_std::Binary::from(msg.as_bytes()),
+        funds: vec![],
+    }
+    .into()
+}
+
+#[test]
+fn test_propose_with_template() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        proposal_single,
+        pre_propose,
+        ..
+    } = setup_default_test(&mut app, vec![treasury_template(), upgrade_template()]);
+
+    propose(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "treasury",
+        vec![bank_send(60, "ujuno"), bank_send(40, "ujuno")],
+    );
+    let proposal = get_proposal(&app, &proposal_single, 1);
+    assert_eq!(proposal.proposal.proposer, Addr::unchecked("ekez"));
+    assert_eq!(proposal.proposal.msgs.len(), 2);
+    // No threshold override, so the module's threshold is used.
+    assert_eq!(
+        proposal.proposal.threshold,
+        Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        }
+    );
+
+    // Sends over the cap or of another denom are not allowed.
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "treasury",
+        vec![bank_send(10, "ujuno"), bank_send(101, "ujuno")],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 1,
+            template: "treasury".to_string(),
+        }
+    );
+    // The cap applies to the proposal's sends in total.
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "treasury",
+        vec![bank_send(60, "ujuno"), bank_send(41, "ujuno")],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 1,
+            template: "treasury".to_string(),
+        }
+    );
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "treasury",
+        vec![bank_send(10, "uatom")],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 0,
+            template: "treasury".to_string(),
+        }
+    );
+
+    // Only allowlisted methods on the target contract may be called.
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "upgrade",
+        vec![wasm_execute("target", r#"{"update_admin":{}}"#)],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 0,
+            template: "upgrade".to_string(),
+        }
+    );
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "upgrade",
+        vec![wasm_execute(
+            "target",
+            r#"{"update_config":{},"update_admin":{}}"#,
+        )],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 0,
+            template: "upgrade".to_string(),
+        }
+    );
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "upgrade",
+        vec![wasm_execute("other", r#"{"update_config":{}}"#)],
+    );
+    assert_eq!(
+        err,
+        ContractError::MessageNotAllowed {
+            index: 0,
+            template: "upgrade".to_string(),
+        }
+    );
+
+    // Proposals using the upgrade template are created with its
+    // threshold.
+    propose(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "upgrade",
+        vec![wasm_execute("target", r#" { "update_config" : { } }"#)],
+    );
+    let proposal = get_proposal(&app, &proposal_single, 2);
+    assert_eq!(
+        proposal.proposal.threshold,
+        Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Percent(Decimal::percent(80)),
+        }
+    );
+
+    let err = propose_should_fail(&mut app, &pre_propose, "ekez", "unknown", vec![]);
+    assert_eq!(
+        err,
+        ContractError::TemplateNotFound {
+            name: "unknown".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_update_templates() {
+    let mut app = App::default();
+    let DefaultTestSetup {
+        core_addr,
+        pre_propose,
+        ..
+    } = setup_default_test(&mut app, vec![treasury_template()]);
+
+    // Only the DAO may add templates.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::AddTemplate {
+                    template: upgrade_template(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::PreProposeError(PreProposeError::NotDao {})
+    );
+
+    app.execute_contract(
+        core_addr.clone(),
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::AddTemplate {
+                template: upgrade_template(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        get_template(&app, &pre_propose, "upgrade"),
+        Some(Template {
+            name: "upgrade".to_string(),
+            description: "configuration changes".to_string(),
+            allowed: vec![MessagePattern::WasmExecute {
+                contract: Addr::unchecked("target"),
+                methods: vec!["update_config".to_string()],
+            }],
+            threshold: Some(Threshold::AbsolutePercentage {
+                percentage: PercentageThreshold::Percent(Decimal::percent(80)),
+            }),
+        })
+    );
+
+    // Templates may not be added twice.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::AddTemplate {
+                    template: upgrade_template(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::TemplateExists {
+            name: "upgrade".to_string()
+        }
+    );
+
+    let templates: Vec<Template> = app
+        .wrap()
+        .query_wasm_smart(
+            pre_propose.clone(),
+            &QueryMsg::QueryExtension {
+                msg: QueryExt::Templates {
+                    start_after: None,
+                    limit: None,
+                },
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        templates
+            .into_iter()
+            .map(|template| template.name)
+            .collect::<Vec<_>>(),
+        vec!["treasury".to_string(), "upgrade".to_string()]
+    );
+
+    // Only the DAO may remove templates.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            pre_propose.clone(),
+            &ExecuteMsg::Extension {
+                msg: ExecuteExt::RemoveTemplate {
+                    name: "treasury".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert_eq!(
+        err,
+        ContractError::PreProposeError(PreProposeError::NotDao {})
+    );
+
+    app.execute_contract(
+        core_addr,
+        pre_propose.clone(),
+        &ExecuteMsg::Extension {
+            msg: ExecuteExt::RemoveTemplate {
+                name: "treasury".to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(get_template(&app, &pre_propose, "treasury"), None);
+
+    let err = propose_should_fail(
+        &mut app,
+        &pre_propose,
+        "ekez",
+        "treasury",
+        vec![bank_send(10, "ujuno")],
+    );
+    assert_eq!(
+        err,
+        ContractError::TemplateNotFound {
+            name: "treasury".to_string()
+        }
+    );
+}
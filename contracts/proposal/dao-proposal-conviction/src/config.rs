@@ -0,0 +1,106 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Decimal, Deps, Uint128};
+use cw_denom::{CheckedDenom, UncheckedDenom};
+
+use crate::ContractError;
+
+#[cw_serde]
+pub struct UncheckedConfig {
+    /// The token that proposals request funding in. The requested
+    /// share of the treasury is the requested amount over the DAO's
+    /// balance of this token.
+    pub denom: UncheckedDenom,
+    /// The fraction of a proposal's conviction that remains after
+    /// each block. Must be greater than zero and less than one.
+    pub decay: Decimal,
+    /// The maximum share of the treasury that a single proposal may
+    /// request. Proposals requesting this share or more can never
+    /// pass. Must be greater than zero and no more than one.
+    pub max_ratio: Decimal,
+    /// Scales the conviction required for proposals to pass. With no
+    /// decay in between, a proposal passes once the fraction of total
+    /// voting power staked on it reaches `weight / (max_ratio -
+    /// share)^2`.
+    pub weight: Decimal,
+    /// If set to true, proposals will be closed if their execution
+    /// fails. Otherwise, the transaction that causes the proposal to
+    /// be executed will fail.
+    pub close_proposals_on_execution_failure: bool,
+}
+
+#[cw_serde]
+pub struct Config {
+    pub denom: CheckedDenom,
+    pub decay: Decimal,
+    pub max_ratio: Decimal,
+    pub weight: Decimal,
+    pub close_proposals_on_execution_failure: bool,
+}
+
+impl UncheckedConfig {
+    pub(crate) fn into_checked(self, deps: Deps) -> Result<Config, ContractError> {
+        if self.decay.is_zero() || self.decay >= Decimal::one() {
+            return Err(ContractError::InvalidDecay {});
+        }
+        if self.max_ratio.is_zero() || self.max_ratio > Decimal::one() {
+            return Err(ContractError::InvalidMaxRatio {});
+        }
+        if self.weight.is_zero() {
+            return Err(ContractError::InvalidWeight {});
+        }
+        Ok(Config {
+            denom: self.denom.into_checked(deps)?,
+            decay: self.decay,
+            max_ratio: self.max_ratio,
+            weight: self.weight,
+            close_proposals_on_execution_failure: self.close_proposals_on_execution_failure,
+        })
+    }
+}
+
+impl Config {
+    /// Computes the conviction a proposal requesting `requested` of a
+    /// treasury holding `treasury` must reach in order to pass when
+    /// `total_power` voting power exists. Returns `None` if the
+    /// proposal may never pass.
+    ///
+    /// This follows the conviction voting threshold used by 1Hive:
+    ///
+    /// ```text
+    /// threshold = weight * total_power / ((1 - decay) * (max_ratio - share)^2)
+    /// ```
+    ///
+    /// As the maximum conviction that staking `s` voting power can
+    /// accrue is `s / (1 - decay)`, this requires an ever larger
+    /// portion of the DAO to back a proposal as its requested share of
+    /// the treasury approaches `max_ratio`.
+    pub fn threshold(
+        &self,
+        requested: Uint128,
+        treasury: Uint128,
+        total_power: Uint128,
+    ) -> Option<Uint128> {
+        if total_power.is_zero() {
+            return None;
+        }
+        let share = if requested.is_zero() {
+            Decimal::zero()
+        } else if treasury.is_zero() {
+            return None;
+        } else {
+            Decimal::from_ratio(requested, treasury)
+        };
+        let distance = self.max_ratio.checked_sub(share).ok()?;
+        let denominator = (Decimal::one() - self.decay)
+            .checked_mul(distance)
+            .ok()?
+            .checked_mul(distance)
+            .ok()?;
+        if denominator.is_zero() {
+            return None;
+        }
+        total_power
+            .checked_multiply_ratio(self.weight.atomics(), denominator.atomics())
+            .ok()
+    }
+}
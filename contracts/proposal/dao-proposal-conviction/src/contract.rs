@@ -0,0 +1,469 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Order, Reply, Response, StdResult,
+    SubMsg, Uint128,
+};
+use cw2::set_contract_version;
+use cw_paginate::paginate_map_values;
+use cw_storage_plus::Bound;
+use dao_voting::reply::TaggedReplyId;
+use dao_voting::voting::{get_total_power, get_voting_power};
+
+use crate::config::{Config, UncheckedConfig};
+use crate::error::ContractError;
+use crate::msg::{ExecuteMsg, InstantiateMsg, QueryMsg, StakeResponse};
+use crate::proposal::{Proposal, ProposalResponse, Status};
+use crate::state::{CONFIG, DAO, PROPOSALS, PROPOSAL_COUNT, STAKED, STAKES};
+
+pub(crate) const CONTRACT_NAME: &str = "crates.io:dao-proposal-conviction";
+pub(crate) const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    msg: InstantiateMsg,
+) -> Result<Response, ContractError> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    let config = msg.into_checked(deps.as_ref())?;
+    DAO.save(deps.storage, &info.sender)?;
+    CONFIG.save(deps.storage, &config)?;
+    PROPOSAL_COUNT.save(deps.storage, &0)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "instantiate")
+        .add_attribute("creator", info.sender))
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, ContractError> {
+    match msg {
+        ExecuteMsg::Propose {
+            title,
+            description,
+            amount,
+            recipient,
+        } => execute_propose(deps, env, info, title, description, amount, recipient),
+        ExecuteMsg::Stake {
+            proposal_id,
+            amount,
+        } => execute_stake(deps, env, info, proposal_id, amount),
+        ExecuteMsg::Unstake {
+            proposal_id,
+            amount,
+        } => execute_unstake(deps, env, info, proposal_id, amount),
+        ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
+        ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
+        ExecuteMsg::Sync { voter } => execute_sync(deps, env, voter),
+        ExecuteMsg::UpdateConfig(config) => execute_update_config(deps, info, config),
+    }
+}
+
+fn execute_propose(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    title: String,
+    description: String,
+    amount: Uint128,
+    recipient: String,
+) -> Result<Response, ContractError> {
+    let recipient = deps.api.addr_validate(&recipient)?;
+    if amount.is_zero() {
+        return Err(ContractError::ZeroRequest {});
+    }
+
+    let dao = DAO.load(deps.storage)?;
+    let sender_power = get_voting_power(deps.as_ref(), info.sender.clone(), &dao, None)?;
+    if sender_power.is_zero() {
+        return Err(ContractError::ZeroVotingPower {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    if threshold(deps.as_ref(), &config, &dao, amount)?.is_none() {
+        return Err(ContractError::RequestTooLarge {});
+    }
+
+    let id = PROPOSAL_COUNT.load(deps.storage)? + 1;
+    PROPOSAL_COUNT.save(deps.storage, &id)?;
+
+    let proposal = Proposal {
+        id,
+        title,
+        description,
+        proposer: info.sender,
+        amount,
+        recipient,
+        status: Status::Open,
+        start_height: env.block.height,
+        staked: Uint128::zero(),
+        conviction: Uint128::zero(),
+        last_update: env.block.height,
+    };
+    PROPOSALS.save(deps.storage, id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "propose")
+        .add_attribute("proposal_id", id.to_string())
+        .add_attribute("proposer", proposal.proposer)
+        .add_attribute("amount", amount)
+        .add_attribute("recipient", proposal.recipient))
+}
+
+fn execute_stake(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroStake {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    if proposal.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    // Voting power received after the proposal was created may not
+    // be staked on it, and voting power given away since may not be
+    // staked at all.
+    let power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &dao,
+        Some(proposal.start_height),
+    )?
+    .min(get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &dao,
+        None,
+    )?);
+    let staked = STAKED
+        .may_load(deps.storage, &info.sender)?
+        .unwrap_or_default();
+    let available = power.saturating_sub(staked);
+    if amount > available {
+        return Err(ContractError::InsufficientPower { available });
+    }
+    STAKED.save(deps.storage, &info.sender, &(staked + amount))?;
+    STAKES.update(deps.storage, (&info.sender, proposal_id), |s| {
+        StdResult::Ok(s.unwrap_or_default() + amount)
+    })?;
+
+    // Conviction accrues with the old stake up to this block, and
+    // with the new stake from here on.
+    proposal.update_conviction(env.block.height, config.decay);
+    proposal.staked += amount;
+    let msgs = execute_if_passed(deps.as_ref(), &config, &dao, &mut proposal)?;
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "stake")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("conviction", proposal.conviction)
+        .add_submessages(msgs))
+}
+
+fn execute_unstake(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    amount: Uint128,
+) -> Result<Response, ContractError> {
+    if amount.is_zero() {
+        return Err(ContractError::ZeroStake {});
+    }
+
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+
+    let staked = STAKES
+        .may_load(deps.storage, (&info.sender, proposal_id))?
+        .unwrap_or_default();
+    if amount > staked {
+        return Err(ContractError::InsufficientStake { staked });
+    }
+    remove_stake(deps.branch(), &info.sender, proposal_id, amount)?;
+
+    proposal.update_conviction(env.block.height, config.decay);
+    let msgs = execute_if_passed(deps.as_ref(), &config, &dao, &mut proposal)?;
+    if proposal.status == Status::Open {
+        proposal.staked -= amount;
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "unstake")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("voter", info.sender)
+        .add_attribute("amount", amount)
+        .add_submessages(msgs))
+}
+
+fn execute_execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    if proposal.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+
+    proposal.update_conviction(env.block.height, config.decay);
+    let msgs = execute_if_passed(deps.as_ref(), &config, &dao, &mut proposal)?;
+    if msgs.is_empty() {
+        return Err(ContractError::ThresholdNotReached {});
+    }
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "execute")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("executor", info.sender)
+        .add_submessages(msgs))
+}
+
+fn execute_close(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+    let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+    if proposal.status != Status::Open {
+        return Err(ContractError::NotOpen {});
+    }
+    if info.sender != proposal.proposer && info.sender != dao {
+        return Err(ContractError::Unclosable {});
+    }
+
+    proposal.update_conviction(env.block.height, config.decay);
+    proposal.set_closed();
+    PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+
+    Ok(Response::default()
+        .add_attribute("method", "close")
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("closer", info.sender))
+}
+
+fn execute_sync(mut deps: DepsMut, env: Env, voter: String) -> Result<Response, ContractError> {
+    let voter = deps.api.addr_validate(&voter)?;
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+
+    let power = get_voting_power(deps.as_ref(), voter.clone(), &dao, None)?;
+    let staked = STAKED.may_load(deps.storage, &voter)?.unwrap_or_default();
+    let mut excess = staked.saturating_sub(power);
+    let removed = excess;
+
+    let stakes = STAKES
+        .prefix(&voter)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    let mut proposals = stakes
+        .into_iter()
+        .map(|(id, amount)| Ok((PROPOSALS.load(deps.storage, id)?, amount)))
+        .collect::<StdResult<Vec<_>>>()?;
+    // Release stake on proposals that are no longer open first, as
+    // that stake is no longer doing anything.
+    proposals.sort_by_key(|(proposal, _)| proposal.status == Status::Open);
+
+    for (mut proposal, amount) in proposals {
+        if excess.is_zero() {
+            break;
+        }
+        let amount = amount.min(excess);
+        excess -= amount;
+        remove_stake(deps.branch(), &voter, proposal.id, amount)?;
+        if proposal.status == Status::Open {
+            proposal.update_conviction(env.block.height, config.decay);
+            proposal.staked -= amount;
+            PROPOSALS.save(deps.storage, proposal.id, &proposal)?;
+        }
+    }
+
+    Ok(Response::default()
+        .add_attribute("method", "sync")
+        .add_attribute("voter", voter)
+        .add_attribute("removed", removed))
+}
+
+fn execute_update_config(
+    deps: DepsMut,
+    info: MessageInfo,
+    config: UncheckedConfig,
+) -> Result<Response, ContractError> {
+    if info.sender != DAO.load(deps.storage)? {
+        Err(ContractError::NotDao {})
+    } else {
+        let config = config.into_checked(deps.as_ref())?;
+        CONFIG.save(deps.storage, &config)?;
+        Ok(Response::default()
+            .add_attribute("method", "update_config")
+            .add_attribute("updater", info.sender))
+    }
+}
+
+/// Computes the conviction a proposal requesting `amount` must reach
+/// to pass given the DAO's current treasury and voting power.
+fn threshold(
+    deps: Deps,
+    config: &Config,
+    dao: &Addr,
+    amount: Uint128,
+) -> StdResult<Option<Uint128>> {
+    let treasury = config.denom.query_balance(&deps.querier, dao)?;
+    let total_power = get_total_power(deps, dao, None)?;
+    Ok(config.threshold(amount, treasury, total_power))
+}
+
+/// Executes `proposal` if its conviction has reached its threshold,
+/// returning the messages to do so. `proposal` must have had its
+/// conviction updated to the current block.
+fn execute_if_passed(
+    deps: Deps,
+    config: &Config,
+    dao: &Addr,
+    proposal: &mut Proposal,
+) -> StdResult<Vec<SubMsg>> {
+    if proposal.status != Status::Open {
+        return Ok(vec![]);
+    }
+    match threshold(deps, config, dao, proposal.amount)? {
+        Some(threshold) if proposal.conviction >= threshold => Ok(vec![proposal.set_executed(
+            dao,
+            &config.denom,
+            config.close_proposals_on_execution_failure,
+        )?]),
+        _ => Ok(vec![]),
+    }
+}
+
+/// Removes `amount` of `voter`'s stake on `proposal_id`. Does not
+/// update the proposal.
+fn remove_stake(deps: DepsMut, voter: &Addr, proposal_id: u64, amount: Uint128) -> StdResult<()> {
+    let remaining = STAKES.load(deps.storage, (voter, proposal_id))? - amount;
+    if remaining.is_zero() {
+        STAKES.remove(deps.storage, (voter, proposal_id));
+    } else {
+        STAKES.save(deps.storage, (voter, proposal_id), &remaining)?;
+    }
+    STAKED.update(deps.storage, voter, |staked| {
+        StdResult::Ok(staked.unwrap_or_default() - amount)
+    })?;
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
+    match msg {
+        QueryMsg::Config {} => to_binary(&CONFIG.load(deps.storage)?),
+        QueryMsg::Proposal { proposal_id } => {
+            let proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            to_binary(&proposal_response(deps, &env, proposal)?)
+        }
+        QueryMsg::ListProposals { start_after, limit } => {
+            let proposals =
+                paginate_map_values(deps, &PROPOSALS, start_after, limit, Order::Ascending)?;
+            to_binary(
+                &proposals
+                    .into_iter()
+                    .map(|proposal| proposal_response(deps, &env, proposal))
+                    .collect::<StdResult<Vec<_>>>()?,
+            )
+        }
+        QueryMsg::Stake { voter, proposal_id } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            to_binary(
+                &STAKES
+                    .may_load(deps.storage, (&voter, proposal_id))?
+                    .unwrap_or_default(),
+            )
+        }
+        QueryMsg::ListStakes {
+            voter,
+            start_after,
+            limit,
+        } => {
+            let voter = deps.api.addr_validate(&voter)?;
+            let stakes = STAKES
+                .prefix(&voter)
+                .range(
+                    deps.storage,
+                    start_after.map(Bound::exclusive),
+                    None,
+                    Order::Ascending,
+                )
+                .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+                .map(|s| {
+                    s.map(|(proposal_id, amount)| StakeResponse {
+                        proposal_id,
+                        amount,
+                    })
+                })
+                .collect::<StdResult<Vec<_>>>()?;
+            to_binary(&stakes)
+        }
+        QueryMsg::NextProposalId {} => to_binary(&(PROPOSAL_COUNT.load(deps.storage)? + 1)),
+        QueryMsg::Dao {} => to_binary(&DAO.load(deps.storage)?),
+        QueryMsg::Info {} => to_binary(&dao_interface::voting::InfoResponse {
+            info: cw2::get_contract_version(deps.storage)?,
+        }),
+    }
+}
+
+fn proposal_response(deps: Deps, env: &Env, mut proposal: Proposal) -> StdResult<ProposalResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    let dao = DAO.load(deps.storage)?;
+    proposal.update_conviction(env.block.height, config.decay);
+    Ok(ProposalResponse {
+        threshold: if proposal.status == Status::Open {
+            threshold(deps, &config, &dao, proposal.amount)?
+        } else {
+            None
+        },
+        proposal,
+    })
+}
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
+    let repl = TaggedReplyId::new(msg.id)?;
+    match repl {
+        TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            let mut proposal = PROPOSALS.load(deps.storage, proposal_id)?;
+            proposal.set_execution_failed();
+            PROPOSALS.save(deps.storage, proposal_id, &proposal)?;
+            Ok(Response::default()
+                .add_attribute("proposal_execution_failed", proposal_id.to_string()))
+        }
+        _ => Err(ContractError::InvalidReplyID { id: msg.id }),
+    }
+}
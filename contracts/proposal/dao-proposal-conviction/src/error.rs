@@ -0,0 +1,56 @@
+use cosmwasm_std::{StdError, Uint128};
+use cw_denom::DenomError;
+use dao_voting::reply::error::TagError;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+    #[error(transparent)]
+    Denom(#[from] DenomError),
+    #[error(transparent)]
+    Tag(#[from] TagError),
+
+    #[error("only the DAO my perform this action")]
+    NotDao {},
+
+    #[error("non-zero voting power required to perform this action")]
+    ZeroVotingPower {},
+
+    #[error("decay must be greater than zero and less than one")]
+    InvalidDecay {},
+
+    #[error("max ratio must be greater than zero and no more than one")]
+    InvalidMaxRatio {},
+
+    #[error("weight must be greater than zero")]
+    InvalidWeight {},
+
+    #[error("proposals must request a non-zero amount")]
+    ZeroRequest {},
+
+    #[error("requested amount is too large a share of the treasury to ever pass")]
+    RequestTooLarge {},
+
+    #[error("only open proposals may be staked on, executed, or closed")]
+    NotOpen {},
+
+    #[error("can not stake or unstake zero voting power")]
+    ZeroStake {},
+
+    #[error("not enough unstaked voting power. available ({available})")]
+    InsufficientPower { available: Uint128 },
+
+    #[error("not enough voting power staked on proposal. staked ({staked})")]
+    InsufficientStake { staked: Uint128 },
+
+    #[error("only the proposer or the DAO may close a proposal")]
+    Unclosable {},
+
+    #[error("proposal has not reached its conviction threshold")]
+    ThresholdNotReached {},
+
+    #[error("received a reply failure with an invalid ID: ({id})")]
+    InvalidReplyID { id: u64 },
+}
@@ -0,0 +1,13 @@
+#![doc = include_str!("../README.md")]
+
+pub mod config;
+pub mod contract;
+mod error;
+pub mod msg;
+pub mod proposal;
+pub mod state;
+
+#[cfg(test)]
+mod tests;
+
+pub use crate::error::ContractError;
@@ -0,0 +1,79 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Uint128;
+
+use dao_macros::proposal_module_query;
+
+use crate::config::UncheckedConfig;
+
+pub type InstantiateMsg = UncheckedConfig;
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    /// Creates a proposal requesting `amount` of the configured
+    /// denom. When the proposal's conviction reaches its threshold
+    /// the DAO sends `amount` of the configured denom to
+    /// `recipient`.
+    Propose {
+        title: String,
+        description: String,
+        amount: Uint128,
+        recipient: String,
+    },
+    /// Stakes `amount` of the sender's voting power on an open
+    /// proposal. The total voting power a voter has staked may not
+    /// exceed their current voting power, nor their voting power when
+    /// the proposal was created. Executes the proposal if it has
+    /// reached its threshold.
+    Stake { proposal_id: u64, amount: Uint128 },
+    /// Removes `amount` of the sender's voting power from a
+    /// proposal. Voting power staked on proposals that are no longer
+    /// open must be unstaked before it may be staked elsewhere.
+    Unstake { proposal_id: u64, amount: Uint128 },
+    /// Executes a proposal whose conviction has reached its
+    /// threshold. Conviction accrues between blocks, so this may be
+    /// called by anyone once enough time has passed.
+    Execute { proposal_id: u64 },
+    /// Closes an open proposal. May only be called by the proposer
+    /// or the DAO.
+    Close { proposal_id: u64 },
+    /// Removes stake from a voter's proposals until the voting power
+    /// they have staked no longer exceeds their current voting
+    /// power. May be called by anyone, and exists so that voters may
+    /// not keep stake after transferring away voting power.
+    Sync { voter: String },
+    /// Updates the module's config. May only be called by the DAO.
+    UpdateConfig(UncheckedConfig),
+}
+
+#[proposal_module_query]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(crate::config::Config)]
+    Config {},
+    /// Gets a proposal with its conviction updated to the current
+    /// block.
+    #[returns(crate::proposal::ProposalResponse)]
+    Proposal { proposal_id: u64 },
+    #[returns(Vec<crate::proposal::ProposalResponse>)]
+    ListProposals {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the voting power a voter has staked on a proposal.
+    #[returns(Uint128)]
+    Stake { voter: String, proposal_id: u64 },
+    /// Lists the proposals a voter has staked on.
+    #[returns(Vec<StakeResponse>)]
+    ListStakes {
+        voter: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct StakeResponse {
+    pub proposal_id: u64,
+    pub amount: Uint128,
+}
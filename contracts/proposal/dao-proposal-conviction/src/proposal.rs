@@ -0,0 +1,172 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{to_binary, Addr, Decimal, StdResult, SubMsg, Uint128, WasmMsg};
+use cw_denom::CheckedDenom;
+use dao_voting::reply::mask_proposal_execution_proposal_id;
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum Status {
+    /// The proposal is open and accruing conviction.
+    Open,
+    /// The proposal reached its conviction threshold and was
+    /// executed.
+    Executed,
+    /// The proposal was withdrawn by its proposer or closed by the
+    /// DAO.
+    Closed,
+    /// The proposal's execution failed.
+    ExecutionFailed,
+}
+
+#[cw_serde]
+pub struct Proposal {
+    pub id: u64,
+    pub title: String,
+    pub description: String,
+    pub proposer: Addr,
+    /// The amount of the configured denom this proposal requests
+    /// from the treasury.
+    pub amount: Uint128,
+    /// The address `amount` is sent to when this proposal is
+    /// executed.
+    pub recipient: Addr,
+    pub status: Status,
+    /// The block height at which this proposal was created. Stake on
+    /// this proposal is limited by voting power at this height so
+    /// that voting power transferred after it was created can not be
+    /// staked on it twice.
+    pub start_height: u64,
+
+    /// The voting power currently staked on this proposal.
+    pub staked: Uint128,
+    /// The proposal's conviction as of `last_update`.
+    pub conviction: Uint128,
+    /// The block height at which `conviction` was last computed.
+    pub last_update: u64,
+}
+
+#[cw_serde]
+pub struct ProposalResponse {
+    pub proposal: Proposal,
+    /// The conviction this proposal must reach to be executed given
+    /// the current treasury balance and total voting power. `None` if
+    /// the proposal may not currently pass.
+    pub threshold: Option<Uint128>,
+}
+
+/// Returns the conviction that `conviction` decays into after
+/// `blocks` blocks with `staked` voting power staked the entire time.
+///
+/// Each block conviction follows `y' = decay * y + staked`, which
+/// after `t` blocks has the closed form:
+///
+/// ```text
+/// y_t = decay^t * y_0 + staked * (1 - decay^t) / (1 - decay)
+/// ```
+pub fn accrue(conviction: Uint128, staked: Uint128, decay: Decimal, blocks: u64) -> Uint128 {
+    if blocks == 0 {
+        return conviction;
+    }
+    // Any decay less than one raised to more than u32::MAX rounds to
+    // zero at the precision of a `Decimal`.
+    let remaining = u32::try_from(blocks).map_or(Decimal::zero(), |blocks| decay.pow(blocks));
+    let growth = (Decimal::one() - remaining) / (Decimal::one() - decay);
+    conviction * remaining + staked * growth
+}
+
+impl Proposal {
+    /// Brings this proposal's conviction up to date with the block at
+    /// `height`. Conviction only accrues while a proposal is open.
+    pub(crate) fn update_conviction(&mut self, height: u64, decay: Decimal) {
+        if self.status == Status::Open {
+            self.conviction = accrue(
+                self.conviction,
+                self.staked,
+                decay,
+                height.saturating_sub(self.last_update),
+            );
+        }
+        self.last_update = height;
+    }
+
+    pub(crate) fn set_closed(&mut self) {
+        debug_assert_eq!(self.status, Status::Open);
+
+        self.status = Status::Closed;
+    }
+
+    /// Sets the proposal's status to executed and returns a
+    /// submessage that pays out the requested amount of `denom`.
+    pub(crate) fn set_executed(
+        &mut self,
+        dao: &Addr,
+        denom: &CheckedDenom,
+        close_on_execution_failure: bool,
+    ) -> StdResult<SubMsg> {
+        debug_assert_eq!(self.status, Status::Open);
+
+        self.status = Status::Executed;
+
+        let core_exec = WasmMsg::Execute {
+            contract_addr: dao.to_string(),
+            msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
+                msgs: vec![denom.get_transfer_to_message(&self.recipient, self.amount)?],
+            })?,
+            funds: vec![],
+        };
+        Ok(if close_on_execution_failure {
+            let masked_id = mask_proposal_execution_proposal_id(self.id);
+            SubMsg::reply_on_error(core_exec, masked_id)
+        } else {
+            SubMsg::new(core_exec)
+        })
+    }
+
+    pub(crate) fn set_execution_failed(&mut self) {
+        debug_assert_eq!(self.status, Status::Executed);
+
+        self.status = Status::ExecutionFailed;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accrue() {
+        let decay = Decimal::percent(50);
+
+        assert_eq!(
+            accrue(Uint128::new(10), Uint128::new(4), decay, 0),
+            Uint128::new(10)
+        );
+        // 10 -> 5 + 4 = 9 -> 4.5 + 4 = 8.5
+        assert_eq!(
+            accrue(Uint128::new(10), Uint128::new(4), decay, 1),
+            Uint128::new(9)
+        );
+        assert_eq!(
+            accrue(Uint128::new(10), Uint128::new(4), decay, 2),
+            Uint128::new(8)
+        );
+        // Accruing over many blocks is the same as accruing one block
+        // at a time.
+        let mut conviction = Uint128::new(1_000_000);
+        for _ in 0..10 {
+            conviction = accrue(conviction, Uint128::new(1_000), Decimal::percent(90), 1);
+        }
+        let at_once = accrue(
+            Uint128::new(1_000_000),
+            Uint128::new(1_000),
+            Decimal::percent(90),
+            10,
+        );
+        assert!(at_once.max(conviction) - at_once.min(conviction) <= Uint128::new(10));
+        // Conviction approaches `staked / (1 - decay)`.
+        assert_eq!(
+            accrue(Uint128::zero(), Uint128::new(4), decay, u64::MAX),
+            Uint128::new(8)
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::{Addr, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use crate::{config::Config, proposal::Proposal};
+
+pub(crate) const DAO: Item<Addr> = Item::new("dao");
+pub(crate) const CONFIG: Item<Config> = Item::new("config");
+
+pub(crate) const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
+pub(crate) const PROPOSALS: Map<u64, Proposal> = Map::new("proposals");
+
+/// The voting power each voter has staked on each proposal.
+pub(crate) const STAKES: Map<(&Addr, u64), Uint128> = Map::new("stakes");
+/// The total voting power each voter has staked across all
+/// proposals, including those that are no longer open.
+pub(crate) const STAKED: Map<&Addr, Uint128> = Map::new("staked");
@@ -0,0 +1,578 @@
+use cosmwasm_std::{coins, to_binary, Addr, Decimal, Empty, Uint128};
+use cw_denom::UncheckedDenom;
+use cw_multi_test::{next_block, App, BankSudo, Contract, ContractWrapper, Executor};
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_testing::contracts::{cw4_group_contract, dao_core_contract, dao_voting_cw4_contract};
+
+use crate::{
+    config::{Config, UncheckedConfig},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, StakeResponse},
+    proposal::{ProposalResponse, Status},
+    ContractError,
+};
+
+const ALICE: &str = "alice";
+const BOB: &str = "bob";
+
+struct Dao {
+    core: Addr,
+    conviction: Addr,
+}
+
+// Built from this crate rather than taken from dao-testing so that
+// errors may be downcast to this crate's `ContractError`.
+fn proposal_conviction_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        crate::contract::execute,
+        crate::contract::instantiate,
+        crate::contract::query,
+    )
+    .with_reply(crate::contract::reply);
+    Box::new(contract)
+}
+
+fn default_config() -> UncheckedConfig {
+    UncheckedConfig {
+        denom: UncheckedDenom::Native("ujuno".to_string()),
+        decay: Decimal::percent(50),
+        max_ratio: Decimal::percent(50),
+        weight: Decimal::percent(1),
+        close_proposals_on_execution_failure: true,
+    }
+}
+
+fn setup(app: &mut App, instantiate: InstantiateMsg) -> Dao {
+    let conviction_id = app.store_code(proposal_conviction_contract());
+    let core_id = app.store_code(dao_core_contract());
+    let cw4_id = app.store_code(cw4_group_contract());
+    let cw4_voting_id = app.store_code(dao_voting_cw4_contract());
+
+    let core_instantiate = dao_core::msg::InstantiateMsg {
+        admin: None,
+        name: "core module".to_string(),
+        description: "core module".to_string(),
+        image_url: None,
+        automatically_add_cw20s: false,
+        automatically_add_cw721s: false,
+        voting_module_instantiate_info: ModuleInstantiateInfo {
+            code_id: cw4_voting_id,
+            msg: to_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                cw4_group_code_id: cw4_id,
+                initial_members: vec![
+                    cw4::Member {
+                        addr: ALICE.to_string(),
+                        weight: 50,
+                    },
+                    cw4::Member {
+                        addr: BOB.to_string(),
+                        weight: 50,
+                    },
+                ],
+            })
+            .unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+            code_id: conviction_id,
+            msg: to_binary(&instantiate).unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "conviction module".to_string(),
+        }],
+        initial_items: None,
+        dao_uri: None,
+    };
+    let core = app
+        .instantiate_contract(
+            core_id,
+            Addr::unchecked(ALICE),
+            &core_instantiate,
+            &[],
+            "core module".to_string(),
+            None,
+        )
+        .unwrap();
+    let modules: Vec<dao_core::state::ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            &core,
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    let conviction = modules.into_iter().next().unwrap().address;
+
+    app.sudo(cw_multi_test::SudoMsg::Bank(BankSudo::Mint {
+        to_address: core.to_string(),
+        amount: coins(1000, "ujuno"),
+    }))
+    .unwrap();
+    app.update_block(next_block);
+
+    Dao { core, conviction }
+}
+
+fn propose(app: &mut App, dao: &Dao, sender: &str, amount: u128) -> Result<u64, ContractError> {
+    app.execute_contract(
+        Addr::unchecked(sender),
+        dao.conviction.clone(),
+        &ExecuteMsg::Propose {
+            title: "grant".to_string(),
+            description: "fund ekez".to_string(),
+            amount: Uint128::new(amount),
+            recipient: "ekez".to_string(),
+        },
+        &[],
+    )
+    .map_err(|e| e.downcast::<ContractError>().unwrap())?;
+    let next: u64 = app
+        .wrap()
+        .query_wasm_smart(&dao.conviction, &QueryMsg::NextProposalId {})
+        .unwrap();
+    Ok(next - 1)
+}
+
+fn execute(app: &mut App, dao: &Dao, sender: &str, msg: ExecuteMsg) -> Result<(), ContractError> {
+    app.execute_contract(Addr::unchecked(sender), dao.conviction.clone(), &msg, &[])
+        .map(|_| ())
+        .map_err(|e| e.downcast().unwrap())
+}
+
+fn stake(app: &mut App, dao: &Dao, sender: &str, proposal_id: u64, amount: u128) {
+    execute(
+        app,
+        dao,
+        sender,
+        ExecuteMsg::Stake {
+            proposal_id,
+            amount: Uint128::new(amount),
+        },
+    )
+    .unwrap()
+}
+
+fn query_proposal(app: &App, dao: &Dao, proposal_id: u64) -> ProposalResponse {
+    app.wrap()
+        .query_wasm_smart(&dao.conviction, &QueryMsg::Proposal { proposal_id })
+        .unwrap()
+}
+
+fn query_stake(app: &App, dao: &Dao, voter: &str, proposal_id: u64) -> Uint128 {
+    app.wrap()
+        .query_wasm_smart(
+            &dao.conviction,
+            &QueryMsg::Stake {
+                voter: voter.to_string(),
+                proposal_id,
+            },
+        )
+        .unwrap()
+}
+
+fn update_members(app: &mut App, dao: &Dao, add: Vec<cw4::Member>, remove: Vec<String>) {
+    let group: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            app.wrap()
+                .query_wasm_smart::<Addr>(&dao.core, &dao_core::msg::QueryMsg::VotingModule {})
+                .unwrap(),
+            &dao_voting_cw4::msg::QueryMsg::GroupContract {},
+        )
+        .unwrap();
+    app.execute_contract(
+        dao.core.clone(),
+        group,
+        &cw4_group::msg::ExecuteMsg::UpdateMembers { remove, add },
+        &[],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_threshold() {
+    let config = Config {
+        denom: cw_denom::CheckedDenom::Native("ujuno".to_string()),
+        decay: Decimal::percent(50),
+        max_ratio: Decimal::percent(50),
+        weight: Decimal::percent(1),
+        close_proposals_on_execution_failure: true,
+    };
+    let threshold = |requested: u128, treasury: u128| {
+        config.threshold(
+            Uint128::new(requested),
+            Uint128::new(treasury),
+            Uint128::new(100),
+        )
+    };
+
+    // 100 * 0.01 / (0.5 * 0.5^2)
+    assert_eq!(threshold(0, 0), Some(Uint128::new(8)));
+    // 100 * 0.01 / (0.5 * 0.4^2)
+    assert_eq!(threshold(100, 1000), Some(Uint128::new(12)));
+    // 100 * 0.01 / (0.5 * 0.1^2)
+    assert_eq!(threshold(400, 1000), Some(Uint128::new(200)));
+    // Requesting `max_ratio` or more of the treasury may never pass.
+    assert_eq!(threshold(500, 1000), None);
+    assert_eq!(threshold(1000, 1000), None);
+    assert_eq!(threshold(1, 0), None);
+}
+
+#[test]
+fn test_invalid_config() {
+    let mut app = App::default();
+    let conviction_id = app.store_code(proposal_conviction_contract());
+    let instantiate = |app: &mut App, config: UncheckedConfig| -> ContractError {
+        app.instantiate_contract(
+            conviction_id,
+            Addr::unchecked(ALICE),
+            &config,
+            &[],
+            "conviction",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap()
+    };
+
+    let err = instantiate(
+        &mut app,
+        UncheckedConfig {
+            decay: Decimal::one(),
+            ..default_config()
+        },
+    );
+    assert_eq!(err, ContractError::InvalidDecay {});
+
+    let err = instantiate(
+        &mut app,
+        UncheckedConfig {
+            max_ratio: Decimal::percent(101),
+            ..default_config()
+        },
+    );
+    assert_eq!(err, ContractError::InvalidMaxRatio {});
+
+    let err = instantiate(
+        &mut app,
+        UncheckedConfig {
+            weight: Decimal::zero(),
+            ..default_config()
+        },
+    );
+    assert_eq!(err, ContractError::InvalidWeight {});
+}
+
+#[test]
+fn test_conviction_execution() {
+    let mut app = App::default();
+    let dao = setup(&mut app, default_config());
+
+    let err = propose(&mut app, &dao, "ekez", 100).unwrap_err();
+    assert_eq!(err, ContractError::ZeroVotingPower {});
+    let err = propose(&mut app, &dao, ALICE, 500).unwrap_err();
+    assert_eq!(err, ContractError::RequestTooLarge {});
+    let err = propose(&mut app, &dao, ALICE, 0).unwrap_err();
+    assert_eq!(err, ContractError::ZeroRequest {});
+
+    // 100 of 1000 requested, so the threshold is 12 and staking 10
+    // voting power for two blocks is enough to pass.
+    let id = propose(&mut app, &dao, ALICE, 100).unwrap();
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.threshold, Some(Uint128::new(12)));
+    assert_eq!(proposal.proposal.status, Status::Open);
+
+    stake(&mut app, &dao, ALICE, id, 10);
+    app.update_block(next_block);
+
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.conviction, Uint128::new(10));
+    let err = execute(&mut app, &dao, BOB, ExecuteMsg::Execute { proposal_id: id }).unwrap_err();
+    assert_eq!(err, ContractError::ThresholdNotReached {});
+
+    app.update_block(next_block);
+
+    // Staking on a proposal that has reached its threshold executes
+    // it.
+    stake(&mut app, &dao, BOB, id, 1);
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+    assert_eq!(proposal.proposal.conviction, Uint128::new(15));
+    assert_eq!(proposal.threshold, None);
+
+    let balance = app.wrap().query_balance("ekez", "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(100));
+    let balance = app.wrap().query_balance(&dao.core, "ujuno").unwrap();
+    assert_eq!(balance.amount, Uint128::new(900));
+
+    let err = execute(
+        &mut app,
+        &dao,
+        ALICE,
+        ExecuteMsg::Stake {
+            proposal_id: id,
+            amount: Uint128::new(1),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(err, ContractError::NotOpen {});
+
+    // Stake on executed proposals may be withdrawn.
+    execute(
+        &mut app,
+        &dao,
+        ALICE,
+        ExecuteMsg::Unstake {
+            proposal_id: id,
+            amount: Uint128::new(10),
+        },
+    )
+    .unwrap();
+    assert_eq!(query_stake(&app, &dao, ALICE, id), Uint128::zero());
+}
+
+#[test]
+fn test_conviction_decays() {
+    let mut app = App::default();
+    let dao = setup(&mut app, default_config());
+
+    // Staking 5 voting power accrues at most 10 conviction which is
+    // below the threshold of 12.
+    let id = propose(&mut app, &dao, ALICE, 100).unwrap();
+    stake(&mut app, &dao, ALICE, id, 5);
+    app.update_block(|b| b.height += 100);
+
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.conviction, Uint128::new(10));
+    let err = execute(&mut app, &dao, BOB, ExecuteMsg::Execute { proposal_id: id }).unwrap_err();
+    assert_eq!(err, ContractError::ThresholdNotReached {});
+
+    // Once stake is removed conviction decays.
+    execute(
+        &mut app,
+        &dao,
+        ALICE,
+        ExecuteMsg::Unstake {
+            proposal_id: id,
+            amount: Uint128::new(5),
+        },
+    )
+    .unwrap();
+    app.update_block(next_block);
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.conviction, Uint128::new(5));
+    assert_eq!(proposal.proposal.staked, Uint128::zero());
+
+    let err = execute(
+        &mut app,
+        &dao,
+        ALICE,
+        ExecuteMsg::Unstake {
+            proposal_id: id,
+            amount: Uint128::new(1),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientStake {
+            staked: Uint128::zero()
+        }
+    );
+}
+
+#[test]
+fn test_close() {
+    let mut app = App::default();
+    let dao = setup(&mut app, default_config());
+
+    let id = propose(&mut app, &dao, ALICE, 100).unwrap();
+    let err = execute(&mut app, &dao, BOB, ExecuteMsg::Close { proposal_id: id }).unwrap_err();
+    assert_eq!(err, ContractError::Unclosable {});
+
+    execute(&mut app, &dao, ALICE, ExecuteMsg::Close { proposal_id: id }).unwrap();
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+
+    let err = execute(&mut app, &dao, ALICE, ExecuteMsg::Close { proposal_id: id }).unwrap_err();
+    assert_eq!(err, ContractError::NotOpen {});
+
+    // The DAO may close any proposal.
+    let id = propose(&mut app, &dao, ALICE, 100).unwrap();
+    app.execute_contract(
+        dao.core.clone(),
+        dao.conviction.clone(),
+        &ExecuteMsg::Close { proposal_id: id },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &dao, id);
+    assert_eq!(proposal.proposal.status, Status::Closed);
+}
+
+#[test]
+fn test_stake_limited_by_power() {
+    let mut app = App::default();
+    let dao = setup(&mut app, default_config());
+
+    let first = propose(&mut app, &dao, ALICE, 100).unwrap();
+    let second = propose(&mut app, &dao, ALICE, 100).unwrap();
+    let third = propose(&mut app, &dao, BOB, 100).unwrap();
+
+    stake(&mut app, &dao, ALICE, first, 5);
+    stake(&mut app, &dao, ALICE, second, 40);
+    let err = execute(
+        &mut app,
+        &dao,
+        ALICE,
+        ExecuteMsg::Stake {
+            proposal_id: third,
+            amount: Uint128::new(10),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientPower {
+            available: Uint128::new(5)
+        }
+    );
+
+    let stakes: Vec<StakeResponse> = app
+        .wrap()
+        .query_wasm_smart(
+            &dao.conviction,
+            &QueryMsg::ListStakes {
+                voter: ALICE.to_string(),
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        stakes,
+        vec![
+            StakeResponse {
+                proposal_id: first,
+                amount: Uint128::new(5)
+            },
+            StakeResponse {
+                proposal_id: second,
+                amount: Uint128::new(40)
+            }
+        ]
+    );
+
+    // Alice's voting power is reduced to 20 and syncing removes
+    // stake until she is no longer staking more than she has.
+    update_members(
+        &mut app,
+        &dao,
+        vec![cw4::Member {
+            addr: ALICE.to_string(),
+            weight: 20,
+        }],
+        vec![],
+    );
+    app.update_block(next_block);
+
+    execute(
+        &mut app,
+        &dao,
+        BOB,
+        ExecuteMsg::Sync {
+            voter: ALICE.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(query_stake(&app, &dao, ALICE, first), Uint128::zero());
+    assert_eq!(query_stake(&app, &dao, ALICE, second), Uint128::new(20));
+    assert_eq!(
+        query_proposal(&app, &dao, second).proposal.staked,
+        Uint128::new(20)
+    );
+}
+
+#[test]
+fn test_transfer_then_restake() {
+    let mut app = App::default();
+    let dao = setup(&mut app, default_config());
+
+    let first = propose(&mut app, &dao, ALICE, 100).unwrap();
+    let second = propose(&mut app, &dao, BOB, 100).unwrap();
+    stake(&mut app, &dao, ALICE, first, 50);
+    app.update_block(next_block);
+
+    // Alice and Bob move their voting power to new addresses.
+    update_members(
+        &mut app,
+        &dao,
+        vec![
+            cw4::Member {
+                addr: "carol".to_string(),
+                weight: 50,
+            },
+            cw4::Member {
+                addr: "dave".to_string(),
+                weight: 50,
+            },
+        ],
+        vec![ALICE.to_string(), BOB.to_string()],
+    );
+    app.update_block(next_block);
+
+    // Alice's voting power may not be staked on the first proposal
+    // again from Carol's address, so it can not pass before anyone
+    // syncs Alice.
+    let err = execute(
+        &mut app,
+        &dao,
+        "carol",
+        ExecuteMsg::Stake {
+            proposal_id: first,
+            amount: Uint128::new(50),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientPower {
+            available: Uint128::zero()
+        }
+    );
+
+    // Nor may Bob stake the voting power he had when the second
+    // proposal was created after giving it to Dave.
+    let err = execute(
+        &mut app,
+        &dao,
+        BOB,
+        ExecuteMsg::Stake {
+            proposal_id: second,
+            amount: Uint128::new(50),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        ContractError::InsufficientPower {
+            available: Uint128::zero()
+        }
+    );
+
+    // Voting power may be staked on proposals created after it was
+    // received.
+    let third = propose(&mut app, &dao, "carol", 100).unwrap();
+    app.update_block(next_block);
+    stake(&mut app, &dao, "carol", third, 50);
+    assert_eq!(
+        query_proposal(&app, &dao, first).proposal.staked,
+        Uint128::new(50)
+    );
+    assert_eq!(
+        query_proposal(&app, &dao, third).proposal.staked,
+        Uint128::new(50)
+    );
+}
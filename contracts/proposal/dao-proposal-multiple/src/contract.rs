@@ -18,15 +18,23 @@ use dao_voting::{
         MultipleChoiceOptions, MultipleChoiceVote, MultipleChoiceVotes, VotingStrategy,
     },
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{DEFAULT_LIMIT, MAX_PROPOSAL_SIZE},
+    proposal::{
+        CheckedProposalDependency, ProposalDependency, DEFAULT_LIMIT, MAX_DEPENDENCIES,
+        MAX_PROPOSAL_SIZE,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
     },
+    signed_ballot::{SignedBallot, SignedBallotError},
     status::Status,
+    threshold::{validate_percentage, PercentageThreshold},
     voting::{get_total_power, get_voting_power, validate_voting_period},
 };
 
-use crate::{msg::MigrateMsg, state::CREATION_POLICY};
+use crate::{
+    msg::MigrateMsg,
+    state::{BALLOT_NONCES, CREATION_POLICY, DEPENDENCIES},
+};
 use crate::{
     msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     proposal::{MultipleChoiceProposal, VoteResult},
@@ -50,6 +58,9 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.voting_strategy.validate()?;
+    if let Some(early_execution) = msg.early_execution {
+        validate_percentage(&early_execution)?;
+    }
 
     let dao = info.sender;
 
@@ -68,6 +79,7 @@ pub fn instantiate(
         allow_revoting: msg.allow_revoting,
         dao,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        early_execution: msg.early_execution,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -95,6 +107,7 @@ pub fn execute(
             description,
             choices,
             proposer,
+            depends_on,
         } => execute_propose(
             deps,
             env,
@@ -103,12 +116,17 @@ pub fn execute(
             description,
             choices,
             proposer,
+            depends_on,
         ),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
             rationale,
         } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        ExecuteMsg::VoteSigned {
+            proposal_id,
+            ballots,
+        } => execute_vote_signed(deps, env, info, proposal_id, ballots),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
@@ -119,6 +137,7 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
         } => execute_update_config(
             deps,
             info,
@@ -129,6 +148,7 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -150,6 +170,7 @@ pub fn execute(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -158,6 +179,7 @@ pub fn execute_propose(
     description: String,
     options: MultipleChoiceOptions,
     proposer: Option<String>,
+    depends_on: Option<Vec<ProposalDependency>>,
 ) -> Result<Response<Empty>, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -180,6 +202,31 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
+    let depends_on = depends_on.unwrap_or_default();
+    if depends_on.len() > MAX_DEPENDENCIES {
+        return Err(ContractError::TooManyDependencies {
+            max: MAX_DEPENDENCIES,
+        });
+    }
+    let depends_on = depends_on
+        .into_iter()
+        .map(|dependency| {
+            let dependency = dependency.into_checked(deps.api)?;
+            // Errors if the proposal does not exist. Dependencies
+            // must exist when a proposal is created, so there may
+            // not be dependency cycles.
+            match dependency_status(deps.as_ref(), &env, &dependency)? {
+                Status::Rejected | Status::Closed | Status::Expired => {
+                    Err(ContractError::UnexecutableDependency {
+                        proposal_module: dependency.proposal_module,
+                        proposal_id: dependency.proposal_id,
+                    })
+                }
+                _ => Ok(dependency),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     let voting_module: Addr = deps.querier.query_wasm_smart(
         config.dao.clone(),
         &dao_core::msg::QueryMsg::VotingModule {},
@@ -210,6 +257,7 @@ pub fn execute_propose(
             proposer: proposer.clone(),
             start_height: env.block.height,
             min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            early_execution: config.early_execution,
             expiration,
             voting_strategy: config.voting_strategy,
             total_power,
@@ -248,6 +296,9 @@ pub fn execute_propose(
     }
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    if !depends_on.is_empty() {
+        DEPENDENCIES.save(deps.storage, id, &depends_on)?;
+    }
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -389,6 +440,16 @@ pub fn execute_execute(
         return Err(ContractError::NotPassed {});
     }
 
+    if let Some(dependency) = blocking_dependencies(deps.as_ref(), &env, proposal_id)?
+        .into_iter()
+        .next()
+    {
+        return Err(ContractError::DependencyNotExecuted {
+            proposal_module: dependency.proposal_module,
+            proposal_id: dependency.proposal_id,
+        });
+    }
+
     prop.status = Status::Executed;
 
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
@@ -435,6 +496,7 @@ pub fn execute_execute(
                     let msg = to_binary(&PreProposeMsg::ProposalCompletedHook {
                         proposal_id,
                         new_status: prop.status,
+                        vetoed: false,
                     })?;
                     let mut hooks = hooks;
                     hooks.push(SubMsg::reply_on_error(
@@ -494,6 +556,7 @@ pub fn execute_close(
             let msg = to_binary(&PreProposeMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status: prop.status,
+                vetoed: false,
             })?;
             let mut hooks = hooks;
             hooks.push(SubMsg::reply_on_error(
@@ -525,6 +588,7 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    early_execution: Option<PercentageThreshold>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -534,6 +598,9 @@ pub fn execute_update_config(
     }
 
     voting_strategy.validate()?;
+    if let Some(early_execution) = early_execution {
+        validate_percentage(&early_execution)?;
+    }
 
     let dao = deps.api.addr_validate(&dao)?;
 
@@ -550,6 +617,7 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
         },
     )?;
 
@@ -578,6 +646,58 @@ pub fn execute_update_proposal_creation_policy(
         .add_attribute("new_policy", format!("{initial_policy:?}")))
 }
 
+pub fn execute_vote_signed(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    ballots: Vec<SignedBallot<MultipleChoiceVote>>,
+) -> Result<Response<Empty>, ContractError> {
+    let mut response = Response::default()
+        .add_attribute("action", "vote_signed")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string());
+
+    for ballot in ballots {
+        let voter = ballot.verify(
+            deps.api,
+            &env.block.chain_id,
+            &env.contract.address,
+            proposal_id,
+        )?;
+        let voter = deps.api.addr_humanize(&voter)?;
+
+        let expected = BALLOT_NONCES
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default();
+        if ballot.nonce != expected {
+            return Err(SignedBallotError::WrongNonce {
+                expected,
+                actual: ballot.nonce,
+            }
+            .into());
+        }
+        BALLOT_NONCES.save(deps.storage, &voter, &(expected + 1))?;
+
+        let vote = execute_vote(
+            deps.branch(),
+            env.clone(),
+            MessageInfo {
+                sender: voter.clone(),
+                funds: vec![],
+            },
+            proposal_id,
+            ballot.vote,
+            None,
+        )?;
+        response = response
+            .add_submessages(vote.messages)
+            .add_attribute("voter", voter);
+    }
+
+    Ok(response)
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
@@ -749,6 +869,10 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
         QueryMsg::Dao {} => query_dao(deps),
+        QueryMsg::BlockingDependencies { proposal_id } => {
+            query_blocking_dependencies(deps, env, proposal_id)
+        }
+        QueryMsg::BallotNonce { address } => query_ballot_nonce(deps, address),
     }
 }
 
@@ -767,6 +891,20 @@ pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
     to_binary(&proposal.into_response(&env.block, id)?)
 }
 
+pub fn query_blocking_dependencies(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    // Error if the proposal does not exist.
+    PROPOSALS.load(deps.storage, id)?;
+    to_binary(&blocking_dependencies(deps, &env, id)?)
+}
+
+pub fn query_ballot_nonce(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let nonce = BALLOT_NONCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    to_binary(&nonce)
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_binary(&policy)
@@ -925,6 +1063,10 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
             };
             Ok(Response::new().add_attribute("failed_prepropose_hook", format!("{addr}")))
         }
+        // This module never executes proposal messages individually.
+        TaggedReplyId::FailedProposalMessage(_, _) => {
+            Err(ContractError::InvalidReplyID { id: msg.id })
+        }
     }
 }
 
@@ -933,3 +1075,36 @@ pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, C
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
     Ok(Response::default())
 }
+
+/// Gets the status of the proposal a dependency refers to. Errors if
+/// the proposal does not exist.
+fn dependency_status(
+    deps: Deps,
+    env: &Env,
+    dependency: &CheckedProposalDependency,
+) -> StdResult<Status> {
+    if dependency.proposal_module == env.contract.address {
+        let proposal = PROPOSALS.load(deps.storage, dependency.proposal_id)?;
+        proposal.current_status(&env.block)
+    } else {
+        dependency.query_status(&deps.querier)
+    }
+}
+
+/// Gets the dependencies of a proposal that have not been executed.
+fn blocking_dependencies(
+    deps: Deps,
+    env: &Env,
+    proposal_id: u64,
+) -> StdResult<Vec<CheckedProposalDependency>> {
+    let mut blocking = vec![];
+    for dependency in DEPENDENCIES
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_default()
+    {
+        if dependency_status(deps, env, &dependency)? != Status::Executed {
+            blocking.push(dependency);
+        }
+    }
+    Ok(blocking)
+}
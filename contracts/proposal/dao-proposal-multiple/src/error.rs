@@ -1,6 +1,6 @@
 use std::u64;
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, StdError};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
 use dao_voting::{reply::error::TagError, threshold::ThresholdError};
@@ -26,6 +26,9 @@ pub enum ContractError {
     #[error("{0}")]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error("{0}")]
+    SignedBallotError(#[from] dao_voting::signed_ballot::SignedBallotError),
+
     #[error("Suggested proposal expiration is larger than the maximum proposal duration")]
     InvalidExpiration {},
 
@@ -85,6 +88,21 @@ pub enum ContractError {
     )]
     InvalidProposer {},
 
+    #[error("proposals may not depend on more than ({max}) other proposals")]
+    TooManyDependencies { max: usize },
+
+    #[error("proposal ({proposal_id}) in ({proposal_module}) can never be executed")]
+    UnexecutableDependency {
+        proposal_module: Addr,
+        proposal_id: u64,
+    },
+
+    #[error("proposal ({proposal_id}) in ({proposal_module}) must be executed first")]
+    DependencyNotExecuted {
+        proposal_module: Addr,
+        proposal_id: u64,
+    },
+
     #[error("{0}")]
     Tag(#[from] TagError),
 
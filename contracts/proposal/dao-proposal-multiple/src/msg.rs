@@ -4,6 +4,9 @@ use dao_macros::proposal_module_query;
 use dao_voting::{
     multiple_choice::{MultipleChoiceOptions, MultipleChoiceVote, VotingStrategy},
     pre_propose::PreProposeInfo,
+    proposal::ProposalDependency,
+    signed_ballot::SignedBallot,
+    threshold::PercentageThreshold,
 };
 
 #[cw_serde]
@@ -37,6 +40,10 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set, proposals may pass before the minimum voting period
+    /// has elapsed once the leading option has received this
+    /// percentage of the total voting power.
+    pub early_execution: Option<PercentageThreshold>,
 }
 
 #[cw_serde]
@@ -55,6 +62,10 @@ pub enum ExecuteMsg {
         /// pre-propose module is attached, this must be Some and will
         /// set the proposer of the proposal it creates.
         proposer: Option<String>,
+        /// Proposals that must be executed before this proposal may
+        /// be executed. These may live in this or any other proposal
+        /// module.
+        depends_on: Option<Vec<ProposalDependency>>,
     },
     /// Votes on a proposal. Voting power is determined by the DAO's
     /// voting power module.
@@ -68,6 +79,15 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Casts votes signed off-chain on behalf of their signers. Each
+    /// ballot is counted as if its signer had voted. Errors if any
+    /// ballot is invalid.
+    VoteSigned {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The signed ballots to cast.
+        ballots: Vec<SignedBallot<MultipleChoiceVote>>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -116,6 +136,10 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// If set, proposals may pass before the minimum voting period
+        /// has elapsed once the leading option has received this
+        /// percentage of the total voting power.
+        early_execution: Option<PercentageThreshold>,
     },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
@@ -187,6 +211,15 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists the dependencies of a proposal that have not yet been
+    /// executed. The proposal may not be executed until this is
+    /// empty.
+    #[returns(Vec<::dao_voting::proposal::CheckedProposalDependency>)]
+    BlockingDependencies { proposal_id: u64 },
+    /// Gets the nonce the next signed ballot from `address` must
+    /// use.
+    #[returns(u64)]
+    BallotNonce { address: String },
 }
 
 #[cw_serde]
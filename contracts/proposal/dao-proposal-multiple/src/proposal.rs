@@ -6,6 +6,7 @@ use dao_voting::{
         CheckedMultipleChoiceOption, MultipleChoiceOptionType, MultipleChoiceVotes, VotingStrategy,
     },
     status::Status,
+    threshold::PercentageThreshold,
     voting::does_vote_count_pass,
 };
 
@@ -25,6 +26,10 @@ pub struct MultipleChoiceProposal {
     /// voting. The proposal may not pass unless this is expired or
     /// None.
     pub min_voting_period: Option<Expiration>,
+    /// If set, the proposal may pass before `min_voting_period` has
+    /// expired once the leading option has received this percentage
+    /// of `total_power`.
+    pub early_execution: Option<PercentageThreshold>,
     /// The the time at which this proposal will expire and close for
     /// additional votes.
     pub expiration: Expiration,
@@ -99,9 +104,21 @@ impl MultipleChoiceProposal {
         // proposal can not yet be passed. This gives DAO members some
         // time to remove liquidity / scheme on a recovery plan if a
         // single actor accumulates enough tokens to unilaterally pass
-        // proposals.
+        // proposals. A supermajority of all voting power may pass
+        // proposals early if the module is configured to allow it.
         if let Some(min) = self.min_voting_period {
-            if !min.is_expired(block) {
+            if !min.is_expired(block)
+                && !self.early_execution.map_or(false, |supermajority| {
+                    let leading = self
+                        .votes
+                        .vote_weights
+                        .iter()
+                        .max()
+                        .copied()
+                        .unwrap_or_default();
+                    does_vote_count_pass(leading, self.total_power, supermajority)
+                })
+            {
                 return Ok(false);
             }
         }
@@ -260,7 +277,7 @@ impl MultipleChoiceProposal {
 mod tests {
     use super::*;
 
-    use cosmwasm_std::testing::mock_env;
+    use cosmwasm_std::{testing::mock_env, Decimal};
     use dao_voting::multiple_choice::{MultipleChoiceOption, MultipleChoiceOptions};
 
     fn create_proposal(
@@ -306,6 +323,7 @@ mod tests {
             votes,
             allow_revoting,
             min_voting_period: None,
+            early_execution: None,
         }
     }
 
@@ -845,4 +863,37 @@ mod tests {
         // No quorum reached & proposal has expired => rejection
         assert!(prop.is_rejected(&env.block).unwrap());
     }
+
+    #[test]
+    fn test_early_execution() {
+        let env = mock_env();
+        let voting_strategy = VotingStrategy::SingleChoice {
+            quorum: dao_voting::threshold::PercentageThreshold::Majority {},
+        };
+        let votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(85), Uint128::new(5), Uint128::new(0)],
+        };
+
+        let mut prop = create_proposal(
+            &env.block,
+            voting_strategy,
+            votes,
+            Uint128::new(100),
+            false,
+            false,
+        );
+        prop.min_voting_period = Some(Expiration::AtHeight(env.block.height + 1));
+
+        // The min voting period prevents passing..
+        assert!(!prop.is_passed(&env.block).unwrap());
+
+        // ..unless the leading option has a supermajority.
+        prop.early_execution = Some(PercentageThreshold::Percent(Decimal::percent(80)));
+        assert!(prop.is_passed(&env.block).unwrap());
+
+        prop.votes = MultipleChoiceVotes {
+            vote_weights: vec![Uint128::new(75), Uint128::new(5), Uint128::new(0)],
+        };
+        assert!(!prop.is_passed(&env.block).unwrap());
+    }
 }
@@ -7,6 +7,8 @@ use cw_utils::Duration;
 use dao_voting::{
     multiple_choice::{MultipleChoiceVote, VotingStrategy},
     pre_propose::ProposalCreationPolicy,
+    proposal::CheckedProposalDependency,
+    threshold::PercentageThreshold,
 };
 
 /// The proposal module's configuration.
@@ -43,6 +45,10 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set, proposals may pass before the minimum voting period
+    /// has elapsed once the leading option has received this
+    /// percentage of the total voting power.
+    pub early_execution: Option<PercentageThreshold>,
 }
 
 // Each ballot stores a chosen vote and corresponding voting power and rationale.
@@ -61,6 +67,11 @@ pub const CONFIG: Item<Config> = Item::new("config");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, MultipleChoiceProposal> = Map::new("proposals");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals that must be executed before a proposal may be
+/// executed. Only present for proposals with dependencies.
+pub const DEPENDENCIES: Map<u64, Vec<CheckedProposalDependency>> = Map::new("dependencies");
+/// The nonce the next signed ballot from an address must use.
+pub const BALLOT_NONCES: Map<&Addr, u64> = Map::new("ballot_nonces");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
@@ -276,10 +276,12 @@ pub fn test_allow_voting_after_proposal_execution_pre_expiration_cw20() {
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
     };
 
     let core_addr = instantiate_with_multiple_staked_balances_governance(
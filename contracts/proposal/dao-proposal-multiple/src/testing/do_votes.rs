@@ -129,6 +129,7 @@ where
         allow_revoting: false,
         voting_strategy,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info,
     };
 
@@ -212,6 +213,7 @@ where
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options,
+                depends_on: None,
             },
         },
         &funds,
@@ -39,6 +39,7 @@ pub fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    slash_recipient: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -73,6 +74,7 @@ pub fn make_proposal(
                     description: "description".to_string(),
                     choices,
                     proposer: None,
+                    depends_on: None,
                 },
                 &[],
             )
@@ -86,6 +88,7 @@ pub fn make_proposal(
                         title: "title".to_string(),
                         description: "description".to_string(),
                         choices,
+                        depends_on: None,
                     },
                 },
                 &funds,
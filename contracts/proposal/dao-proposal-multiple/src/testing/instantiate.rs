@@ -38,6 +38,11 @@ fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                submission_policy: None,
+                proposal_limits: None,
+                alternative_deposits: vec![],
+                nft_deposit: None,
+                submission_fee: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -63,10 +68,12 @@ pub fn _get_default_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
     }
 }
 
@@ -83,6 +90,7 @@ fn _get_default_non_token_dao_proposal_module_instantiate(app: &mut App) -> Inst
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
     }
 }
 
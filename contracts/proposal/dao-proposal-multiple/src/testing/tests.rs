@@ -1,4 +1,7 @@
-use cosmwasm_std::{to_binary, Addr, Coin, CosmosMsg, Decimal, Empty, Timestamp, Uint128, WasmMsg};
+use cosmwasm_std::{
+    coins, to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Empty, StdError, Timestamp, Uint128,
+    WasmMsg,
+};
 use cw20::Cw20Coin;
 use cw_denom::{CheckedDenom, UncheckedDenom};
 use cw_hooks::HooksResponse;
@@ -14,10 +17,13 @@ use dao_voting::{
         MAX_NUM_CHOICES,
     },
     pre_propose::PreProposeInfo,
+    proposal::{CheckedProposalDependency, ProposalDependency, MAX_DEPENDENCIES},
+    signed_ballot::{SignedBallot, SignedBallotError},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
+use k256::ecdsa::SigningKey;
 use std::panic;
 
 use crate::{
@@ -45,6 +51,10 @@ use dao_pre_propose_multiple as cppm;
 
 use dao_testing::{
     contracts::{cw20_balances_voting_contract, cw20_base_contract},
+    signed_ballots::{
+        ballot_app, instantiate_with_signers, sign_ballot, signer_address, BallotApp,
+    },
+    simulate::simulate_multiple_choice_proposal,
     ShouldExecute,
 };
 
@@ -93,6 +103,11 @@ pub fn get_pre_propose_info(
             msg: to_binary(&cppm::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                submission_policy: None,
+                proposal_limits: None,
+                alternative_deposits: vec![],
+                nft_deposit: None,
+                submission_fee: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -119,6 +134,7 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -135,6 +151,7 @@ fn test_propose() {
         voting_strategy: voting_strategy.clone(),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
     };
     assert_eq!(config, expected);
 
@@ -175,6 +192,7 @@ fn test_propose() {
         },
         allow_revoting: false,
         min_voting_period: None,
+        early_execution: None,
     };
 
     assert_eq!(created.proposal, expected);
@@ -194,6 +212,7 @@ fn test_propose_wrong_num_choices() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -209,6 +228,7 @@ fn test_propose_wrong_num_choices() {
     let expected = Config {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -229,6 +249,7 @@ fn test_propose_wrong_num_choices() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     );
@@ -255,6 +276,7 @@ fn test_propose_wrong_num_choices() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     );
@@ -272,6 +294,7 @@ fn test_proposal_count_initialized_to_zero() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -308,6 +331,7 @@ fn test_no_early_pass_with_min_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -358,6 +382,7 @@ fn test_no_early_pass_with_min_duration() {
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -400,6 +425,7 @@ fn test_propose_with_messages() {
         max_voting_period: Duration::Height(10),
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         only_members_execute: true,
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
@@ -435,6 +461,7 @@ fn test_propose_with_messages() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period: cw_utils::Duration::Height(20),
         only_members_execute: false,
         allow_revoting: false,
@@ -470,6 +497,7 @@ fn test_propose_with_messages() {
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -521,6 +549,7 @@ fn test_min_duration_units_missmatch() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     instantiate_with_staked_balances_governance(
@@ -553,6 +582,7 @@ fn test_min_duration_larger_than_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     instantiate_with_staked_balances_governance(
@@ -584,6 +614,7 @@ fn test_min_duration_same_as_proposal_duration() {
         only_members_execute: true,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -634,6 +665,7 @@ fn test_min_duration_same_as_proposal_duration() {
             description: "This is a simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -692,6 +724,7 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -702,6 +735,7 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -726,7 +760,8 @@ fn test_voting_module_token_proposal_deposit_instantiate() {
         Some(CheckedDepositInfo {
             denom: CheckedDenom::Cw20(token),
             amount: Uint128::new(1),
-            refund_policy: DepositRefundPolicy::OnlyPassed
+            refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None
         })
     )
 }
@@ -762,6 +797,7 @@ fn test_different_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -774,6 +810,7 @@ fn test_different_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -823,6 +860,7 @@ fn test_bad_token_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -835,6 +873,7 @@ fn test_bad_token_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -855,6 +894,7 @@ fn test_take_proposal_deposit() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -865,6 +905,7 @@ fn test_take_proposal_deposit() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -918,6 +959,7 @@ fn test_take_proposal_deposit() {
                     title: "title".to_string(),
                     description: "description".to_string(),
                     choices: mc_options.clone(),
+                    depends_on: None,
                 },
             },
             &[],
@@ -962,6 +1004,7 @@ fn test_native_proposal_deposit() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -970,6 +1013,7 @@ fn test_native_proposal_deposit() {
                 },
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -1027,6 +1071,7 @@ fn test_native_proposal_deposit() {
                     title: "title".to_string(),
                     description: "description".to_string(),
                     choices: mc_options.clone(),
+                    depends_on: None,
                 },
             },
             &[],
@@ -1110,6 +1155,7 @@ fn test_deposit_return_on_execute() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         true,
     );
@@ -1382,6 +1428,7 @@ fn test_cant_propose_zero_power() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -1392,6 +1439,7 @@ fn test_cant_propose_zero_power() {
                 denom: DepositToken::VotingModuleToken {},
                 amount: Uint128::new(1),
                 refund_policy: DepositRefundPolicy::Always,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -1466,6 +1514,7 @@ fn test_cant_propose_zero_power() {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
+                depends_on: None,
             },
         },
         &[],
@@ -1481,6 +1530,7 @@ fn test_cant_propose_zero_power() {
                 title: "A simple text proposal".to_string(),
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
+                depends_on: None,
             },
         },
         &[],
@@ -1507,6 +1557,7 @@ fn test_cant_vote_not_registered() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1554,6 +1605,7 @@ fn test_cant_execute_not_member() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: true,
         allow_revoting: false,
@@ -1595,6 +1647,7 @@ fn test_cant_execute_not_member() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -1644,6 +1697,7 @@ fn test_cant_execute_not_member_when_proposal_created() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: true,
         allow_revoting: false,
@@ -1685,6 +1739,7 @@ fn test_cant_execute_not_member_when_proposal_created() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -1765,6 +1820,7 @@ fn test_open_proposal_submission() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: get_pre_propose_info(&mut app, None, true),
     };
     let core_addr = instantiate_with_staked_balances_governance(&mut app, instantiate, None);
@@ -1799,6 +1855,7 @@ fn test_open_proposal_submission() {
         start_height: current_block.height,
         expiration: max_voting_period.after(&current_block),
         min_voting_period: None,
+        early_execution: None,
         allow_revoting: false,
         total_power: Uint128::new(100_000_000),
         status: Status::Open,
@@ -1858,6 +1915,7 @@ fn test_close_open_proposal() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -1926,6 +1984,7 @@ fn test_no_refund_failed_proposal() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         false,
     );
@@ -2004,6 +2063,7 @@ fn test_deposit_return_on_close() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         }),
         false,
     );
@@ -2054,6 +2114,7 @@ fn test_execute_expired_proposal() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2108,6 +2169,7 @@ fn test_execute_expired_proposal() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -2217,6 +2279,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            early_execution: None,
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2236,6 +2299,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            early_execution: None,
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2253,6 +2317,7 @@ fn test_update_config() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period: cw_utils::Duration::Height(10),
         only_members_execute: false,
         allow_revoting: false,
@@ -2271,6 +2336,7 @@ fn test_update_config() {
             },
             min_voting_period: None,
             close_proposal_on_execution_failure: true,
+            early_execution: None,
             max_voting_period: cw_utils::Duration::Height(10),
             only_members_execute: false,
             allow_revoting: false,
@@ -2299,6 +2365,7 @@ fn test_no_return_if_no_refunds() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         true,
     );
@@ -2345,6 +2412,7 @@ fn test_query_list_proposals() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2398,6 +2466,7 @@ fn test_query_list_proposals() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2430,6 +2499,7 @@ fn test_query_list_proposals() {
             },
             allow_revoting: false,
             min_voting_period: None,
+            early_execution: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2458,6 +2528,7 @@ fn test_query_list_proposals() {
             },
             allow_revoting: false,
             min_voting_period: None,
+            early_execution: None,
         },
     };
     assert_eq!(proposals_forward.proposals[0], expected);
@@ -2478,6 +2549,7 @@ fn test_hooks() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2604,6 +2676,7 @@ fn test_active_threshold_absolute() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2667,6 +2740,7 @@ fn test_active_threshold_absolute() {
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2692,6 +2766,7 @@ fn test_active_threshold_absolute() {
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2715,6 +2790,7 @@ fn test_active_threshold_absolute() {
                 description: "This is a simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2731,6 +2807,7 @@ fn test_active_threshold_percent() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2795,6 +2872,7 @@ fn test_active_threshold_percent() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2820,6 +2898,7 @@ fn test_active_threshold_percent() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2843,6 +2922,7 @@ fn test_active_threshold_percent() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2859,6 +2939,7 @@ fn test_active_threshold_none() {
     let instantiate = InstantiateMsg {
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         max_voting_period,
         only_members_execute: false,
         allow_revoting: false,
@@ -2926,6 +3007,7 @@ fn test_active_threshold_none() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options.clone(),
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2951,6 +3033,7 @@ fn test_active_threshold_none() {
                 description: "A simple text proposal".to_string(),
                 choices: mc_options,
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -2973,6 +3056,7 @@ fn test_revoting() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3012,6 +3096,7 @@ fn test_revoting() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3105,6 +3190,7 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3144,6 +3230,7 @@ fn test_allow_revoting_config_changes() {
             description: "A simple text proposal".to_string(),
             choices: mc_options.clone(),
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3163,6 +3250,7 @@ fn test_allow_revoting_config_changes() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
         },
         &[],
     )
@@ -3204,6 +3292,7 @@ fn test_allow_revoting_config_changes() {
             description: "A very complex text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3256,6 +3345,7 @@ fn test_revoting_same_vote_twice() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3295,6 +3385,7 @@ fn test_revoting_same_vote_twice() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3350,6 +3441,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -3389,6 +3481,7 @@ fn test_invalid_revote_does_not_invalidate_initial_vote() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3483,6 +3576,7 @@ fn test_return_deposit_to_dao_on_proposal_failure() {
             denom: DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         false,
     );
@@ -3538,6 +3632,7 @@ fn test_close_failed_proposal() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
 
@@ -3614,6 +3709,7 @@ fn test_close_failed_proposal() {
             description: "Burning more tokens, than dao treasury have".to_string(),
             choices: mc_options.clone(),
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3673,6 +3769,7 @@ fn test_close_failed_proposal() {
                                     allow_revoting: false,
                                     dao: original.dao.to_string(),
                                     close_proposal_on_execution_failure: false,
+                                    early_execution: None,
                                 })
                                 .unwrap(),
                                 funds: vec![],
@@ -3688,6 +3785,7 @@ fn test_close_failed_proposal() {
                     ],
                 },
                 proposer: None,
+                depends_on: None,
             },
             &[],
         )
@@ -3725,6 +3823,7 @@ fn test_close_failed_proposal() {
             description: "Burning more tokens, than dao treasury have".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -3775,6 +3874,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: get_pre_propose_info(
             &mut app,
             Some(UncheckedDepositInfo {
@@ -3784,6 +3884,7 @@ fn test_no_double_refund_on_execute_fail_and_close() {
                 // that we don't get a second refund on close. Refunds on
                 // close only happen if this is true.
                 refund_policy: DepositRefundPolicy::Always,
+                slash_recipient: None,
             }),
             false,
         ),
@@ -3964,6 +4065,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
         },
         min_voting_period: None,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
     };
     let core_addr = instantiate_with_staked_balances_governance(
@@ -4006,6 +4108,7 @@ pub fn test_not_allow_voting_on_expired_proposal() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -4055,6 +4158,7 @@ fn test_next_proposal_id() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -4100,6 +4204,7 @@ fn test_next_proposal_id() {
             description: "A simple text proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -4126,6 +4231,7 @@ fn test_vote_with_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -4171,6 +4277,7 @@ fn test_vote_with_rationale() {
             description: "A simple proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -4222,6 +4329,7 @@ fn test_revote_with_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -4267,6 +4375,7 @@ fn test_revote_with_rationale() {
             description: "A simple proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -4376,6 +4485,7 @@ fn test_update_rationale() {
                 quorum: PercentageThreshold::Majority {},
             },
             close_proposal_on_execution_failure: false,
+            early_execution: None,
             pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         },
         Some(vec![
@@ -4422,6 +4532,7 @@ fn test_update_rationale() {
             description: "A simple proposal".to_string(),
             choices: mc_options,
             proposer: None,
+            depends_on: None,
         },
         &[],
     )
@@ -4490,3 +4601,351 @@ fn test_update_rationale() {
         Some("This may be a good idea, but I'm not sure. YOLO".to_string())
     );
 }
+
+#[test]
+fn test_vote_signed() {
+    let mut app = ballot_app();
+    let key = SigningKey::from_bytes(&[7; 32]).unwrap();
+    let voter = signer_address(&key);
+
+    let instantiate = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Majority {},
+        },
+        max_voting_period: Duration::Time(604800),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        close_proposal_on_execution_failure: true,
+        early_execution: None,
+    };
+    let proposal_module = instantiate_with_signers(
+        &mut app,
+        proposal_multiple_contract(),
+        to_binary(&instantiate).unwrap(),
+        vec![
+            cw4::Member {
+                addr: CREATOR_ADDR.to_string(),
+                weight: 1,
+            },
+            cw4::Member {
+                addr: voter.to_string(),
+                weight: 1,
+            },
+        ],
+    );
+
+    let propose = ExecuteMsg::Propose {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        choices: MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    title: "A".to_string(),
+                    description: "a".to_string(),
+                    msgs: vec![],
+                },
+                MultipleChoiceOption {
+                    title: "B".to_string(),
+                    description: "b".to_string(),
+                    msgs: vec![],
+                },
+            ],
+        },
+        proposer: None,
+        depends_on: None,
+    };
+    for _ in 0..2 {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &propose,
+            &[],
+        )
+        .unwrap();
+    }
+    let chain_id = app.block_info().chain_id;
+    let query_nonce = |app: &BallotApp| -> u64 {
+        app.wrap()
+            .query_wasm_smart(
+                proposal_module.clone(),
+                &QueryMsg::BallotNonce {
+                    address: voter.to_string(),
+                },
+            )
+            .unwrap()
+    };
+    let vote_signed =
+        |app: &mut BallotApp, proposal_id: u64, ballot: SignedBallot<MultipleChoiceVote>| {
+            app.execute_contract(
+                Addr::unchecked("relayer"),
+                proposal_module.clone(),
+                &ExecuteMsg::VoteSigned {
+                    proposal_id,
+                    ballots: vec![ballot],
+                },
+                &[],
+            )
+        };
+    let first = MultipleChoiceVote { option_id: 0 };
+    let second = MultipleChoiceVote { option_id: 1 };
+
+    // A signed ballot is counted for its signer.
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 1, first, 0);
+    vote_signed(&mut app, 1, ballot.clone()).unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.votes.vote_weights[0], Uint128::new(1));
+    assert_eq!(query_nonce(&app), 1);
+
+    // Ballots may not be replayed.
+    let err: ContractError = vote_signed(&mut app, 1, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::WrongNonce {
+            expected: 1,
+            actual: 0
+        })
+    ));
+
+    // Ballots are only valid for the proposal and module they were
+    // signed for.
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 1, second, 1);
+    let err: ContractError = vote_signed(&mut app, 2, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::InvalidSignature {})
+    ));
+    let ballot = sign_ballot(&key, &chain_id, &Addr::unchecked("other"), 2, second, 1);
+    let err: ContractError = vote_signed(&mut app, 2, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::InvalidSignature {})
+    ));
+    assert_eq!(query_nonce(&app), 1);
+
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 2, second, 1);
+    vote_signed(&mut app, 2, ballot).unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::Proposal { proposal_id: 2 })
+        .unwrap();
+    assert_eq!(proposal.proposal.votes.vote_weights[1], Uint128::new(1));
+    assert_eq!(query_nonce(&app), 2);
+}
+
+#[test]
+fn test_proposal_dependencies() {
+    let mut app = App::default();
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        close_proposal_on_execution_failure: true,
+        early_execution: None,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![Cw20Coin {
+            address: "whale".to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let propose = |app: &mut App, depends_on: Vec<u64>| {
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            govmod.clone(),
+            &ExecuteMsg::Propose {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                choices: MultipleChoiceOptions {
+                    options: vec![
+                        MultipleChoiceOption {
+                            description: "multiple choice option 1".to_string(),
+                            msgs: vec![],
+                            title: "title".to_string(),
+                        },
+                        MultipleChoiceOption {
+                            description: "multiple choice option 2".to_string(),
+                            msgs: vec![],
+                            title: "title".to_string(),
+                        },
+                    ],
+                },
+                proposer: None,
+                depends_on: Some(
+                    depends_on
+                        .into_iter()
+                        .map(|proposal_id| ProposalDependency {
+                            proposal_module: govmod.to_string(),
+                            proposal_id,
+                        })
+                        .collect(),
+                ),
+            },
+            &[],
+        )
+    };
+    let vote = |app: &mut App, proposal_id: u64| {
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            govmod.clone(),
+            &ExecuteMsg::Vote {
+                proposal_id,
+                vote: MultipleChoiceVote { option_id: 0 },
+                rationale: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+    let execute = |app: &mut App, proposal_id: u64| {
+        app.execute_contract(
+            Addr::unchecked("whale"),
+            govmod.clone(),
+            &ExecuteMsg::Execute { proposal_id },
+            &[],
+        )
+    };
+
+    // Dependencies must exist.
+    let err: ContractError = propose(&mut app, vec![1]).unwrap_err().downcast().unwrap();
+    assert!(matches!(err, ContractError::Std(_)));
+
+    propose(&mut app, vec![]).unwrap();
+    propose(&mut app, vec![1]).unwrap();
+    vote(&mut app, 1);
+    vote(&mut app, 2);
+
+    // The second proposal may not be executed before the first.
+    let blocking: Vec<CheckedProposalDependency> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::BlockingDependencies { proposal_id: 2 })
+        .unwrap();
+    assert_eq!(
+        blocking,
+        vec![CheckedProposalDependency {
+            proposal_module: govmod.clone(),
+            proposal_id: 1,
+        }]
+    );
+    let err: ContractError = execute(&mut app, 2).unwrap_err().downcast().unwrap();
+    assert!(matches!(
+        err,
+        ContractError::DependencyNotExecuted { proposal_id: 1, .. }
+    ));
+
+    execute(&mut app, 1).unwrap();
+    let blocking: Vec<CheckedProposalDependency> = app
+        .wrap()
+        .query_wasm_smart(&govmod, &QueryMsg::BlockingDependencies { proposal_id: 2 })
+        .unwrap();
+    assert!(blocking.is_empty());
+    execute(&mut app, 2).unwrap();
+    let proposal: ProposalResponse = query_proposal(&app, &govmod, 2);
+    assert_eq!(proposal.proposal.status, Status::Executed);
+
+    let err: ContractError = propose(&mut app, vec![1; MAX_DEPENDENCIES + 1])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::TooManyDependencies { .. }));
+}
+
+#[test]
+fn test_simulate_execution() {
+    let mut app = App::default();
+    let msg = InstantiateMsg {
+        voting_strategy: VotingStrategy::SingleChoice {
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        },
+        max_voting_period: Duration::Height(10),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        close_proposal_on_execution_failure: true,
+        early_execution: None,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+    };
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        msg,
+        Some(vec![Cw20Coin {
+            address: "whale".to_string(),
+            amount: Uint128::new(100),
+        }]),
+    );
+    let govmod = query_multiple_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(
+        &mut app,
+        &govmod,
+        "whale",
+        MultipleChoiceOptions {
+            options: vec![
+                MultipleChoiceOption {
+                    description: "pay ekez".to_string(),
+                    msgs: vec![BankMsg::Send {
+                        to_address: "ekez".to_string(),
+                        amount: coins(10, "ujuno"),
+                    }
+                    .into()],
+                    title: "title".to_string(),
+                },
+                MultipleChoiceOption {
+                    description: "do nothing".to_string(),
+                    msgs: vec![],
+                    title: "title".to_string(),
+                },
+            ],
+        },
+    );
+
+    // The DAO has no funds so executing the first option would fail.
+    let err: StdError = simulate_multiple_choice_proposal(&mut app, &govmod, proposal_id, 0)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, StdError::Overflow { .. }));
+    simulate_multiple_choice_proposal(&mut app, &govmod, proposal_id, 1).unwrap();
+    simulate_multiple_choice_proposal(&mut app, &govmod, proposal_id, 3).unwrap_err();
+
+    app.sudo(SudoMsg::Bank(BankSudo::Mint {
+        to_address: core_addr.to_string(),
+        amount: coins(10, "ujuno"),
+    }))
+    .unwrap();
+    simulate_multiple_choice_proposal(&mut app, &govmod, proposal_id, 0).unwrap();
+
+    // Simulation does not change state.
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+    let proposal = query_proposal(&app, &govmod, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+}
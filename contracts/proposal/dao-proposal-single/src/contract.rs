@@ -1,8 +1,8 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply,
-    Response, StdResult, Storage, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, Event, MessageInfo, Order,
+    Reply, Response, StdResult, Storage, SubMsg, WasmMsg,
 };
 use cw2::{get_contract_version, set_contract_version, ContractVersion};
 use cw_hooks::Hooks;
@@ -14,18 +14,26 @@ use dao_proposal_hooks::{new_proposal_hooks, proposal_status_changed_hooks};
 use dao_vote_hooks::new_vote_hooks;
 use dao_voting::pre_propose::{PreProposeInfo, ProposalCreationPolicy};
 use dao_voting::proposal::{
-    SingleChoiceProposeMsg as ProposeMsg, DEFAULT_LIMIT, MAX_PROPOSAL_SIZE,
+    CheckedProposalDependency, ProposalDependency, SingleChoiceProposeMsg as ProposeMsg,
+    DEFAULT_LIMIT, MAX_DEPENDENCIES, MAX_PROPOSAL_SIZE,
 };
 use dao_voting::reply::{
-    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id, TaggedReplyId,
+    failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
+    mask_proposal_message_index, TaggedReplyId,
 };
+use dao_voting::signed_ballot::{SignedBallot, SignedBallotError};
 use dao_voting::status::Status;
-use dao_voting::threshold::Threshold;
-use dao_voting::voting::{get_total_power, get_voting_power, validate_voting_period, Vote, Votes};
+use dao_voting::threshold::{validate_percentage, PercentageThreshold, Threshold};
+use dao_voting::voting::{
+    get_total_power, get_voting_power, validate_voting_period, Vote, VoteReveal, Votes,
+};
 
 use crate::msg::MigrateMsg;
-use crate::proposal::{next_proposal_id, SingleChoiceProposal};
-use crate::state::{Config, CREATION_POLICY};
+use crate::proposal::{next_proposal_id, ExecutionFailure, ProposalVersion, SingleChoiceProposal};
+use crate::state::{
+    Commitment, Config, RetryPolicy, AMENDMENT_DEADLINES, BALLOT_NONCES, COMMITMENTS,
+    CREATION_POLICY, DEPENDENCIES, PROPOSAL_VERSIONS,
+};
 
 use crate::v1_state::{
     v1_duration_to_v2, v1_expiration_to_v2, v1_status_to_v2, v1_threshold_to_v2, v1_votes_to_v2,
@@ -56,11 +64,20 @@ pub fn instantiate(
     set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     msg.threshold.validate()?;
+    if let Some(early_execution) = msg.early_execution {
+        validate_percentage(&early_execution)?;
+    }
+    if let Some(veto_threshold) = msg.veto_threshold {
+        validate_percentage(&veto_threshold)?;
+    }
 
     let dao = info.sender;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(msg.min_voting_period, msg.max_voting_period)?;
+    validate_execution_window(msg.execution_window, max_voting_period)?;
+    validate_amendment_window(msg.amendment_window)?;
+    validate_reveal_period(msg.reveal_period, max_voting_period)?;
 
     let (initial_policy, pre_propose_messages) = msg
         .pre_propose_info
@@ -74,6 +91,12 @@ pub fn instantiate(
         dao: dao.clone(),
         allow_revoting: msg.allow_revoting,
         close_proposal_on_execution_failure: msg.close_proposal_on_execution_failure,
+        early_execution: msg.early_execution,
+        execution_window: msg.execution_window,
+        retry_policy: msg.retry_policy,
+        amendment_window: msg.amendment_window,
+        reveal_period: msg.reveal_period,
+        veto_threshold: msg.veto_threshold,
     };
 
     // Initialize proposal count to zero so that queries return zero
@@ -101,16 +124,47 @@ pub fn execute(
             description,
             msgs,
             proposer,
-        }) => execute_propose(deps, env, info.sender, title, description, msgs, proposer),
+            threshold,
+            depends_on,
+        }) => execute_propose(
+            deps,
+            env,
+            info.sender,
+            title,
+            description,
+            msgs,
+            proposer,
+            threshold,
+            depends_on,
+        ),
         ExecuteMsg::Vote {
             proposal_id,
             vote,
             rationale,
         } => execute_vote(deps, env, info, proposal_id, vote, rationale),
+        ExecuteMsg::CommitVote {
+            proposal_id,
+            commitment,
+        } => execute_commit_vote(deps, env, info, proposal_id, commitment),
+        ExecuteMsg::RevealVote {
+            proposal_id,
+            vote,
+            salt,
+        } => execute_reveal_vote(deps, env, info, proposal_id, vote, salt),
+        ExecuteMsg::VoteSigned {
+            proposal_id,
+            ballots,
+        } => execute_vote_signed(deps, env, info, proposal_id, ballots),
         ExecuteMsg::UpdateRationale {
             proposal_id,
             rationale,
         } => execute_update_rationale(deps, info, proposal_id, rationale),
+        ExecuteMsg::Amend {
+            proposal_id,
+            title,
+            description,
+            msgs,
+        } => execute_amend(deps, env, info, proposal_id, title, description, msgs),
         ExecuteMsg::Execute { proposal_id } => execute_execute(deps, env, info, proposal_id),
         ExecuteMsg::Close { proposal_id } => execute_close(deps, env, info, proposal_id),
         ExecuteMsg::UpdateConfig {
@@ -121,6 +175,12 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
+            execution_window,
+            retry_policy,
+            amendment_window,
+            reveal_period,
+            veto_threshold,
         } => execute_update_config(
             deps,
             info,
@@ -131,6 +191,12 @@ pub fn execute(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
+            execution_window,
+            retry_policy,
+            amendment_window,
+            reveal_period,
+            veto_threshold,
         ),
         ExecuteMsg::UpdatePreProposeInfo { info: new_info } => {
             execute_update_proposal_creation_policy(deps, info, new_info)
@@ -148,6 +214,7 @@ pub fn execute(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn execute_propose(
     deps: DepsMut,
     env: Env,
@@ -156,6 +223,8 @@ pub fn execute_propose(
     description: String,
     msgs: Vec<CosmosMsg<Empty>>,
     proposer: Option<String>,
+    threshold: Option<Threshold>,
+    depends_on: Option<Vec<ProposalDependency>>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
@@ -178,6 +247,43 @@ pub fn execute_propose(
         _ => return Err(ContractError::InvalidProposer {}),
     };
 
+    // Only pre-propose modules may override the threshold.
+    let threshold = match (threshold, &proposal_creation_policy) {
+        (None, _) => config.threshold,
+        (Some(threshold), ProposalCreationPolicy::Module { .. }) => {
+            threshold.validate()?;
+            threshold
+        }
+        (Some(_), ProposalCreationPolicy::Anyone {}) => {
+            return Err(ContractError::ThresholdOverrideNotPermitted {})
+        }
+    };
+
+    let depends_on = depends_on.unwrap_or_default();
+    if depends_on.len() > MAX_DEPENDENCIES {
+        return Err(ContractError::TooManyDependencies {
+            max: MAX_DEPENDENCIES,
+        });
+    }
+    let depends_on = depends_on
+        .into_iter()
+        .map(|dependency| {
+            let dependency = dependency.into_checked(deps.api)?;
+            // Errors if the proposal does not exist. Dependencies
+            // must exist when a proposal is created, so there may
+            // not be dependency cycles.
+            match dependency_status(deps.as_ref(), &env, &dependency)? {
+                Status::Rejected | Status::Closed | Status::Expired => {
+                    Err(ContractError::UnexecutableDependency {
+                        proposal_module: dependency.proposal_module,
+                        proposal_id: dependency.proposal_id,
+                    })
+                }
+                _ => Ok(dependency),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
     let voting_module: Addr = deps.querier.query_wasm_smart(
         config.dao.clone(),
         &dao_core::msg::QueryMsg::VotingModule {},
@@ -195,6 +301,16 @@ pub fn execute_propose(
     }
 
     let expiration = config.max_voting_period.after(&env.block);
+    let reveal_deadline = config
+        .reveal_period
+        .map(|period| expiration + period)
+        .transpose()?;
+    // Proposals in commit-reveal mode are not decided until their
+    // reveal period ends.
+    let execution_deadline = config
+        .execution_window
+        .map(|window| reveal_deadline.unwrap_or(expiration) + window)
+        .transpose()?;
 
     let total_power = get_total_power(deps.as_ref(), &config.dao, Some(env.block.height))?;
 
@@ -206,13 +322,20 @@ pub fn execute_propose(
             proposer: proposer.clone(),
             start_height: env.block.height,
             min_voting_period: config.min_voting_period.map(|min| min.after(&env.block)),
+            early_execution: config.early_execution,
             expiration,
-            threshold: config.threshold,
+            reveal_deadline,
+            execution_deadline,
+            threshold,
+            veto_threshold: config.veto_threshold,
             total_power,
             msgs,
             status: Status::Open,
             votes: Votes::zero(),
             allow_revoting: config.allow_revoting,
+            execution_attempts: 0,
+            execution_failures: vec![],
+            ballot_version: 0,
         };
         // Update the proposal's status. Addresses case where proposal
         // expires on the same block as it is created.
@@ -232,18 +355,15 @@ pub fn execute_propose(
     // The size selected was determined by uploading versions of this
     // contract to the Juno mainnet until queries worked within a
     // reasonable margin of error.
-    //
-    // `to_vec` is the method used by cosmwasm to convert a struct
-    // into it's byte representation in storage.
-    let proposal_size = cosmwasm_std::to_vec(&proposal)?.len() as u64;
-    if proposal_size > MAX_PROPOSAL_SIZE {
-        return Err(ContractError::ProposalTooLarge {
-            size: proposal_size,
-            max: MAX_PROPOSAL_SIZE,
-        });
-    }
+    check_proposal_size(&proposal)?;
 
     PROPOSALS.save(deps.storage, id, &proposal)?;
+    if !depends_on.is_empty() {
+        DEPENDENCIES.save(deps.storage, id, &depends_on)?;
+    }
+    if let Some(window) = config.amendment_window {
+        AMENDMENT_DEADLINES.save(deps.storage, id, &window.after(&env.block))?;
+    }
 
     let hooks = new_proposal_hooks(PROPOSAL_HOOKS, deps.storage, id, proposer.as_str())?;
 
@@ -255,6 +375,121 @@ pub fn execute_propose(
         .add_attribute("status", proposal.status.to_string()))
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn execute_amend(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    title: Option<String>,
+    description: Option<String>,
+    msgs: Option<Vec<CosmosMsg<Empty>>>,
+) -> Result<Response, ContractError> {
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if info.sender != prop.proposer {
+        return Err(ContractError::Unauthorized {});
+    }
+    // A pre-propose module may have checked the messages before the
+    // proposal was created, so replacing them here would bypass it.
+    if msgs.is_some()
+        && matches!(
+            CREATION_POLICY.load(deps.storage)?,
+            ProposalCreationPolicy::Module { .. }
+        )
+    {
+        return Err(ContractError::MessagesNotAmendable {});
+    }
+
+    prop.update_status(&env.block);
+    match AMENDMENT_DEADLINES.may_load(deps.storage, proposal_id)? {
+        Some(deadline) if prop.status == Status::Open && !deadline.is_expired(&env.block) => (),
+        _ => return Err(ContractError::NotAmendable {}),
+    }
+
+    // The first time a proposal is amended its original content is
+    // stored as version zero.
+    let latest = PROPOSAL_VERSIONS
+        .prefix(proposal_id)
+        .keys(deps.storage, None, None, Order::Descending)
+        .next()
+        .transpose()?;
+    let version = match latest {
+        Some(latest) => latest + 1,
+        None => {
+            PROPOSAL_VERSIONS.save(
+                deps.storage,
+                (proposal_id, 0),
+                &ProposalVersion {
+                    version: 0,
+                    height: prop.start_height,
+                    title: prop.title.clone(),
+                    description: prop.description.clone(),
+                    msgs: prop.msgs.clone(),
+                },
+            )?;
+            1
+        }
+    };
+
+    if let Some(title) = title {
+        prop.title = title;
+    }
+    if let Some(description) = description {
+        prop.description = description;
+    }
+    let votes_reset = match msgs {
+        Some(msgs) if msgs != prop.msgs => {
+            prop.msgs = msgs;
+            // Votes cast on the previous messages do not count
+            // towards the new ones. Rather than removing every
+            // ballot, bump the ballot version so that older ballots
+            // and commitments are ignored.
+            prop.ballot_version += 1;
+            prop.votes = Votes::zero();
+            true
+        }
+        _ => false,
+    };
+
+    check_proposal_size(&prop)?;
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+    let ballot_version = prop.ballot_version;
+    PROPOSAL_VERSIONS.save(
+        deps.storage,
+        (proposal_id, version),
+        &ProposalVersion {
+            version,
+            height: env.block.height,
+            title: prop.title,
+            description: prop.description,
+            msgs: prop.msgs,
+        },
+    )?;
+
+    let response = Response::default()
+        .add_attribute("action", "amend")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("version", version.to_string())
+        .add_attribute("votes_reset", votes_reset.to_string());
+
+    // Indexers tracking votes need to know to discard the ballots
+    // they have seen for this proposal.
+    Ok(if votes_reset {
+        response.add_event(
+            Event::new("votes_reset")
+                .add_attribute("proposal_id", proposal_id.to_string())
+                .add_attribute("ballot_version", ballot_version.to_string()),
+        )
+    } else {
+        response
+    })
+}
+
 pub fn execute_execute(
     deps: DepsMut,
     env: Env,
@@ -266,7 +501,35 @@ pub fn execute_execute(
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
     let config = CONFIG.load(deps.storage)?;
-    if config.only_members_execute {
+
+    // Check here that the proposal is passed. Allow it to be executed
+    // even if it is expired so long as it passed during its voting
+    // period. Proposals whose execution failed may be retried if the
+    // module has a retry policy.
+    let old_status = prop.status;
+    prop.update_status(&env.block);
+    let is_retry = match (prop.status, &config.retry_policy) {
+        (Status::Passed, _) => false,
+        (Status::Expired, _) => return Err(ContractError::ExecutionExpired {}),
+        (Status::ExecutionFailed, Some(policy)) => {
+            if prop
+                .execution_deadline
+                .map_or(false, |deadline| deadline.is_expired(&env.block))
+            {
+                return Err(ContractError::ExecutionExpired {});
+            }
+            if prop.execution_attempts > policy.max_retries {
+                return Err(ContractError::RetriesExhausted {
+                    max: policy.max_retries,
+                });
+            }
+            true
+        }
+        _ => return Err(ContractError::NotPassed {}),
+    };
+
+    // Retries may only be performed by members.
+    if config.only_members_execute || is_retry {
         let power = get_voting_power(
             deps.as_ref(),
             info.sender.clone(),
@@ -278,28 +541,64 @@ pub fn execute_execute(
         }
     }
 
-    // Check here that the proposal is passed. Allow it to be executed
-    // even if it is expired so long as it passed during its voting
-    // period.
-    let old_status = prop.status;
-    prop.update_status(&env.block);
-    if prop.status != Status::Passed {
-        return Err(ContractError::NotPassed {});
+    if let Some(dependency) = blocking_dependencies(deps.as_ref(), &env, proposal_id)?
+        .into_iter()
+        .next()
+    {
+        return Err(ContractError::DependencyNotExecuted {
+            proposal_module: dependency.proposal_module,
+            proposal_id: dependency.proposal_id,
+        });
     }
 
     prop.status = Status::Executed;
 
+    // Messages that failed when last executed individually. If
+    // present, only these messages are retried.
+    let failed_indices: Vec<u64> = prop
+        .execution_failures
+        .iter()
+        .filter_map(|failure| failure.msg_index)
+        .collect();
+    if config.retry_policy.is_some() {
+        prop.execution_attempts += 1;
+        prop.execution_failures = vec![];
+    }
+
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
     let response = {
-        if !prop.msgs.is_empty() {
-            let execute_message = WasmMsg::Execute {
-                contract_addr: config.dao.to_string(),
-                msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook {
-                    msgs: prop.msgs,
-                })?,
-                funds: vec![],
-            };
+        if prop.msgs.is_empty() {
+            Response::default()
+        } else if let Some(policy) = &config.retry_policy {
+            if policy.execute_individually || !failed_indices.is_empty() {
+                let indices = if failed_indices.is_empty() {
+                    (0..prop.msgs.len() as u64).collect()
+                } else {
+                    failed_indices
+                };
+                let submessages = indices
+                    .into_iter()
+                    .map(|index| {
+                        let execute_message = execute_proposal_hook_msg(
+                            &config.dao,
+                            vec![prop.msgs[index as usize].clone()],
+                        )?;
+                        Ok(SubMsg::reply_on_error(
+                            execute_message,
+                            mask_proposal_message_index(proposal_id, index),
+                        ))
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                Response::default().add_submessages(submessages)
+            } else {
+                let execute_message = execute_proposal_hook_msg(&config.dao, prop.msgs)?;
+                let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
+                Response::default()
+                    .add_submessage(SubMsg::reply_on_error(execute_message, masked_proposal_id))
+            }
+        } else {
+            let execute_message = execute_proposal_hook_msg(&config.dao, prop.msgs)?;
             match config.close_proposal_on_execution_failure {
                 true => {
                     let masked_proposal_id = mask_proposal_execution_proposal_id(proposal_id);
@@ -308,8 +607,6 @@ pub fn execute_execute(
                 }
                 false => Response::default().add_message(execute_message),
             }
-        } else {
-            Response::default()
         }
     };
 
@@ -321,14 +618,20 @@ pub fn execute_execute(
         prop.status.to_string(),
     )?;
 
-    // Add prepropose / deposit module hook which will handle deposit refunds.
+    // Add prepropose / deposit module hook which will handle deposit
+    // refunds. Deposits are settled on the first execution attempt so
+    // retries do not notify the pre-propose module.
     let proposal_creation_policy = CREATION_POLICY.load(deps.storage)?;
     let hooks = match proposal_creation_policy {
         ProposalCreationPolicy::Anyone {} => hooks,
+        ProposalCreationPolicy::Module { .. } if is_retry => hooks,
         ProposalCreationPolicy::Module { addr } => {
+            // Only passed proposals may be executed, so this proposal
+            // was not vetoed.
             let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status: prop.status,
+                vetoed: false,
             })?;
             let mut hooks = hooks;
             hooks.push(SubMsg::reply_on_error(
@@ -364,6 +667,10 @@ pub fn execute_vote(
         .may_load(deps.storage, proposal_id)?
         .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
 
+    if prop.reveal_deadline.is_some() {
+        return Err(ContractError::CommitRevealRequired {});
+    }
+
     // Allow voting on proposals until they expire.
     // Voting on a non-open proposal will never change
     // their outcome as if an outcome has been determined,
@@ -386,7 +693,9 @@ pub fn execute_vote(
     }
 
     BALLOTS.update(deps.storage, (proposal_id, &info.sender), |bal| match bal {
-        Some(current_ballot) => {
+        // Ballots cast before the proposal's messages were amended
+        // were removed from the tally when it was reset.
+        Some(current_ballot) if current_ballot.version == prop.ballot_version => {
             if prop.allow_revoting {
                 if current_ballot.vote == vote {
                     // Don't allow casting the same vote more than
@@ -404,16 +713,18 @@ pub fn execute_vote(
                         // you're changing your vote, you've also
                         // likely changed your thinking.
                         rationale: rationale.clone(),
+                        version: prop.ballot_version,
                     })
                 }
             } else {
                 Err(ContractError::AlreadyVoted {})
             }
         }
-        None => Ok(Ballot {
+        _ => Ok(Ballot {
             power: vote_power,
             vote,
             rationale: rationale.clone(),
+            version: prop.ballot_version,
         }),
     })?;
 
@@ -452,23 +763,217 @@ pub fn execute_vote(
         .add_attribute("status", prop.status.to_string()))
 }
 
+pub fn execute_commit_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    commitment: Binary,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if prop.reveal_deadline.is_none() {
+        return Err(ContractError::NotCommitReveal {});
+    }
+    if prop.expiration.is_expired(&env.block) {
+        return Err(ContractError::Expired { id: proposal_id });
+    }
+
+    let vote_power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?;
+    if vote_power.is_zero() {
+        return Err(ContractError::NotRegistered {});
+    }
+
+    COMMITMENTS.update(
+        deps.storage,
+        (proposal_id, &info.sender),
+        |existing| match existing {
+            Some(existing) if existing.version == prop.ballot_version && !prop.allow_revoting => {
+                Err(ContractError::AlreadyVoted {})
+            }
+            _ => Ok(Commitment {
+                commitment,
+                version: prop.ballot_version,
+            }),
+        },
+    )?;
+
+    Ok(Response::default()
+        .add_attribute("action", "commit_vote")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string()))
+}
+
+pub fn execute_reveal_vote(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    vote: Vote,
+    salt: String,
+) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    let mut prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
+    if prop.reveal_deadline.is_none() {
+        return Err(ContractError::NotCommitReveal {});
+    }
+    if !prop.expiration.is_expired(&env.block) || !prop.is_revealing(&env.block) {
+        return Err(ContractError::NotRevealPeriod {});
+    }
+
+    let commitment = COMMITMENTS
+        .may_load(deps.storage, (proposal_id, &info.sender))?
+        .filter(|commitment| commitment.version == prop.ballot_version)
+        .ok_or_else(|| ContractError::NoSuchVote {
+            id: proposal_id,
+            voter: info.sender.to_string(),
+        })?;
+    let reveal = VoteReveal {
+        proposal_module: env.contract.address.to_string(),
+        proposal_id,
+        voter: info.sender.to_string(),
+        vote,
+        salt,
+    };
+    if reveal.commitment()? != commitment.commitment {
+        return Err(ContractError::InvalidReveal {});
+    }
+    COMMITMENTS.remove(deps.storage, (proposal_id, &info.sender));
+
+    let vote_power = get_voting_power(
+        deps.as_ref(),
+        info.sender.clone(),
+        &config.dao,
+        Some(prop.start_height),
+    )?;
+    BALLOTS.save(
+        deps.storage,
+        (proposal_id, &info.sender),
+        &Ballot {
+            power: vote_power,
+            vote,
+            rationale: None,
+            version: prop.ballot_version,
+        },
+    )?;
+
+    let old_status = prop.status;
+
+    prop.votes.add_vote(vote, vote_power);
+    prop.update_status(&env.block);
+
+    PROPOSALS.save(deps.storage, proposal_id, &prop)?;
+
+    let change_hooks = proposal_status_changed_hooks(
+        PROPOSAL_HOOKS,
+        deps.storage,
+        proposal_id,
+        old_status.to_string(),
+        prop.status.to_string(),
+    )?;
+
+    let vote_hooks = new_vote_hooks(
+        VOTE_HOOKS,
+        deps.storage,
+        proposal_id,
+        info.sender.to_string(),
+        vote.to_string(),
+    )?;
+
+    Ok(Response::default()
+        .add_submessages(change_hooks)
+        .add_submessages(vote_hooks)
+        .add_attribute("action", "reveal_vote")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string())
+        .add_attribute("position", vote.to_string())
+        .add_attribute("status", prop.status.to_string()))
+}
+
+pub fn execute_vote_signed(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    proposal_id: u64,
+    ballots: Vec<SignedBallot<Vote>>,
+) -> Result<Response, ContractError> {
+    let mut response = Response::default()
+        .add_attribute("action", "vote_signed")
+        .add_attribute("sender", info.sender)
+        .add_attribute("proposal_id", proposal_id.to_string());
+
+    for ballot in ballots {
+        let voter = ballot.verify(
+            deps.api,
+            &env.block.chain_id,
+            &env.contract.address,
+            proposal_id,
+        )?;
+        let voter = deps.api.addr_humanize(&voter)?;
+
+        let expected = BALLOT_NONCES
+            .may_load(deps.storage, &voter)?
+            .unwrap_or_default();
+        if ballot.nonce != expected {
+            return Err(SignedBallotError::WrongNonce {
+                expected,
+                actual: ballot.nonce,
+            }
+            .into());
+        }
+        BALLOT_NONCES.save(deps.storage, &voter, &(expected + 1))?;
+
+        let vote = execute_vote(
+            deps.branch(),
+            env.clone(),
+            MessageInfo {
+                sender: voter.clone(),
+                funds: vec![],
+            },
+            proposal_id,
+            ballot.vote,
+            None,
+        )?;
+        response = response
+            .add_submessages(vote.messages)
+            .add_attribute("voter", voter);
+    }
+
+    Ok(response)
+}
+
 pub fn execute_update_rationale(
     deps: DepsMut,
     info: MessageInfo,
     proposal_id: u64,
     rationale: Option<String>,
 ) -> Result<Response, ContractError> {
+    let prop = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .ok_or(ContractError::NoSuchProposal { id: proposal_id })?;
+
     BALLOTS.update(
         deps.storage,
         // info.sender can't be forged so we implicitly access control
         // with the key.
         (proposal_id, &info.sender),
         |ballot| match ballot {
-            Some(ballot) => Ok(Ballot {
+            Some(ballot) if ballot.version == prop.ballot_version => Ok(Ballot {
                 rationale: rationale.clone(),
                 ..ballot
             }),
-            None => Err(ContractError::NoSuchVote {
+            _ => Err(ContractError::NoSuchVote {
                 id: proposal_id,
                 voter: info.sender.to_string(),
             }),
@@ -489,17 +994,21 @@ pub fn execute_close(
     proposal_id: u64,
 ) -> Result<Response, ContractError> {
     let mut prop = PROPOSALS.load(deps.storage, proposal_id)?;
+    let stored_status = prop.status;
 
     // Update status to ensure that proposals which were open and have
-    // expired are moved to "rejected."
+    // expired are moved to "rejected," and that passed proposals
+    // which were not executed in time are moved to "expired."
     prop.update_status(&env.block);
-    if prop.status != Status::Rejected {
-        return Err(ContractError::WrongCloseStatus {});
-    }
-
-    let old_status = prop.status;
+    let (old_status, new_status) = match prop.status {
+        Status::Rejected => (Status::Rejected, Status::Closed),
+        // The stored status is only expired once the proposal has
+        // been closed.
+        Status::Expired if stored_status != Status::Expired => (Status::Passed, Status::Expired),
+        _ => return Err(ContractError::WrongCloseStatus {}),
+    };
 
-    prop.status = Status::Closed;
+    prop.status = new_status;
     PROPOSALS.save(deps.storage, proposal_id, &prop)?;
 
     let hooks = proposal_status_changed_hooks(
@@ -518,6 +1027,7 @@ pub fn execute_close(
             let msg = to_binary(&PreProposeHookMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status: prop.status,
+                vetoed: prop.is_vetoed(&env.block),
             })?;
             let mut hooks = hooks;
             hooks.push(SubMsg::reply_on_error(
@@ -550,6 +1060,12 @@ pub fn execute_update_config(
     allow_revoting: bool,
     dao: String,
     close_proposal_on_execution_failure: bool,
+    early_execution: Option<PercentageThreshold>,
+    execution_window: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    amendment_window: Option<Duration>,
+    reveal_period: Option<Duration>,
+    veto_threshold: Option<PercentageThreshold>,
 ) -> Result<Response, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
@@ -558,10 +1074,19 @@ pub fn execute_update_config(
         return Err(ContractError::Unauthorized {});
     }
     threshold.validate()?;
+    if let Some(early_execution) = early_execution {
+        validate_percentage(&early_execution)?;
+    }
+    if let Some(veto_threshold) = veto_threshold {
+        validate_percentage(&veto_threshold)?;
+    }
     let dao = deps.api.addr_validate(&dao)?;
 
     let (min_voting_period, max_voting_period) =
         validate_voting_period(min_voting_period, max_voting_period)?;
+    validate_execution_window(execution_window, max_voting_period)?;
+    validate_amendment_window(amendment_window)?;
+    validate_reveal_period(reveal_period, max_voting_period)?;
 
     CONFIG.save(
         deps.storage,
@@ -573,6 +1098,12 @@ pub fn execute_update_config(
             allow_revoting,
             dao,
             close_proposal_on_execution_failure,
+            early_execution,
+            execution_window,
+            retry_policy,
+            amendment_window,
+            reveal_period,
+            veto_threshold,
         },
     )?;
 
@@ -581,6 +1112,54 @@ pub fn execute_update_config(
         .add_attribute("sender", info.sender))
 }
 
+/// Validates that an execution window may be added to expirations
+/// computed from `max_voting_period`.
+fn validate_execution_window(
+    execution_window: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    match execution_window {
+        Some(window) if !extends_voting_period(window, max_voting_period) => {
+            Err(ContractError::InvalidExecutionWindow {})
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Validates that a reveal period may be added to expirations
+/// computed from `max_voting_period`.
+fn validate_reveal_period(
+    reveal_period: Option<Duration>,
+    max_voting_period: Duration,
+) -> Result<(), ContractError> {
+    match reveal_period {
+        Some(period) if !extends_voting_period(period, max_voting_period) => {
+            Err(ContractError::InvalidRevealPeriod {})
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns true if `period` is non-zero and has the same units as
+/// `max_voting_period`.
+fn extends_voting_period(period: Duration, max_voting_period: Duration) -> bool {
+    match (period, max_voting_period) {
+        (Duration::Height(period), Duration::Height(_))
+        | (Duration::Time(period), Duration::Time(_)) => period != 0,
+        _ => false,
+    }
+}
+
+/// Validates that an amendment window, if set, is non-zero.
+fn validate_amendment_window(amendment_window: Option<Duration>) -> Result<(), ContractError> {
+    match amendment_window {
+        Some(Duration::Height(0)) | Some(Duration::Time(0)) => {
+            Err(ContractError::InvalidAmendmentWindow {})
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn execute_update_proposal_creation_policy(
     deps: DepsMut,
     info: MessageInfo,
@@ -732,6 +1311,11 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
         QueryMsg::ProposalCreationPolicy {} => query_creation_policy(deps),
         QueryMsg::ProposalHooks {} => to_binary(&PROPOSAL_HOOKS.query_hooks(deps)?),
         QueryMsg::VoteHooks {} => to_binary(&VOTE_HOOKS.query_hooks(deps)?),
+        QueryMsg::BlockingDependencies { proposal_id } => {
+            query_blocking_dependencies(deps, env, proposal_id)
+        }
+        QueryMsg::ProposalVersions { proposal_id } => query_proposal_versions(deps, proposal_id),
+        QueryMsg::BallotNonce { address } => query_ballot_nonce(deps, address),
     }
 }
 
@@ -750,6 +1334,40 @@ pub fn query_proposal(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
     to_binary(&proposal.into_response(&env.block, id))
 }
 
+pub fn query_blocking_dependencies(deps: Deps, env: Env, id: u64) -> StdResult<Binary> {
+    // Error if the proposal does not exist.
+    PROPOSALS.load(deps.storage, id)?;
+    to_binary(&blocking_dependencies(deps, &env, id)?)
+}
+
+pub fn query_proposal_versions(deps: Deps, id: u64) -> StdResult<Binary> {
+    let proposal = PROPOSALS.load(deps.storage, id)?;
+    let versions = PROPOSAL_VERSIONS
+        .prefix(id)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(_, version)| version))
+        .collect::<StdResult<Vec<_>>>()?;
+    if versions.is_empty() {
+        to_binary(&vec![ProposalVersion {
+            version: 0,
+            height: proposal.start_height,
+            title: proposal.title,
+            description: proposal.description,
+            msgs: proposal.msgs,
+        }])
+    } else {
+        to_binary(&versions)
+    }
+}
+
+pub fn query_ballot_nonce(deps: Deps, address: String) -> StdResult<Binary> {
+    let address = deps.api.addr_validate(&address)?;
+    let nonce = BALLOT_NONCES
+        .may_load(deps.storage, &address)?
+        .unwrap_or_default();
+    to_binary(&nonce)
+}
+
 pub fn query_creation_policy(deps: Deps) -> StdResult<Binary> {
     let policy = CREATION_POLICY.load(deps.storage)?;
     to_binary(&policy)
@@ -804,7 +1422,13 @@ pub fn query_next_proposal_id(deps: Deps) -> StdResult<Binary> {
 
 pub fn query_vote(deps: Deps, proposal_id: u64, voter: String) -> StdResult<Binary> {
     let voter = deps.api.addr_validate(&voter)?;
-    let ballot = BALLOTS.may_load(deps.storage, (proposal_id, &voter))?;
+    let ballot_version = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .map(|prop| prop.ballot_version)
+        .unwrap_or_default();
+    let ballot = BALLOTS
+        .may_load(deps.storage, (proposal_id, &voter))?
+        .filter(|ballot| ballot.version == ballot_version);
     let vote = ballot.map(|ballot| VoteInfo {
         voter,
         vote: ballot.vote,
@@ -825,10 +1449,18 @@ pub fn query_list_votes(
         .map(|addr| deps.api.addr_validate(&addr))
         .transpose()?;
     let min = start_after.as_ref().map(Bound::<&Addr>::exclusive);
+    let ballot_version = PROPOSALS
+        .may_load(deps.storage, proposal_id)?
+        .map(|prop| prop.ballot_version)
+        .unwrap_or_default();
 
     let votes = BALLOTS
         .prefix(proposal_id)
         .range(deps.storage, min, None, cosmwasm_std::Order::Ascending)
+        .filter(|item| {
+            item.as_ref()
+                .map_or(true, |(_, ballot)| ballot.version == ballot_version)
+        })
         .take(limit as usize)
         .map(|item| {
             let (voter, ballot) = item?;
@@ -880,6 +1512,12 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                     allow_revoting: current_config.allow_revoting,
                     dao: current_config.dao.clone(),
                     close_proposal_on_execution_failure,
+                    early_execution: None,
+                    execution_window: None,
+                    retry_policy: None,
+                    amendment_window: None,
+                    reveal_period: None,
+                    veto_threshold: None,
                 },
             )?;
 
@@ -917,13 +1555,20 @@ pub fn migrate(deps: DepsMut, _env: Env, msg: MigrateMsg) -> Result<Response, Co
                         proposer: prop.proposer,
                         start_height: prop.start_height,
                         min_voting_period: prop.min_voting_period.map(v1_expiration_to_v2),
+                        early_execution: None,
                         expiration: v1_expiration_to_v2(prop.expiration),
+                        reveal_deadline: None,
+                        execution_deadline: None,
+                        veto_threshold: None,
                         threshold: v1_threshold_to_v2(prop.threshold),
                         total_power: prop.total_power,
                         msgs: prop.msgs,
                         status: v1_status_to_v2(prop.status),
                         votes: v1_votes_to_v2(prop.votes),
                         allow_revoting: prop.allow_revoting,
+                        execution_attempts: 0,
+                        execution_failures: vec![],
+                        ballot_version: 0,
                     };
 
                     PROPOSALS
@@ -948,9 +1593,14 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
     let repl = TaggedReplyId::new(msg.id)?;
     match repl {
         TaggedReplyId::FailedProposalExecution(proposal_id) => {
+            let error = msg.result.into_result().err().unwrap_or_default();
             PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
                 Some(mut prop) => {
                     prop.status = Status::ExecutionFailed;
+                    prop.execution_failures.push(ExecutionFailure {
+                        msg_index: None,
+                        error,
+                    });
 
                     Ok(prop)
                 }
@@ -959,6 +1609,25 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
 
             Ok(Response::new().add_attribute("proposal_execution_failed", proposal_id.to_string()))
         }
+        TaggedReplyId::FailedProposalMessage(proposal_id, index) => {
+            let error = msg.result.into_result().err().unwrap_or_default();
+            PROPOSALS.update(deps.storage, proposal_id, |prop| match prop {
+                Some(mut prop) => {
+                    prop.status = Status::ExecutionFailed;
+                    prop.execution_failures.push(ExecutionFailure {
+                        msg_index: Some(index),
+                        error,
+                    });
+
+                    Ok(prop)
+                }
+                None => Err(ContractError::NoSuchProposal { id: proposal_id }),
+            })?;
+
+            Ok(Response::new()
+                .add_attribute("proposal_execution_failed", proposal_id.to_string())
+                .add_attribute("failed_message_index", index.to_string()))
+        }
         TaggedReplyId::FailedProposalHook(idx) => {
             let addr = PROPOSAL_HOOKS.remove_hook_by_index(deps.storage, idx)?;
             Ok(Response::new().add_attribute("removed_proposal_hook", format!("{addr}:{idx}")))
@@ -1012,3 +1681,61 @@ pub fn reply(deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractE
         }
     }
 }
+
+/// Errors if a proposal is larger than `MAX_PROPOSAL_SIZE`. See the
+/// comment in `execute_propose` for why this limit exists.
+fn check_proposal_size(proposal: &SingleChoiceProposal) -> Result<(), ContractError> {
+    // `to_vec` is the method used by cosmwasm to convert a struct
+    // into it's byte representation in storage.
+    let proposal_size = cosmwasm_std::to_vec(proposal)?.len() as u64;
+    if proposal_size > MAX_PROPOSAL_SIZE {
+        return Err(ContractError::ProposalTooLarge {
+            size: proposal_size,
+            max: MAX_PROPOSAL_SIZE,
+        });
+    }
+    Ok(())
+}
+
+/// Builds a message asking the DAO to execute `msgs` on behalf of
+/// this module.
+fn execute_proposal_hook_msg(dao: &Addr, msgs: Vec<CosmosMsg<Empty>>) -> StdResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: dao.to_string(),
+        msg: to_binary(&dao_core::msg::ExecuteMsg::ExecuteProposalHook { msgs })?,
+        funds: vec![],
+    })
+}
+
+/// Gets the status of the proposal a dependency refers to. Errors if
+/// the proposal does not exist.
+fn dependency_status(
+    deps: Deps,
+    env: &Env,
+    dependency: &CheckedProposalDependency,
+) -> StdResult<Status> {
+    if dependency.proposal_module == env.contract.address {
+        let proposal = PROPOSALS.load(deps.storage, dependency.proposal_id)?;
+        Ok(proposal.current_status(&env.block))
+    } else {
+        dependency.query_status(&deps.querier)
+    }
+}
+
+/// Gets the dependencies of a proposal that have not been executed.
+fn blocking_dependencies(
+    deps: Deps,
+    env: &Env,
+    proposal_id: u64,
+) -> StdResult<Vec<CheckedProposalDependency>> {
+    let mut blocking = vec![];
+    for dependency in DEPENDENCIES
+        .may_load(deps.storage, proposal_id)?
+        .unwrap_or_default()
+    {
+        if dependency_status(deps, env, &dependency)? != Status::Executed {
+            blocking.push(dependency);
+        }
+    }
+    Ok(blocking)
+}
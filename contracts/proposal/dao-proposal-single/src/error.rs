@@ -1,6 +1,6 @@
 use std::u64;
 
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Addr, StdError};
 use cw_hooks::HookError;
 use cw_utils::ParseReplyError;
 use dao_voting::reply::error::TagError;
@@ -26,6 +26,9 @@ pub enum ContractError {
     #[error(transparent)]
     VotingError(#[from] dao_voting::error::VotingError),
 
+    #[error(transparent)]
+    SignedBallotError(#[from] dao_voting::signed_ballot::SignedBallotError),
+
     #[error("no such proposal ({id})")]
     NoSuchProposal { id: u64 },
 
@@ -56,7 +59,7 @@ pub enum ContractError {
     #[error("proposal is closed")]
     Closed {},
 
-    #[error("only rejected proposals may be closed")]
+    #[error("only rejected or expired proposals may be closed")]
     WrongCloseStatus {},
 
     #[error("the DAO is currently inactive, you cannot create proposals")]
@@ -68,11 +71,62 @@ pub enum ContractError {
     #[error("min voting period must be less than or equal to max voting period")]
     InvalidMinVotingPeriod {},
 
+    #[error("execution_window must be non-zero and have the same units as max_voting_period")]
+    InvalidExecutionWindow {},
+
+    #[error("proposal was not executed before its execution deadline")]
+    ExecutionExpired {},
+
+    #[error("proposal execution may be retried at most ({max}) times")]
+    RetriesExhausted { max: u32 },
+
+    #[error("amendment_window must be non-zero")]
+    InvalidAmendmentWindow {},
+
+    #[error("proposals may only be amended while open and within their amendment window")]
+    NotAmendable {},
+
+    #[error("proposals created through a pre-propose module may not have their messages amended")]
+    MessagesNotAmendable {},
+
+    #[error("reveal_period must be non-zero and have the same units as max_voting_period")]
+    InvalidRevealPeriod {},
+
+    #[error("votes on this proposal must be committed and revealed")]
+    CommitRevealRequired {},
+
+    #[error("votes on this proposal are not committed and revealed")]
+    NotCommitReveal {},
+
+    #[error("votes may only be revealed after voting ends and before the reveal period ends")]
+    NotRevealPeriod {},
+
+    #[error("revealed vote does not match commitment")]
+    InvalidReveal {},
+
     #[error(
         "pre-propose modules must specify a proposer. lacking one, no proposer should be specified"
     )]
     InvalidProposer {},
 
+    #[error("only pre-propose modules may set a proposal's threshold")]
+    ThresholdOverrideNotPermitted {},
+
+    #[error("proposals may not depend on more than ({max}) other proposals")]
+    TooManyDependencies { max: usize },
+
+    #[error("proposal ({proposal_id}) in ({proposal_module}) can never be executed")]
+    UnexecutableDependency {
+        proposal_module: Addr,
+        proposal_id: u64,
+    },
+
+    #[error("proposal ({proposal_id}) in ({proposal_module}) must be executed first")]
+    DependencyNotExecuted {
+        proposal_module: Addr,
+        proposal_id: u64,
+    },
+
     #[error(transparent)]
     Tag(#[from] TagError),
 
@@ -1,11 +1,17 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, CosmosMsg, Empty};
 use cw_utils::Duration;
 use dao_macros::proposal_module_query;
 use dao_voting::{
-    pre_propose::PreProposeInfo, proposal::SingleChoiceProposeMsg, threshold::Threshold,
+    pre_propose::PreProposeInfo,
+    proposal::SingleChoiceProposeMsg,
+    signed_ballot::SignedBallot,
+    threshold::{PercentageThreshold, Threshold},
     voting::Vote,
 };
 
+use crate::state::RetryPolicy;
+
 #[cw_serde]
 pub struct InstantiateMsg {
     /// The threshold a proposal must reach to complete.
@@ -38,6 +44,34 @@ pub struct InstantiateMsg {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set, proposals may pass before the minimum voting period
+    /// has elapsed once this percentage of the total voting power
+    /// has voted yes. Proposals must still reach their passing
+    /// threshold.
+    pub early_execution: Option<PercentageThreshold>,
+    /// If set, passed proposals that have not been executed this
+    /// long after their voting period ends expire and may no longer
+    /// be executed. Must have the same units as `max_voting_period`.
+    pub execution_window: Option<Duration>,
+    /// If set, proposals whose execution fails record the failure
+    /// and may be executed again by members a limited number of
+    /// times. Takes precedence over
+    /// `close_proposal_on_execution_failure`.
+    pub retry_policy: Option<RetryPolicy>,
+    /// If set, proposers may amend the title, description, and
+    /// messages of their proposals for this long after creation
+    /// while voting is open. Amending a proposal's messages discards
+    /// the votes cast on it.
+    pub amendment_window: Option<Duration>,
+    /// If set, votes are cast by committing to a hash of the vote
+    /// during the voting period and revealing the vote during this
+    /// period after it. Unrevealed votes are not counted. Must have
+    /// the same units as `max_voting_period`.
+    pub reveal_period: Option<Duration>,
+    /// If set, proposals are vetoed and rejected once this
+    /// percentage of the votes cast on them are no with veto
+    /// votes. The deposits of vetoed proposals are not refunded.
+    pub veto_threshold: Option<PercentageThreshold>,
 }
 
 #[cw_serde]
@@ -56,12 +90,55 @@ pub enum ExecuteMsg {
         /// the vote.
         rationale: Option<String>,
     },
+    /// Commits to a vote on a proposal in commit-reveal mode. The
+    /// commitment is the SHA-256 hash of a JSON serialized
+    /// `dao_voting::voting::VoteReveal` for this module, proposal, and
+    /// sender. Commitments may only be replaced if revoting is
+    /// allowed.
+    CommitVote {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The commitment to the sender's vote.
+        commitment: Binary,
+    },
+    /// Reveals a vote previously committed to, counting it. Votes
+    /// may only be revealed after the proposal's voting period ends
+    /// and before its reveal period ends.
+    RevealVote {
+        /// The ID of the proposal the vote was cast on.
+        proposal_id: u64,
+        /// The sender's position on the proposal.
+        vote: Vote,
+        /// The salt used when committing to the vote.
+        salt: String,
+    },
+    /// Casts votes signed off-chain on behalf of their signers. Each
+    /// ballot is counted as if its signer had voted. Errors if any
+    /// ballot is invalid.
+    VoteSigned {
+        /// The ID of the proposal to vote on.
+        proposal_id: u64,
+        /// The signed ballots to cast.
+        ballots: Vec<SignedBallot<Vote>>,
+    },
     /// Updates the sender's rationale for their vote on the specified
     /// proposal. Errors if no vote vote has been cast.
     UpdateRationale {
         proposal_id: u64,
         rationale: Option<String>,
     },
+    /// Amends the content of a proposal. Only the proposer may amend
+    /// a proposal, and only while it is open and within its amendment
+    /// window. Fields that are not set are left unchanged. Changing a
+    /// proposal's messages discards all votes cast on it. Messages
+    /// may not be amended if proposals are created through a
+    /// pre-propose module.
+    Amend {
+        proposal_id: u64,
+        title: Option<String>,
+        description: Option<String>,
+        msgs: Option<Vec<CosmosMsg<Empty>>>,
+    },
     /// Causes the messages associated with a passed proposal to be
     /// executed by the DAO.
     Execute {
@@ -110,6 +187,34 @@ pub enum ExecuteMsg {
         /// remain open until the DAO's treasury was large enough for it to be
         /// executed.
         close_proposal_on_execution_failure: bool,
+        /// If set, proposals may pass before the minimum voting period
+        /// has elapsed once this percentage of the total voting power
+        /// has voted yes. Proposals must still reach their passing
+        /// threshold.
+        early_execution: Option<PercentageThreshold>,
+        /// If set, passed proposals that have not been executed this
+        /// long after their voting period ends expire and may no
+        /// longer be executed. Must have the same units as
+        /// `max_voting_period`. Applies only to future proposals.
+        execution_window: Option<Duration>,
+        /// If set, proposals whose execution fails record the
+        /// failure and may be executed again by members a limited
+        /// number of times. Takes precedence over
+        /// `close_proposal_on_execution_failure`.
+        retry_policy: Option<RetryPolicy>,
+        /// If set, proposers may amend the title, description, and
+        /// messages of their proposals for this long after creation
+        /// while voting is open. Applies only to future proposals.
+        amendment_window: Option<Duration>,
+        /// If set, votes are committed during the voting period and
+        /// revealed during this period after it. Must have the same
+        /// units as `max_voting_period`. Applies only to future
+        /// proposals.
+        reveal_period: Option<Duration>,
+        /// If set, proposals are vetoed and rejected once this
+        /// percentage of the votes cast on them are no with veto
+        /// votes. Applies only to future proposals.
+        veto_threshold: Option<PercentageThreshold>,
     },
     /// Update's the proposal creation policy used for this
     /// module. Only the DAO may call this method.
@@ -195,6 +300,20 @@ pub enum QueryMsg {
     /// Lists all of the consumers of vote hooks for this module.
     #[returns(::cw_hooks::HooksResponse)]
     VoteHooks {},
+    /// Lists the dependencies of a proposal that have not yet been
+    /// executed. The proposal may not be executed until this is
+    /// empty.
+    #[returns(Vec<::dao_voting::proposal::CheckedProposalDependency>)]
+    BlockingDependencies { proposal_id: u64 },
+    /// Lists the versions of a proposal's content from oldest to
+    /// newest. Proposals that have not been amended have a single
+    /// version.
+    #[returns(Vec<crate::proposal::ProposalVersion>)]
+    ProposalVersions { proposal_id: u64 },
+    /// Gets the nonce the next signed ballot from `address` must
+    /// use.
+    #[returns(u64)]
+    BallotNonce { address: String },
 }
 
 #[cw_serde]
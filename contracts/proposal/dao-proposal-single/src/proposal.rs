@@ -4,7 +4,7 @@ use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{Addr, BlockInfo, CosmosMsg, Decimal, Empty, StdResult, Storage, Uint128};
 use cw_utils::Expiration;
 use dao_voting::status::Status;
-use dao_voting::threshold::{PercentageThreshold, Threshold};
+use dao_voting::threshold::{dynamic_quorum, PercentageThreshold, Threshold};
 use dao_voting::voting::{does_vote_count_fail, does_vote_count_pass, Votes};
 
 #[cw_serde]
@@ -21,11 +21,23 @@ pub struct SingleChoiceProposal {
     /// voting. The proposal may not pass unless this is expired or
     /// None.
     pub min_voting_period: Option<Expiration>,
+    /// If set, the proposal may pass before `min_voting_period` has
+    /// expired once this percentage of `total_power` has voted yes.
+    pub early_execution: Option<PercentageThreshold>,
     /// The the time at which this proposal will expire and close for
     /// additional votes.
     pub expiration: Expiration,
+    /// If set, votes on this proposal are committed before
+    /// `expiration` and revealed between `expiration` and this time.
+    pub reveal_deadline: Option<Expiration>,
+    /// If set, the time after which this proposal may no longer be
+    /// executed should it pass.
+    pub execution_deadline: Option<Expiration>,
     /// The threshold at which this proposal will pass.
     pub threshold: Threshold,
+    /// If set, the percentage of votes cast that must be no with
+    /// veto votes for this proposal to be vetoed.
+    pub veto_threshold: Option<PercentageThreshold>,
     /// The total amount of voting power at the time of this
     /// proposal's creation.
     pub total_power: Uint128,
@@ -34,6 +46,42 @@ pub struct SingleChoiceProposal {
     pub status: Status,
     pub votes: Votes,
     pub allow_revoting: bool,
+    /// The number of times execution of this proposal has been
+    /// attempted under the module's retry policy.
+    #[serde(default)]
+    pub execution_attempts: u32,
+    /// The failures recorded during this proposal's most recent
+    /// execution attempt.
+    #[serde(default)]
+    pub execution_failures: Vec<ExecutionFailure>,
+    /// Incremented each time an amendment changes this proposal's
+    /// messages. Ballots and commitments with an older version were
+    /// cast on previous messages and do not count.
+    #[serde(default)]
+    pub ballot_version: u32,
+}
+
+/// A version of a proposal's content.
+#[cw_serde]
+pub struct ProposalVersion {
+    /// The version number. The content a proposal was created with
+    /// is version zero.
+    pub version: u32,
+    /// The block height at which this version was created.
+    pub height: u64,
+    pub title: String,
+    pub description: String,
+    pub msgs: Vec<CosmosMsg<Empty>>,
+}
+
+/// A failure recorded while executing a proposal.
+#[cw_serde]
+pub struct ExecutionFailure {
+    /// The index of the message that failed, if the proposal's
+    /// messages were executed individually.
+    pub msg_index: Option<u64>,
+    /// The error returned by the failed execution.
+    pub error: String,
 }
 
 pub fn next_proposal_id(store: &dyn Storage) -> StdResult<u64> {
@@ -61,15 +109,58 @@ impl SingleChoiceProposal {
 
     /// Gets the current status of the proposal.
     pub fn current_status(&self, block: &BlockInfo) -> Status {
-        if self.status == Status::Open && self.is_passed(block) {
+        let status = if self.status == Status::Open && self.is_passed(block) {
             Status::Passed
         } else if self.status == Status::Open
-            && (self.expiration.is_expired(block) || self.is_rejected(block))
+            && ((self.expiration.is_expired(block) && !self.is_revealing(block))
+                || self.is_rejected(block))
         {
             Status::Rejected
         } else {
             self.status
+        };
+        if status == Status::Passed
+            && self
+                .execution_deadline
+                .map_or(false, |deadline| deadline.is_expired(block))
+        {
+            Status::Expired
+        } else {
+            status
+        }
+    }
+
+    /// Returns true if votes on this proposal are committed and
+    /// revealed and its reveal period has not ended. Votes are not
+    /// known until revealed, so the outcome of such a proposal is not
+    /// known until then either.
+    pub fn is_revealing(&self, block: &BlockInfo) -> bool {
+        self.reveal_deadline
+            .map_or(false, |deadline| !deadline.is_expired(block))
+    }
+
+    /// Returns true if this proposal has been vetoed. Before
+    /// expiration a proposal is vetoed once its no with veto votes
+    /// reach the veto threshold of the total voting power, as no
+    /// sequence of outstanding votes can then prevent it from being
+    /// vetoed. After expiration only the votes cast are considered.
+    pub fn is_vetoed(&self, block: &BlockInfo) -> bool {
+        let veto_threshold = match self.veto_threshold {
+            Some(veto_threshold) => veto_threshold,
+            None => return false,
+        };
+        if self.allow_revoting && !self.expiration.is_expired(block) {
+            return false;
+        }
+        if self.is_revealing(block) {
+            return false;
         }
+        let options = if self.expiration.is_expired(block) {
+            self.votes.total()
+        } else {
+            self.total_power
+        };
+        does_vote_count_pass(self.votes.no_with_veto, options, veto_threshold)
     }
 
     /// Sets a proposals status to its current status.
@@ -87,13 +178,39 @@ impl SingleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return false;
         }
+        // Proposals may not pass early while votes are committed and
+        // revealed.
+        if self.is_revealing(block) {
+            return false;
+        }
         // If the min voting period is set and not expired the
         // proposal can not yet be passed. This gives DAO members some
         // time to remove liquidity / scheme on a recovery plan if a
         // single actor accumulates enough tokens to unilaterally pass
-        // proposals.
+        // proposals. A supermajority of all voting power may pass
+        // proposals early if the module is configured to allow it.
         if let Some(min) = self.min_voting_period {
-            if !min.is_expired(block) {
+            if !min.is_expired(block)
+                && !self.early_execution.map_or(false, |supermajority| {
+                    does_vote_count_pass(self.votes.yes, self.total_power, supermajority)
+                })
+            {
+                return false;
+            }
+        }
+        // Passes only if, even should all outstanding votes be no
+        // with veto votes, the proposal can not be vetoed.
+        if let Some(veto_threshold) = self.veto_threshold {
+            let (outstanding_votes, options) = if self.expiration.is_expired(block) {
+                (Uint128::zero(), self.votes.total())
+            } else {
+                (self.total_power - self.votes.total(), self.total_power)
+            };
+            if does_vote_count_pass(
+                self.votes.no_with_veto + outstanding_votes,
+                options,
+                veto_threshold,
+            ) {
                 return false;
             }
         }
@@ -121,6 +238,50 @@ impl SingleChoiceProposal {
                 }
             }
             Threshold::AbsoluteCount { threshold } => self.votes.yes >= threshold,
+            Threshold::Optimistic {
+                objection_threshold,
+            } => {
+                // Passes if, even should all outstanding votes be no
+                // votes, the proposal can not be objected to. At
+                // expiration there are no outstanding votes.
+                let outstanding_votes = if self.expiration.is_expired(block) {
+                    Uint128::zero()
+                } else {
+                    self.total_power - self.votes.total()
+                };
+                !does_vote_count_pass(
+                    self.votes.no + outstanding_votes,
+                    self.total_power,
+                    objection_threshold,
+                )
+            }
+            Threshold::DynamicQuorum {
+                threshold,
+                base_quorum,
+                opposed_quorum,
+            } => {
+                if self.expiration.is_expired(block) {
+                    let quorum =
+                        dynamic_quorum(base_quorum, opposed_quorum, self.votes.yes, self.votes.no);
+                    let options = self.votes.total() - self.votes.abstain;
+                    does_vote_count_pass(
+                        self.votes.total(),
+                        self.total_power,
+                        PercentageThreshold::Percent(quorum),
+                    ) && does_vote_count_pass(self.votes.yes, options, threshold)
+                } else {
+                    // Outstanding votes may change the required
+                    // quorum, so before expiration the proposal must
+                    // meet the largest quorum it could be held to.
+                    let quorum = base_quorum.max(opposed_quorum);
+                    let options = self.total_power - self.votes.abstain;
+                    does_vote_count_pass(
+                        self.votes.total(),
+                        self.total_power,
+                        PercentageThreshold::Percent(quorum),
+                    ) && does_vote_count_pass(self.votes.yes, options, threshold)
+                }
+            }
         }
     }
 
@@ -132,6 +293,12 @@ impl SingleChoiceProposal {
         if self.allow_revoting && !self.expiration.is_expired(block) {
             return false;
         }
+        if self.is_revealing(block) {
+            return false;
+        }
+        if self.is_vetoed(block) {
+            return true;
+        }
 
         match self.threshold {
             Threshold::AbsolutePercentage {
@@ -234,10 +401,55 @@ impl SingleChoiceProposal {
                 let outstanding_votes = self.total_power - self.votes.total();
                 self.votes.yes + outstanding_votes < threshold
             }
+            Threshold::Optimistic {
+                objection_threshold,
+            } => does_vote_count_pass(self.votes.no, self.total_power, objection_threshold),
+            Threshold::DynamicQuorum {
+                threshold,
+                base_quorum,
+                opposed_quorum,
+            } => {
+                if self.expiration.is_expired(block) {
+                    // Consider only votes cast, rejecting the
+                    // proposal if it failed to meet the quorum its
+                    // opposition called for.
+                    let quorum =
+                        dynamic_quorum(base_quorum, opposed_quorum, self.votes.yes, self.votes.no);
+                    if !does_vote_count_pass(
+                        self.votes.total(),
+                        self.total_power,
+                        PercentageThreshold::Percent(quorum),
+                    ) {
+                        return true;
+                    }
+                    let options = self.votes.total() - self.votes.abstain;
+                    does_no_vote_count_reject(self.votes.no, options, threshold)
+                } else {
+                    let options = self.total_power - self.votes.abstain;
+                    does_no_vote_count_reject(self.votes.no, options, threshold)
+                }
+            }
         }
     }
 }
 
+/// Returns true if `no` votes out of `options` possible votes prevent
+/// `threshold` from being reached.
+fn does_no_vote_count_reject(
+    no: Uint128,
+    options: Uint128,
+    threshold: PercentageThreshold,
+) -> bool {
+    // Inverting a 100% threshold (`Decimal::one() - threshold`) gives
+    // a 0% requirement for no votes which zero no votes meet, so with
+    // a 100% threshold a single no vote rejects the proposal.
+    if threshold == PercentageThreshold::Percent(Decimal::percent(100)) {
+        options.is_zero() || !no.is_zero()
+    } else {
+        does_vote_count_fail(no, options, threshold)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -271,7 +483,14 @@ mod test {
             start_height: 100,
             expiration,
             min_voting_period: Some(min_voting_period),
+            early_execution: None,
+            reveal_deadline: None,
+            execution_deadline: None,
+            veto_threshold: None,
             allow_revoting,
+            ballot_version: 0,
+            execution_attempts: 0,
+            execution_failures: vec![],
             msgs: vec![],
             status: Status::Open,
             threshold,
@@ -328,6 +547,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -370,6 +590,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Does not pass if min voting period is not expired.
@@ -411,6 +632,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Proposal has not passed.
@@ -445,6 +667,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(4),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total votes. 7 yes and 2 abstain. Majority threshold. This
@@ -480,6 +703,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // Not expired, revoting allowed => no rejection.
@@ -517,6 +741,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -529,7 +754,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -542,7 +768,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -555,7 +782,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(11),
             false,
@@ -578,6 +806,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             false,
@@ -590,6 +819,7 @@ mod test {
                 yes: Uint128::new(10),
                 no: Uint128::zero(),
                 abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(100),
             true,
@@ -602,7 +832,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             false,
@@ -614,7 +845,8 @@ mod test {
             Votes {
                 yes: Uint128::new(9),
                 no: Uint128::new(1),
-                abstain: Uint128::zero()
+                abstain: Uint128::zero(),
+                no_with_veto: Uint128::zero(),
             },
             Uint128::new(10),
             true,
@@ -623,6 +855,95 @@ mod test {
         ));
     }
 
+    #[test]
+    fn test_optimistic_threshold() {
+        let threshold = Threshold::Optimistic {
+            objection_threshold: PercentageThreshold::Percent(Decimal::percent(30)),
+        };
+        let votes = Votes {
+            yes: Uint128::new(1),
+            no: Uint128::new(2),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        };
+
+        // Outstanding votes could still object.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(10),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(10),
+            false,
+            true,
+            false
+        ));
+        // Passes at expiration without objection.
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes,
+            Uint128::new(10),
+            true,
+            true,
+            false
+        ));
+
+        // 3 of 10 no votes is an objection.
+        let votes = Votes {
+            yes: Uint128::new(5),
+            no: Uint128::new(3),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        };
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(10),
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes,
+            Uint128::new(10),
+            true,
+            true,
+            false
+        ));
+
+        // Passes early once an objection is impossible.
+        let votes = Votes {
+            yes: Uint128::new(7),
+            no: Uint128::new(1),
+            abstain: Uint128::new(1),
+            no_with_veto: Uint128::zero(),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes.clone(),
+            Uint128::new(10),
+            false,
+            true,
+            false
+        ));
+        // Unless revoting is allowed.
+        assert!(!check_is_passed(
+            threshold,
+            votes,
+            Uint128::new(10),
+            false,
+            true,
+            true
+        ));
+    }
+
     #[test]
     fn test_tricky_pass() {
         let threshold = Threshold::AbsolutePercentage {
@@ -632,6 +953,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold,
@@ -652,6 +974,7 @@ mod test {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -680,6 +1003,7 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(6),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             threshold.clone(),
@@ -710,6 +1034,7 @@ mod test {
             yes: Uint128::new(4),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // 15 total voting power
@@ -781,18 +1106,21 @@ mod test {
             yes: Uint128::new(7),
             no: Uint128::new(3),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no + veto)
         let passes_ignoring_abstain = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(6),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(6),
             no: Uint128::new(7),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -893,18 +1221,21 @@ mod test {
             yes: Uint128::new(3),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
         // abstain votes are not counted for threshold => yes / (yes + no)
         let rejected_ignoring_abstain = Votes {
             yes: Uint128::new(4),
             no: Uint128::new(8),
             abstain: Uint128::new(5),
+            no_with_veto: Uint128::zero(),
         };
         // fails any way you look at it
         let failing = Votes {
             yes: Uint128::new(5),
             no: Uint128::new(8),
             abstain: Uint128::new(2),
+            no_with_veto: Uint128::zero(),
         };
 
         // first, expired (voting period over)
@@ -1026,6 +1357,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(1),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1049,6 +1381,7 @@ mod test {
             yes: Uint128::new(8),
             no: Uint128::new(4),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(!check_is_passed(
             quorum.clone(),
@@ -1072,6 +1405,7 @@ mod test {
             yes: Uint128::new(9),
             no: Uint128::new(3),
             abstain: Uint128::new(0),
+            no_with_veto: Uint128::zero(),
         };
         assert!(check_is_passed(
             quorum.clone(),
@@ -1107,4 +1441,242 @@ mod test {
         let now = advance_proposal_id(storage).unwrap();
         assert_eq!(now, next);
     }
+
+    #[test]
+    fn test_dynamic_quorum_threshold() {
+        let threshold = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            base_quorum: Decimal::percent(20),
+            opposed_quorum: Decimal::percent(60),
+        };
+        let votes = |yes: u128, no: u128| Votes {
+            yes: Uint128::new(yes),
+            no: Uint128::new(no),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        };
+        let total = Uint128::new(100);
+
+        // Without opposition the base quorum applies.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes(15, 0),
+            total,
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes(15, 0),
+            total,
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes(25, 0),
+            total,
+            true,
+            true,
+            false
+        ));
+
+        // Opposition raises the quorum. 20 / 45 no votes requires
+        // ~37.8% participation.
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes(25, 20),
+            total,
+            true,
+            true,
+            false
+        ));
+        // 15 / 35 no votes requires ~37.1% participation.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes(20, 15),
+            total,
+            true,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold.clone(),
+            votes(20, 15),
+            total,
+            true,
+            true,
+            false
+        ));
+
+        // Before expiration proposals must meet the largest possible
+        // quorum to pass.
+        assert!(!check_is_passed(
+            threshold.clone(),
+            votes(55, 0),
+            total,
+            false,
+            true,
+            false
+        ));
+        assert!(!check_is_rejected(
+            threshold.clone(),
+            votes(55, 0),
+            total,
+            false,
+            true,
+            false
+        ));
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes(61, 0),
+            total,
+            false,
+            true,
+            false
+        ));
+        assert!(check_is_rejected(
+            threshold,
+            votes(10, 50),
+            total,
+            false,
+            true,
+            false
+        ));
+
+        // Opposition may also lower the quorum. 14 / 35 no votes
+        // requires 28% participation.
+        let threshold = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            base_quorum: Decimal::percent(40),
+            opposed_quorum: Decimal::percent(10),
+        };
+        assert!(check_is_passed(
+            threshold.clone(),
+            votes(21, 14),
+            total,
+            true,
+            true,
+            false
+        ));
+        assert!(!check_is_passed(
+            threshold,
+            votes(35, 0),
+            total,
+            true,
+            true,
+            false
+        ));
+    }
+
+    #[test]
+    fn test_early_execution() {
+        let votes = |yes: u128| Votes {
+            yes: Uint128::new(yes),
+            no: Uint128::zero(),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
+        };
+        let threshold = Threshold::AbsolutePercentage {
+            percentage: PercentageThreshold::Majority {},
+        };
+
+        // Without early execution the min voting period prevents
+        // passing.
+        let (prop, block) = setup_prop(
+            threshold.clone(),
+            votes(90),
+            Uint128::new(100),
+            false,
+            false,
+            false,
+        );
+        assert!(!prop.is_passed(&block));
+
+        let (mut prop, block) =
+            setup_prop(threshold, votes(70), Uint128::new(100), false, false, false);
+        prop.early_execution = Some(PercentageThreshold::Percent(Decimal::percent(80)));
+        assert!(!prop.is_passed(&block));
+        prop.votes = votes(80);
+        assert!(prop.is_passed(&block));
+
+        // The proposal's threshold must still be met.
+        let (mut prop, block) = setup_prop(
+            Threshold::AbsoluteCount {
+                threshold: Uint128::new(90),
+            },
+            votes(85),
+            Uint128::new(100),
+            false,
+            false,
+            false,
+        );
+        prop.early_execution = Some(PercentageThreshold::Percent(Decimal::percent(80)));
+        assert!(!prop.is_passed(&block));
+    }
+
+    #[test]
+    fn test_veto_threshold() {
+        let votes = |yes: u128, no: u128, no_with_veto: u128| Votes {
+            yes: Uint128::new(yes),
+            no: Uint128::new(no + no_with_veto),
+            abstain: Uint128::zero(),
+            no_with_veto: Uint128::new(no_with_veto),
+        };
+        let threshold = Threshold::ThresholdQuorum {
+            threshold: PercentageThreshold::Majority {},
+            quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+        };
+        let veto_threshold = Some(PercentageThreshold::Percent(Decimal::percent(33)));
+
+        // Before expiration a majority of yes votes does not pass the
+        // proposal while outstanding votes could veto it.
+        let (mut prop, block) = setup_prop(
+            threshold.clone(),
+            votes(60, 0, 0),
+            Uint128::new(100),
+            false,
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&block));
+        prop.veto_threshold = veto_threshold;
+        assert!(!prop.is_passed(&block));
+        assert!(!prop.is_vetoed(&block));
+
+        prop.votes = votes(60, 0, 33);
+        assert!(prop.is_vetoed(&block));
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        // After expiration the veto threshold is compared with the
+        // votes cast.
+        let (mut prop, block) = setup_prop(
+            threshold.clone(),
+            votes(30, 5, 20),
+            Uint128::new(100),
+            true,
+            true,
+            false,
+        );
+        assert!(prop.is_passed(&block));
+        prop.veto_threshold = veto_threshold;
+        assert!(prop.is_vetoed(&block));
+        assert!(!prop.is_passed(&block));
+        assert!(prop.is_rejected(&block));
+
+        let (mut prop, block) = setup_prop(
+            threshold,
+            votes(30, 5, 10),
+            Uint128::new(100),
+            true,
+            true,
+            false,
+        );
+        prop.veto_threshold = veto_threshold;
+        assert!(!prop.is_vetoed(&block));
+        assert!(prop.is_passed(&block));
+    }
 }
@@ -1,11 +1,16 @@
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Uint128};
+use cosmwasm_std::{Addr, Binary, Uint128};
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
-use cw_utils::Duration;
-use dao_voting::{pre_propose::ProposalCreationPolicy, threshold::Threshold, voting::Vote};
+use cw_utils::{Duration, Expiration};
+use dao_voting::{
+    pre_propose::ProposalCreationPolicy,
+    proposal::CheckedProposalDependency,
+    threshold::{PercentageThreshold, Threshold},
+    voting::Vote,
+};
 
-use crate::proposal::SingleChoiceProposal;
+use crate::proposal::{ProposalVersion, SingleChoiceProposal};
 
 /// A vote cast for a proposal.
 #[cw_serde]
@@ -20,7 +25,34 @@ pub struct Ballot {
     /// we deserialize into None (i.e. Option::default()).
     #[serde(default)]
     pub rationale: Option<String>,
+    /// The proposal's ballot version when this vote was cast. Ballots
+    /// cast before the proposal's messages were last amended have an
+    /// older version and are ignored.
+    #[serde(default)]
+    pub version: u32,
+}
+
+/// A commitment to a vote on a proposal in commit-reveal mode.
+#[cw_serde]
+pub struct Commitment {
+    /// The hash of the committed vote.
+    pub commitment: Binary,
+    /// The proposal's ballot version when this commitment was made.
+    pub version: u32,
 }
+/// Controls how failed proposal executions are handled.
+#[cw_serde]
+pub struct RetryPolicy {
+    /// The number of times a member may retry executing a proposal
+    /// after its first execution attempt fails.
+    pub max_retries: u32,
+    /// If set to true each of a proposal's messages is executed on
+    /// its own so that one failing message does not prevent the
+    /// others from executing. Retries only re-execute the messages
+    /// that failed.
+    pub execute_individually: bool,
+}
+
 /// The governance module's configuration.
 #[cw_serde]
 pub struct Config {
@@ -55,6 +87,34 @@ pub struct Config {
     /// remain open until the DAO's treasury was large enough for it to be
     /// executed.
     pub close_proposal_on_execution_failure: bool,
+    /// If set, proposals may pass before the minimum voting period
+    /// has elapsed once this percentage of the total voting power
+    /// has voted yes. Proposals must still reach their passing
+    /// threshold.
+    pub early_execution: Option<PercentageThreshold>,
+    /// If set, passed proposals that have not been executed this
+    /// long after their voting period ends expire and may no longer
+    /// be executed. Must have the same units as `max_voting_period`.
+    pub execution_window: Option<Duration>,
+    /// If set, proposals whose execution fails record the failure
+    /// and may be executed again by members a limited number of
+    /// times. Takes precedence over
+    /// `close_proposal_on_execution_failure`.
+    pub retry_policy: Option<RetryPolicy>,
+    /// If set, proposers may amend the title, description, and
+    /// messages of their proposals for this long after creation
+    /// while voting is open. Amending a proposal's messages discards
+    /// the votes cast on it.
+    pub amendment_window: Option<Duration>,
+    /// If set, votes are cast by committing to a hash of the vote
+    /// during the voting period and revealing the vote during this
+    /// period after it. Unrevealed votes are not counted. Must have
+    /// the same units as `max_voting_period`.
+    pub reveal_period: Option<Duration>,
+    /// If set, proposals are vetoed and rejected once this
+    /// percentage of the votes cast on them are no with veto
+    /// votes. The deposits of vetoed proposals are not refunded.
+    pub veto_threshold: Option<PercentageThreshold>,
 }
 
 /// The current top level config for the module.  The "config" key was
@@ -64,6 +124,22 @@ pub const CONFIG: Item<Config> = Item::new("config_v2");
 pub const PROPOSAL_COUNT: Item<u64> = Item::new("proposal_count");
 pub const PROPOSALS: Map<u64, SingleChoiceProposal> = Map::new("proposals_v2");
 pub const BALLOTS: Map<(u64, &Addr), Ballot> = Map::new("ballots");
+/// The proposals that must be executed before a proposal may be
+/// executed. Only present for proposals with dependencies.
+pub const DEPENDENCIES: Map<u64, Vec<CheckedProposalDependency>> = Map::new("dependencies");
+/// The time after which a proposal may no longer be amended. Only
+/// present for proposals created while an amendment window was
+/// configured.
+pub const AMENDMENT_DEADLINES: Map<u64, Expiration> = Map::new("amendment_deadlines");
+/// The versions of an amended proposal's content keyed by proposal ID
+/// and version number. Only present for proposals that have been
+/// amended.
+pub const PROPOSAL_VERSIONS: Map<(u64, u32), ProposalVersion> = Map::new("proposal_versions");
+/// Commitments to votes on proposals in commit-reveal mode that have
+/// not yet been revealed.
+pub const COMMITMENTS: Map<(u64, &Addr), Commitment> = Map::new("commitments");
+/// The nonce the next signed ballot from an address must use.
+pub const BALLOT_NONCES: Map<&Addr, u64> = Map::new("ballot_nonces");
 /// Consumers of proposal state change hooks.
 pub const PROPOSAL_HOOKS: Hooks = Hooks::new("proposal_hooks");
 /// Consumers of vote hooks.
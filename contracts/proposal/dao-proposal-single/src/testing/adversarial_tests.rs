@@ -173,10 +173,17 @@ pub fn test_executed_prop_state_remains_after_vote_swing() {
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -269,10 +276,17 @@ pub fn test_passed_prop_state_remains_after_vote_swing() {
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     };
 
     let core_addr = instantiate_with_staked_balances_governance(
@@ -127,6 +127,12 @@ where
         only_members_execute: false,
         allow_revoting: false,
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
         pre_propose_info,
     };
 
@@ -193,6 +199,7 @@ where
                 title: "A simple text proposal".to_string(),
                 description: "This is a simple text proposal".to_string(),
                 msgs: vec![],
+                depends_on: None,
             },
         },
         &funds,
@@ -44,6 +44,7 @@ pub(crate) fn make_proposal(
                     denom,
                     amount,
                     refund_policy: _,
+                    slash_recipient: _,
                 }) => match denom {
                     CheckedDenom::Native(denom) => coins(amount.u128(), denom),
                     CheckedDenom::Cw20(addr) => {
@@ -78,6 +79,8 @@ pub(crate) fn make_proposal(
                     description: "description".to_string(),
                     msgs: msgs.clone(),
                     proposer: None,
+                    threshold: None,
+                    depends_on: None,
                 }),
                 &[],
             )
@@ -91,6 +94,7 @@ pub(crate) fn make_proposal(
                         title: "title".to_string(),
                         description: "description".to_string(),
                         msgs: msgs.clone(),
+                        depends_on: None,
                     },
                 },
                 &funds,
@@ -37,6 +37,11 @@ pub(crate) fn get_pre_propose_info(
             msg: to_binary(&cppbps::InstantiateMsg {
                 deposit_info,
                 open_proposal_submission,
+                submission_policy: None,
+                proposal_limits: None,
+                alternative_deposits: vec![],
+                nft_deposit: None,
+                submission_fee: None,
                 extension: Empty::default(),
             })
             .unwrap(),
@@ -62,10 +67,17 @@ pub(crate) fn get_default_token_dao_proposal_module_instantiate(app: &mut App) -
                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                 amount: Uint128::new(10_000_000),
                 refund_policy: DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             false,
         ),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
@@ -84,6 +96,12 @@ pub(crate) fn get_default_non_token_dao_proposal_module_instantiate(
         allow_revoting: false,
         pre_propose_info: get_pre_propose_info(app, None, false),
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     }
 }
 
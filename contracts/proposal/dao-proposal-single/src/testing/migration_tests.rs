@@ -303,6 +303,7 @@ fn test_v1_v2_full_migration() {
             denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
             amount: Uint128::new(1),
             refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         false,
     );
@@ -2,7 +2,7 @@ use cosmwasm_std::{
     coins,
     testing::{mock_dependencies, mock_env},
     to_binary, Addr, Attribute, BankMsg, Binary, ContractInfoResponse, CosmosMsg, Decimal, Empty,
-    Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
+    Event, Reply, StdError, SubMsgResult, Uint128, WasmMsg, WasmQuery,
 };
 use cw2::ContractVersion;
 use cw20::Cw20Coin;
@@ -11,27 +11,38 @@ use cw_hooks::{HookError, HooksResponse};
 use cw_multi_test::{next_block, App, Executor};
 use cw_utils::Duration;
 use dao_interface::{voting::InfoResponse, Admin, ModuleInstantiateInfo};
-use dao_testing::{ShouldExecute, TestSingleChoiceVote};
+use dao_testing::{
+    signed_ballots::{
+        ballot_app, instantiate_with_signers, sign_ballot, signer_address, BallotApp,
+    },
+    simulate::simulate_single_choice_proposal,
+    ShouldExecute, TestSingleChoiceVote,
+};
 use dao_voting::{
     deposit::{CheckedDepositInfo, UncheckedDepositInfo},
     pre_propose::{PreProposeInfo, ProposalCreationPolicy},
-    proposal::{SingleChoiceProposeMsg as ProposeMsg, MAX_PROPOSAL_SIZE},
+    proposal::{
+        CheckedProposalDependency, ProposalDependency, SingleChoiceProposeMsg as ProposeMsg,
+        MAX_DEPENDENCIES, MAX_PROPOSAL_SIZE,
+    },
     reply::{
         failed_pre_propose_module_hook_id, mask_proposal_execution_proposal_id,
         mask_proposal_hook_index, mask_vote_hook_index,
     },
+    signed_ballot::{SignedBallot, SignedBallotError},
     status::Status,
     threshold::{PercentageThreshold, Threshold},
-    voting::{Vote, Votes},
+    voting::{Vote, VoteReveal, Votes},
 };
 use dao_voting_cw20_staked::msg::ActiveThreshold;
+use k256::ecdsa::SigningKey;
 
 use crate::{
     contract::{migrate, CONTRACT_NAME, CONTRACT_VERSION},
     msg::{ExecuteMsg, InstantiateMsg, MigrateMsg, QueryMsg},
-    proposal::SingleChoiceProposal,
+    proposal::{ProposalVersion, SingleChoiceProposal},
     query::{ProposalResponse, VoteInfo},
-    state::Config,
+    state::{Config, RetryPolicy},
     testing::{
         contracts::{
             cw20_base_contract, cw20_stake_contract, cw20_staked_balances_voting_contract,
@@ -119,12 +130,19 @@ fn test_simple_propose_staked_balances() {
         proposer: Addr::unchecked(CREATOR_ADDR),
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
+        reveal_deadline: None,
+        execution_deadline: None,
+        veto_threshold: None,
         min_voting_period: None,
+        early_execution: None,
         threshold: Threshold::ThresholdQuorum {
             quorum: PercentageThreshold::Percent(Decimal::percent(15)),
             threshold: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        execution_attempts: 0,
+        execution_failures: vec![],
+        ballot_version: 0,
         total_power: Uint128::new(100_000_000),
         msgs: vec![],
         status: Status::Open,
@@ -144,7 +162,8 @@ fn test_simple_propose_staked_balances() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(gov_token),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None
         })
     );
 }
@@ -168,12 +187,19 @@ fn test_simple_proposal_cw4_voting() {
         proposer: Addr::unchecked(CREATOR_ADDR),
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
+        reveal_deadline: None,
+        execution_deadline: None,
+        veto_threshold: None,
         min_voting_period: None,
+        early_execution: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        execution_attempts: 0,
+        execution_failures: vec![],
+        ballot_version: 0,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
@@ -253,6 +279,7 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
             },
             amount: Uint128::new(10_000_000),
             refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
         }),
         false,
     );
@@ -272,12 +299,19 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         proposer: Addr::unchecked(CREATOR_ADDR),
         start_height: current_block.height,
         expiration: Duration::Time(604800).after(&current_block),
+        reveal_deadline: None,
+        execution_deadline: None,
+        veto_threshold: None,
         min_voting_period: None,
+        early_execution: None,
         threshold: Threshold::ThresholdQuorum {
             threshold: PercentageThreshold::Percent(Decimal::percent(15)),
             quorum: PercentageThreshold::Majority {},
         },
         allow_revoting: false,
+        execution_attempts: 0,
+        execution_failures: vec![],
+        ballot_version: 0,
         total_power: Uint128::new(1),
         msgs: vec![],
         status: Status::Open,
@@ -297,7 +331,8 @@ fn test_instantiate_with_non_voting_module_cw20_deposit() {
         Some(CheckedDepositInfo {
             denom: cw_denom::CheckedDenom::Cw20(alt_cw20),
             amount: Uint128::new(10_000_000),
-            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None
         })
     );
 }
@@ -586,6 +621,12 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                early_execution: None,
+                execution_window: None,
+                retry_policy: None,
+                amendment_window: None,
+                reveal_period: None,
+                veto_threshold: None,
             })
             .unwrap(),
             funds: vec![],
@@ -614,6 +655,12 @@ fn test_update_config() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     );
 
@@ -632,6 +679,12 @@ fn test_update_config() {
                 allow_revoting: false,
                 dao: core_addr.to_string(),
                 close_proposal_on_execution_failure: false,
+                early_execution: None,
+                execution_window: None,
+                retry_policy: None,
+                amendment_window: None,
+                reveal_period: None,
+                veto_threshold: None,
             },
             &[],
         )
@@ -703,19 +756,27 @@ fn test_anyone_may_propose_and_proposal_listing() {
                 proposer: Addr::unchecked("pppppp"),
                 start_height: current_block.height,
                 min_voting_period: None,
+                early_execution: None,
                 expiration: Duration::Time(604800).after(&current_block),
+                reveal_deadline: None,
+                execution_deadline: None,
+                veto_threshold: None,
                 threshold: Threshold::ThresholdQuorum {
                     quorum: PercentageThreshold::Percent(Decimal::percent(15)),
                     threshold: PercentageThreshold::Majority {},
                 },
                 allow_revoting: false,
+                execution_attempts: 0,
+                execution_failures: vec![],
+                ballot_version: 0,
                 total_power: Uint128::new(100_000_000),
                 msgs: vec![],
                 status: Status::Executed,
                 votes: Votes {
                     yes: Uint128::new(100_000_000),
                     no: Uint128::zero(),
-                    abstain: Uint128::zero()
+                    abstain: Uint128::zero(),
+                    no_with_veto: Uint128::zero(),
                 },
             }
         }
@@ -877,6 +938,8 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -915,6 +978,8 @@ fn test_active_threshold_absolute() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -958,6 +1023,8 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -997,6 +1064,8 @@ fn test_active_threshold_percent() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -1194,6 +1263,12 @@ fn test_allow_revoting_config_changes() {
             allow_revoting: false,
             dao: core_addr.to_string(),
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         },
         &[],
     )
@@ -1429,244 +1504,1109 @@ fn test_absolute_count_threshold_non_multisig() {
     );
 }
 
-/// Tests that we do not overflow when faced with really high token /
-/// vote supply.
-#[test]
-fn test_large_absolute_count_threshold() {
-    do_votes_staked_balances(
-        vec![
-            TestSingleChoiceVote {
-                voter: "two".to_string(),
-                position: Vote::No,
-                weight: Uint128::new(1),
-                should_execute: ShouldExecute::Yes,
-            },
-            // Can vote up to expiration time.
-            TestSingleChoiceVote {
-                voter: "one".to_string(),
-                position: Vote::Yes,
-                weight: Uint128::new(u128::MAX - 1),
-                should_execute: ShouldExecute::Yes,
-            },
-        ],
-        Threshold::AbsoluteCount {
-            threshold: Uint128::new(u128::MAX),
-        },
-        Status::Rejected,
-        None,
-    );
-
-    do_votes_staked_balances(
-        vec![
-            TestSingleChoiceVote {
-                voter: "one".to_string(),
-                position: Vote::Yes,
-                weight: Uint128::new(u128::MAX - 1),
-                should_execute: ShouldExecute::Yes,
-            },
-            TestSingleChoiceVote {
-                voter: "two".to_string(),
-                position: Vote::No,
-                weight: Uint128::new(1),
-                should_execute: ShouldExecute::Yes,
-            },
-        ],
-        Threshold::AbsoluteCount {
-            threshold: Uint128::new(u128::MAX),
-        },
-        Status::Rejected,
-        None,
-    );
-}
-
 #[test]
-fn test_proposal_count_initialized_to_zero() {
+fn test_optimistic_threshold() {
     let mut app = App::default();
-    let pre_propose_info = get_pre_propose_info(&mut app, None, false);
-    let core_addr = instantiate_with_staked_balances_governance(
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.threshold = Threshold::Optimistic {
+        objection_threshold: PercentageThreshold::Percent(Decimal::percent(30)),
+    };
+    instantiate.max_voting_period = Duration::Height(100);
+    let core_addr = instantiate_with_cw4_groups_governance(
         &mut app,
-        InstantiateMsg {
-            threshold: Threshold::ThresholdQuorum {
-                threshold: PercentageThreshold::Majority {},
-                quorum: PercentageThreshold::Percent(Decimal::percent(10)),
-            },
-            max_voting_period: Duration::Height(10),
-            min_voting_period: None,
-            only_members_execute: true,
-            allow_revoting: false,
-            pre_propose_info,
-            close_proposal_on_execution_failure: true,
-        },
+        instantiate,
         Some(vec![
             Cw20Coin {
-                address: "ekez".to_string(),
-                amount: Uint128::new(10),
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(7),
             },
             Cw20Coin {
-                address: "innactive".to_string(),
-                amount: Uint128::new(90),
+                address: "keze".to_string(),
+                amount: Uint128::new(3),
             },
         ]),
     );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
 
-    let core_state: dao_core::query::DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
-        .unwrap();
-    let proposal_modules = core_state.proposal_modules;
-
-    assert_eq!(proposal_modules.len(), 1);
-    let proposal_single = proposal_modules.into_iter().next().unwrap().address;
-
-    let proposal_count: u64 = app
-        .wrap()
-        .query_wasm_smart(proposal_single, &QueryMsg::ProposalCount {})
-        .unwrap();
-    assert_eq!(proposal_count, 0);
-}
-
-#[test]
-fn test_migrate_from_compatible() {
-    let CommonTest {
-        mut app,
-        core_addr,
-        proposal_module,
-        gov_token: _,
-        proposal_id: _,
-    } = setup_test(vec![]);
-
-    let new_code_id = app.store_code(proposal_single_contract());
-    let start_config = query_proposal_config(&app, &proposal_module);
-
-    app.execute(
-        core_addr,
-        CosmosMsg::Wasm(WasmMsg::Migrate {
-            contract_addr: proposal_module.to_string(),
-            new_code_id,
-            msg: to_binary(&MigrateMsg::FromCompatible {}).unwrap(),
-        }),
-    )
-    .unwrap();
-
-    let end_config = query_proposal_config(&app, &proposal_module);
-    assert_eq!(start_config, end_config);
-}
+    // A proposal nobody objects to passes at expiration without any
+    // votes.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+    app.update_block(|block| block.height += 100);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
 
-#[test]
-pub fn test_migrate_updates_version() {
-    let mut deps = mock_dependencies();
-    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
-    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
-    let version = cw2::get_contract_version(&deps.storage).unwrap();
-    assert_eq!(version.version, CONTRACT_VERSION);
-    assert_eq!(version.contract, CONTRACT_NAME);
+    // A proposal is rejected as soon as enough members object.
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "keze", proposal_id, Vote::No);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Rejected);
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
 }
 
-/// Instantiates a DAO with a v1 proposal module and then migrates it
-/// to v2.
 #[test]
-fn test_migrate_from_v1() {
-    use cw_proposal_single_v1 as v1;
-    use dao_pre_propose_single as cppbps;
-
+fn test_proposal_dependencies() {
     let mut app = App::default();
-    let v1_proposal_single_code = app.store_code(v1_proposal_single_contract());
-
-    let instantiate = v1::msg::InstantiateMsg {
-        threshold: voting_v1::Threshold::AbsolutePercentage {
-            percentage: voting_v1::PercentageThreshold::Majority {},
-        },
-        max_voting_period: cw_utils_v1::Duration::Height(6),
-        min_voting_period: None,
-        only_members_execute: false,
-        allow_revoting: false,
-        deposit_info: Some(v1::msg::DepositInfo {
-            token: v1::msg::DepositToken::VotingModuleToken {},
-            deposit: Uint128::new(1),
-            refund_failed_proposals: true,
-        }),
-    };
-
-    let initial_balances = vec![Cw20Coin {
-        amount: Uint128::new(100),
-        address: CREATOR_ADDR.to_string(),
-    }];
-
-    let cw20_id = app.store_code(cw20_base_contract());
-    let cw20_stake_id = app.store_code(cw20_stake_contract());
-    let staked_balances_voting_id = app.store_code(cw20_staked_balances_voting_contract());
-    let core_contract_id = app.store_code(cw_core_contract());
-
-    let instantiate_core = dao_core::msg::InstantiateMsg {
-        admin: None,
-        name: "DAO DAO".to_string(),
-        description: "A DAO that builds DAOs".to_string(),
-        image_url: None,
-        dao_uri: None,
-        automatically_add_cw20s: true,
-        automatically_add_cw721s: false,
-        voting_module_instantiate_info: ModuleInstantiateInfo {
-            code_id: staked_balances_voting_id,
-            msg: to_binary(&dao_voting_cw20_staked::msg::InstantiateMsg {
-                active_threshold: None,
-                token_info: dao_voting_cw20_staked::msg::TokenInfo::New {
-                    code_id: cw20_id,
-                    label: "DAO DAO governance token.".to_string(),
-                    name: "DAO DAO".to_string(),
-                    symbol: "DAO".to_string(),
-                    decimals: 6,
-                    initial_balances: initial_balances.clone(),
-                    marketing: None,
-                    staking_code_id: cw20_stake_id,
-                    unstaking_duration: Some(Duration::Height(6)),
-                    initial_dao_balance: None,
-                },
-            })
-            .unwrap(),
-            admin: None,
-            label: "DAO DAO voting module".to_string(),
-        },
-        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
-            code_id: v1_proposal_single_code,
-            label: "DAO DAO governance module.".to_string(),
-            admin: Some(Admin::CoreModule {}),
-            msg: to_binary(&instantiate).unwrap(),
-        }],
-        initial_items: None,
-    };
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
 
-    let core_addr = app
-        .instantiate_contract(
-            core_contract_id,
+    let propose = |app: &mut App, depends_on: Vec<u64>| {
+        app.execute_contract(
             Addr::unchecked(CREATOR_ADDR),
-            &instantiate_core,
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                threshold: None,
+                depends_on: Some(
+                    depends_on
+                        .into_iter()
+                        .map(|proposal_id| ProposalDependency {
+                            proposal_module: proposal_module.to_string(),
+                            proposal_id,
+                        })
+                        .collect(),
+                ),
+            }),
             &[],
-            "DAO DAO",
-            None,
         )
-        .unwrap();
+    };
 
-    let core_state: dao_core::query::DumpStateResponse = app
-        .wrap()
-        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
-        .unwrap();
-    let voting_module = core_state.voting_module;
+    // Dependencies must exist.
+    let err: ContractError = propose(&mut app, vec![1]).unwrap_err().downcast().unwrap();
+    assert!(matches!(err, ContractError::Std(_)));
 
-    let staking_contract: Addr = app
+    let first = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    propose(&mut app, vec![first]).unwrap();
+    let second = query_next_proposal_id(&app, &proposal_module) - 1;
+
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, second, Vote::Yes);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, first, Vote::Yes);
+
+    // The second proposal may not be executed before the first.
+    let blocking: Vec<CheckedProposalDependency> = app
         .wrap()
         .query_wasm_smart(
-            voting_module.clone(),
-            &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+            &proposal_module,
+            &QueryMsg::BlockingDependencies {
+                proposal_id: second,
+            },
         )
         .unwrap();
-    let token_contract: Addr = app
+    assert_eq!(
+        blocking,
+        vec![CheckedProposalDependency {
+            proposal_module: proposal_module.clone(),
+            proposal_id: first,
+        }]
+    );
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, second);
+    assert!(matches!(
+        err,
+        ContractError::DependencyNotExecuted { proposal_id, .. } if proposal_id == first
+    ));
+
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, first);
+    let blocking: Vec<CheckedProposalDependency> = app
         .wrap()
         .query_wasm_smart(
-            voting_module,
-            &dao_interface::voting::Query::TokenContract {},
+            &proposal_module,
+            &QueryMsg::BlockingDependencies {
+                proposal_id: second,
+            },
         )
         .unwrap();
-
+    assert!(blocking.is_empty());
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, second);
+
+    // Proposals may not depend on proposals that can never be
+    // executed.
+    let rejected = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, CREATOR_ADDR, rejected, Vote::No);
+    let err: ContractError = propose(&mut app, vec![rejected])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::UnexecutableDependency { proposal_id, .. } if proposal_id == rejected
+    ));
+
+    let err: ContractError = propose(&mut app, vec![first; MAX_DEPENDENCIES + 1])
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::TooManyDependencies { .. }));
+}
+
+#[test]
+fn test_execution_window() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.max_voting_period = Duration::Height(10);
+    instantiate.execution_window = Some(Duration::Height(5));
+    instantiate.pre_propose_info = get_pre_propose_info(
+        &mut app,
+        Some(UncheckedDepositInfo {
+            denom: dao_voting::deposit::DepositToken::Token {
+                denom: cw_denom::UncheckedDenom::Native("ujuno".to_string()),
+            },
+            amount: Uint128::new(10),
+            refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+            slash_recipient: None,
+        }),
+        false,
+    );
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    mint_natives(&mut app, CREATOR_ADDR, coins(10, "ujuno"));
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+
+    // Passed proposals may be executed until their voting period
+    // has ended plus the execution window.
+    app.update_block(|block| block.height += 14);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Passed);
+
+    app.update_block(next_block);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Expired);
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::ExecutionExpired {}));
+
+    // Closing an expired proposal refunds its deposit as the
+    // proposal passed.
+    close_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Expired);
+    let balance = query_balance_native(&app, CREATOR_ADDR, "ujuno");
+    assert_eq!(balance, Uint128::new(10));
+    assert!(matches!(
+        query_creation_policy(&app, &proposal_module),
+        ProposalCreationPolicy::Module { .. }
+    ));
+
+    let err = close_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::WrongCloseStatus {}));
+
+    // Execution windows must have the same units as the voting
+    // period.
+    let err: ContractError = app
+        .execute_contract(
+            core_addr.clone(),
+            proposal_module,
+            &ExecuteMsg::UpdateConfig {
+                threshold: Threshold::AbsoluteCount {
+                    threshold: Uint128::new(1),
+                },
+                max_voting_period: Duration::Height(10),
+                min_voting_period: None,
+                only_members_execute: true,
+                allow_revoting: false,
+                dao: core_addr.to_string(),
+                close_proposal_on_execution_failure: false,
+                early_execution: None,
+                execution_window: Some(Duration::Time(10)),
+                retry_policy: None,
+                amendment_window: None,
+                reveal_period: None,
+                veto_threshold: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidExecutionWindow {}));
+}
+
+#[test]
+fn test_execution_retries() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.only_members_execute = false;
+    instantiate.retry_policy = Some(RetryPolicy {
+        max_retries: 1,
+        execute_individually: true,
+    });
+    let core_addr = instantiate_with_cw4_groups_governance(&mut app, instantiate, None);
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    // The first message can not be executed as the DAO does not
+    // have enough funds. The second one can.
+    mint_natives(&mut app, core_addr.as_str(), coins(5, "ujuno"));
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![
+            BankMsg::Send {
+                to_address: "ekez".to_string(),
+                amount: coins(10, "ujuno"),
+            }
+            .into(),
+            BankMsg::Send {
+                to_address: "keze".to_string(),
+                amount: coins(5, "ujuno"),
+            }
+            .into(),
+        ],
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, "anyone", proposal_id);
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::ExecutionFailed);
+    assert_eq!(proposal.execution_attempts, 1);
+    assert_eq!(proposal.execution_failures.len(), 1);
+    assert_eq!(proposal.execution_failures[0].msg_index, Some(0));
+    assert!(!proposal.execution_failures[0].error.is_empty());
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(query_balance_native(&app, "keze", "ujuno"), Uint128::new(5));
+
+    // Only members may retry execution.
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, "anyone", proposal_id);
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Retrying only executes the failed message.
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Executed);
+    assert_eq!(proposal.execution_attempts, 2);
+    assert!(proposal.execution_failures.is_empty());
+    assert_eq!(
+        query_balance_native(&app, "ekez", "ujuno"),
+        Uint128::new(10)
+    );
+    assert_eq!(query_balance_native(&app, "keze", "ujuno"), Uint128::new(5));
+
+    // Retries are bounded.
+    let proposal_id = make_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        vec![BankMsg::Send {
+            to_address: "ekez".to_string(),
+            amount: coins(10, "ujuno"),
+        }
+        .into()],
+    );
+    vote_on_proposal(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    execute_proposal(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::ExecutionFailed);
+    assert_eq!(proposal.execution_attempts, 2);
+
+    let err = execute_proposal_should_fail(&mut app, &proposal_module, CREATOR_ADDR, proposal_id);
+    assert!(matches!(err, ContractError::RetriesExhausted { max: 1 }));
+}
+
+#[test]
+fn test_amendments() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.amendment_window = Some(Duration::Height(5));
+    instantiate.pre_propose_info = PreProposeInfo::AnyoneMayPropose {};
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+
+    // Only the proposer may amend a proposal.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: Some("Typo".to_string()),
+                description: None,
+                msgs: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Unauthorized {}));
+
+    // Amending the title does not change votes.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: Some("Fixed title".to_string()),
+            description: None,
+            msgs: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "Fixed title");
+    assert_eq!(proposal.votes.yes, Uint128::new(1));
+    assert!(query_vote(&app, &proposal_module, "ekez", proposal_id)
+        .vote
+        .is_some());
+
+    // Amending the messages discards existing votes.
+    let msgs: Vec<CosmosMsg> = vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()];
+    let res = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: None,
+                description: None,
+                msgs: Some(msgs.clone()),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(res.has_event(
+        &Event::new("wasm-votes_reset")
+            .add_attribute("proposal_id", proposal_id.to_string())
+            .add_attribute("ballot_version", "1")
+    ));
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.msgs, msgs);
+    assert_eq!(proposal.votes, Votes::zero());
+    assert_eq!(proposal.ballot_version, 1);
+    assert!(query_vote(&app, &proposal_module, "ekez", proposal_id)
+        .vote
+        .is_none());
+    assert!(
+        query_list_votes(&app, &proposal_module, proposal_id, None, None)
+            .votes
+            .is_empty()
+    );
+
+    // Voting again counts the new ballot once, even though the
+    // ballot cast on the previous messages is still stored.
+    vote_on_proposal(&mut app, &proposal_module, "ekez", proposal_id, Vote::Yes);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.votes.yes, Uint128::new(1));
+    let votes = query_list_votes(&app, &proposal_module, proposal_id, None, None).votes;
+    assert_eq!(votes.len(), 1);
+    assert_eq!(votes[0].voter, Addr::unchecked("ekez"));
+
+    let versions: Vec<ProposalVersion> = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::ProposalVersions { proposal_id },
+        )
+        .unwrap();
+    assert_eq!(versions.len(), 3);
+    assert_eq!(versions[0].version, 0);
+    assert_eq!(versions[0].title, "title");
+    assert!(versions[0].msgs.is_empty());
+    assert_eq!(versions[1].title, "Fixed title");
+    assert!(versions[1].msgs.is_empty());
+    assert_eq!(versions[2].version, 2);
+    assert_eq!(versions[2].msgs, msgs);
+
+    // Proposals may not be amended once the amendment window ends.
+    app.update_block(|block| block.height += 5);
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: Some("Too late".to_string()),
+                description: None,
+                msgs: None,
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotAmendable {}));
+
+    // Amendment windows must be non-zero.
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.amendment_window = Some(Duration::Height(0));
+    let proposal_single_id = app.store_code(proposal_single_contract());
+    let err: ContractError = app
+        .instantiate_contract(
+            proposal_single_id,
+            core_addr,
+            &instantiate,
+            &[],
+            "proposal module",
+            None,
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidAmendmentWindow {}));
+}
+
+#[test]
+fn test_amend_pre_propose_messages() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.amendment_window = Some(Duration::Height(5));
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![Cw20Coin {
+            address: CREATOR_ADDR.to_string(),
+            amount: Uint128::new(1),
+        }]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    // Messages checked by the pre-propose module may not be
+    // replaced.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &ExecuteMsg::Amend {
+                proposal_id,
+                title: None,
+                description: None,
+                msgs: Some(vec![BankMsg::Send {
+                    to_address: CREATOR_ADDR.to_string(),
+                    amount: coins(10, "ujuno"),
+                }
+                .into()]),
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::MessagesNotAmendable {}));
+
+    // The title and description may still be amended.
+    app.execute_contract(
+        Addr::unchecked(CREATOR_ADDR),
+        proposal_module.clone(),
+        &ExecuteMsg::Amend {
+            proposal_id,
+            title: Some("Fixed title".to_string()),
+            description: None,
+            msgs: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.title, "Fixed title");
+    assert!(proposal.msgs.is_empty());
+}
+
+#[test]
+fn test_vote_signed_invalid_ballot() {
+    let CommonTest {
+        mut app,
+        core_addr: _,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![]);
+
+    let nonce: u64 = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::BallotNonce {
+                address: CREATOR_ADDR.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(nonce, 0);
+
+    // Uncompressed public keys are not supported.
+    let err: ContractError = app
+        .execute_contract(
+            Addr::unchecked("relayer"),
+            proposal_module,
+            &ExecuteMsg::VoteSigned {
+                proposal_id,
+                ballots: vec![SignedBallot {
+                    vote: Vote::Yes,
+                    nonce: 0,
+                    pubkey: Binary::from(&[4; 65][..]),
+                    signature: Binary::from(&[1; 64][..]),
+                }],
+            },
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::InvalidPubkey {})
+    ));
+}
+
+#[test]
+fn test_vote_signed() {
+    let mut app = ballot_app();
+    let key = SigningKey::from_bytes(&[7; 32]).unwrap();
+    let voter = signer_address(&key);
+
+    let instantiate = InstantiateMsg {
+        threshold: Threshold::AbsoluteCount {
+            threshold: Uint128::new(2),
+        },
+        max_voting_period: Duration::Time(604800),
+        min_voting_period: None,
+        only_members_execute: true,
+        allow_revoting: false,
+        pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
+        close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
+    };
+    let proposal_module = instantiate_with_signers(
+        &mut app,
+        proposal_single_contract(),
+        to_binary(&instantiate).unwrap(),
+        vec![
+            cw4::Member {
+                addr: CREATOR_ADDR.to_string(),
+                weight: 1,
+            },
+            cw4::Member {
+                addr: voter.to_string(),
+                weight: 1,
+            },
+        ],
+    );
+
+    let propose = ExecuteMsg::Propose(ProposeMsg {
+        title: "title".to_string(),
+        description: "description".to_string(),
+        msgs: vec![],
+        proposer: None,
+        threshold: None,
+        depends_on: None,
+    });
+    for _ in 0..2 {
+        app.execute_contract(
+            Addr::unchecked(CREATOR_ADDR),
+            proposal_module.clone(),
+            &propose,
+            &[],
+        )
+        .unwrap();
+    }
+    let chain_id = app.block_info().chain_id;
+    let query_nonce = |app: &BallotApp| -> u64 {
+        app.wrap()
+            .query_wasm_smart(
+                proposal_module.clone(),
+                &QueryMsg::BallotNonce {
+                    address: voter.to_string(),
+                },
+            )
+            .unwrap()
+    };
+    let vote_signed = |app: &mut BallotApp, proposal_id: u64, ballot: SignedBallot<Vote>| {
+        app.execute_contract(
+            Addr::unchecked("relayer"),
+            proposal_module.clone(),
+            &ExecuteMsg::VoteSigned {
+                proposal_id,
+                ballots: vec![ballot],
+            },
+            &[],
+        )
+    };
+
+    // A signed ballot is counted for its signer.
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 1, Vote::Yes, 0);
+    vote_signed(&mut app, 1, ballot.clone()).unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(
+            proposal_module.clone(),
+            &QueryMsg::Proposal { proposal_id: 1 },
+        )
+        .unwrap();
+    assert_eq!(proposal.proposal.votes.yes, Uint128::new(1));
+    assert_eq!(proposal.proposal.status, Status::Open);
+    assert_eq!(query_nonce(&app), 1);
+
+    // Ballots may not be replayed.
+    let err: ContractError = vote_signed(&mut app, 1, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::WrongNonce {
+            expected: 1,
+            actual: 0
+        })
+    ));
+
+    // Ballots are only valid for the proposal and module they were
+    // signed for.
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 1, Vote::No, 1);
+    let err: ContractError = vote_signed(&mut app, 2, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::InvalidSignature {})
+    ));
+    let ballot = sign_ballot(&key, &chain_id, &Addr::unchecked("other"), 2, Vote::No, 1);
+    let err: ContractError = vote_signed(&mut app, 2, ballot)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::SignedBallotError(SignedBallotError::InvalidSignature {})
+    ));
+    assert_eq!(query_nonce(&app), 1);
+
+    let ballot = sign_ballot(&key, &chain_id, &proposal_module, 2, Vote::No, 1);
+    vote_signed(&mut app, 2, ballot).unwrap();
+    let proposal: ProposalResponse = app
+        .wrap()
+        .query_wasm_smart(&proposal_module, &QueryMsg::Proposal { proposal_id: 2 })
+        .unwrap();
+    assert_eq!(proposal.proposal.votes.no, Uint128::new(1));
+    assert_eq!(query_nonce(&app), 2);
+}
+
+#[test]
+fn test_commit_reveal() {
+    let mut app = App::default();
+    let mut instantiate = get_default_non_token_dao_proposal_module_instantiate(&mut app);
+    instantiate.max_voting_period = Duration::Height(5);
+    instantiate.reveal_period = Some(Duration::Height(5));
+    let core_addr = instantiate_with_cw4_groups_governance(
+        &mut app,
+        instantiate,
+        Some(vec![
+            Cw20Coin {
+                address: CREATOR_ADDR.to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(1),
+            },
+            Cw20Coin {
+                address: "keze".to_string(),
+                amount: Uint128::new(1),
+            },
+        ]),
+    );
+    let proposal_module = query_single_proposal_module(&app, &core_addr);
+    let proposal_id = make_proposal(&mut app, &proposal_module, CREATOR_ADDR, vec![]);
+
+    let err = vote_on_proposal_should_fail(
+        &mut app,
+        &proposal_module,
+        CREATOR_ADDR,
+        proposal_id,
+        Vote::Yes,
+    );
+    assert!(matches!(err, ContractError::CommitRevealRequired {}));
+
+    let commitment = |voter: &str, vote: Vote, salt: &str| {
+        VoteReveal {
+            proposal_module: proposal_module.to_string(),
+            proposal_id,
+            voter: voter.to_string(),
+            vote,
+            salt: salt.to_string(),
+        }
+        .commitment()
+        .unwrap()
+    };
+    let commit_as = |app: &mut App, sender: &str, voter: &str, vote: Vote, salt: &str| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            proposal_module.clone(),
+            &ExecuteMsg::CommitVote {
+                proposal_id,
+                commitment: commitment(voter, vote, salt),
+            },
+            &[],
+        )
+    };
+    let commit = |app: &mut App, voter: &str, vote: Vote, salt: &str| {
+        commit_as(app, voter, voter, vote, salt)
+    };
+    let reveal = |app: &mut App, voter: &str, vote: Vote, salt: &str| {
+        app.execute_contract(
+            Addr::unchecked(voter),
+            proposal_module.clone(),
+            &ExecuteMsg::RevealVote {
+                proposal_id,
+                vote,
+                salt: salt.to_string(),
+            },
+            &[],
+        )
+    };
+
+    commit(&mut app, CREATOR_ADDR, Vote::Yes, "a").unwrap();
+    commit(&mut app, "ekez", Vote::Yes, "b").unwrap();
+    // Copying another voter's commitment does not allow copying
+    // their vote.
+    commit_as(&mut app, "keze", "ekez", Vote::Yes, "b").unwrap();
+
+    // Votes are hidden and the proposal may not pass early.
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(proposal.votes, Votes::zero());
+    let err: ContractError = reveal(&mut app, CREATOR_ADDR, Vote::Yes, "a")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotRevealPeriod {}));
+
+    // Votes are revealed once voting ends.
+    app.update_block(|block| block.height += 5);
+    let err: ContractError = commit(&mut app, CREATOR_ADDR, Vote::No, "d")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::Expired { .. }));
+    let err: ContractError = reveal(&mut app, CREATOR_ADDR, Vote::Yes, "wrong")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidReveal {}));
+    reveal(&mut app, CREATOR_ADDR, Vote::Yes, "a").unwrap();
+    reveal(&mut app, "ekez", Vote::Yes, "b").unwrap();
+    let err: ContractError = reveal(&mut app, "keze", Vote::Yes, "b")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::InvalidReveal {}));
+
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Open);
+    assert_eq!(proposal.votes.yes, Uint128::new(2));
+
+    // Unrevealed votes are not counted.
+    app.update_block(|block| block.height += 5);
+    let proposal = query_proposal(&app, &proposal_module, proposal_id).proposal;
+    assert_eq!(proposal.status, Status::Passed);
+    assert_eq!(proposal.votes.no, Uint128::zero());
+    let err: ContractError = reveal(&mut app, "keze", Vote::No, "c")
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, ContractError::NotRevealPeriod {}));
+}
+
+/// Tests that we do not overflow when faced with really high token /
+/// vote supply.
+#[test]
+fn test_large_absolute_count_threshold() {
+    do_votes_staked_balances(
+        vec![
+            TestSingleChoiceVote {
+                voter: "two".to_string(),
+                position: Vote::No,
+                weight: Uint128::new(1),
+                should_execute: ShouldExecute::Yes,
+            },
+            // Can vote up to expiration time.
+            TestSingleChoiceVote {
+                voter: "one".to_string(),
+                position: Vote::Yes,
+                weight: Uint128::new(u128::MAX - 1),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        Threshold::AbsoluteCount {
+            threshold: Uint128::new(u128::MAX),
+        },
+        Status::Rejected,
+        None,
+    );
+
+    do_votes_staked_balances(
+        vec![
+            TestSingleChoiceVote {
+                voter: "one".to_string(),
+                position: Vote::Yes,
+                weight: Uint128::new(u128::MAX - 1),
+                should_execute: ShouldExecute::Yes,
+            },
+            TestSingleChoiceVote {
+                voter: "two".to_string(),
+                position: Vote::No,
+                weight: Uint128::new(1),
+                should_execute: ShouldExecute::Yes,
+            },
+        ],
+        Threshold::AbsoluteCount {
+            threshold: Uint128::new(u128::MAX),
+        },
+        Status::Rejected,
+        None,
+    );
+}
+
+#[test]
+fn test_proposal_count_initialized_to_zero() {
+    let mut app = App::default();
+    let pre_propose_info = get_pre_propose_info(&mut app, None, false);
+    let core_addr = instantiate_with_staked_balances_governance(
+        &mut app,
+        InstantiateMsg {
+            threshold: Threshold::ThresholdQuorum {
+                threshold: PercentageThreshold::Majority {},
+                quorum: PercentageThreshold::Percent(Decimal::percent(10)),
+            },
+            max_voting_period: Duration::Height(10),
+            min_voting_period: None,
+            only_members_execute: true,
+            allow_revoting: false,
+            pre_propose_info,
+            close_proposal_on_execution_failure: true,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
+        },
+        Some(vec![
+            Cw20Coin {
+                address: "ekez".to_string(),
+                amount: Uint128::new(10),
+            },
+            Cw20Coin {
+                address: "innactive".to_string(),
+                amount: Uint128::new(90),
+            },
+        ]),
+    );
+
+    let core_state: dao_core::query::DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr, &dao_core::msg::QueryMsg::DumpState {})
+        .unwrap();
+    let proposal_modules = core_state.proposal_modules;
+
+    assert_eq!(proposal_modules.len(), 1);
+    let proposal_single = proposal_modules.into_iter().next().unwrap().address;
+
+    let proposal_count: u64 = app
+        .wrap()
+        .query_wasm_smart(proposal_single, &QueryMsg::ProposalCount {})
+        .unwrap();
+    assert_eq!(proposal_count, 0);
+}
+
+#[test]
+fn test_migrate_from_compatible() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id: _,
+    } = setup_test(vec![]);
+
+    let new_code_id = app.store_code(proposal_single_contract());
+    let start_config = query_proposal_config(&app, &proposal_module);
+
+    app.execute(
+        core_addr,
+        CosmosMsg::Wasm(WasmMsg::Migrate {
+            contract_addr: proposal_module.to_string(),
+            new_code_id,
+            msg: to_binary(&MigrateMsg::FromCompatible {}).unwrap(),
+        }),
+    )
+    .unwrap();
+
+    let end_config = query_proposal_config(&app, &proposal_module);
+    assert_eq!(start_config, end_config);
+}
+
+#[test]
+pub fn test_migrate_updates_version() {
+    let mut deps = mock_dependencies();
+    cw2::set_contract_version(&mut deps.storage, "my-contract", "old-version").unwrap();
+    migrate(deps.as_mut(), mock_env(), MigrateMsg::FromCompatible {}).unwrap();
+    let version = cw2::get_contract_version(&deps.storage).unwrap();
+    assert_eq!(version.version, CONTRACT_VERSION);
+    assert_eq!(version.contract, CONTRACT_NAME);
+}
+
+/// Instantiates a DAO with a v1 proposal module and then migrates it
+/// to v2.
+#[test]
+fn test_migrate_from_v1() {
+    use cw_proposal_single_v1 as v1;
+    use dao_pre_propose_single as cppbps;
+
+    let mut app = App::default();
+    let v1_proposal_single_code = app.store_code(v1_proposal_single_contract());
+
+    let instantiate = v1::msg::InstantiateMsg {
+        threshold: voting_v1::Threshold::AbsolutePercentage {
+            percentage: voting_v1::PercentageThreshold::Majority {},
+        },
+        max_voting_period: cw_utils_v1::Duration::Height(6),
+        min_voting_period: None,
+        only_members_execute: false,
+        allow_revoting: false,
+        deposit_info: Some(v1::msg::DepositInfo {
+            token: v1::msg::DepositToken::VotingModuleToken {},
+            deposit: Uint128::new(1),
+            refund_failed_proposals: true,
+        }),
+    };
+
+    let initial_balances = vec![Cw20Coin {
+        amount: Uint128::new(100),
+        address: CREATOR_ADDR.to_string(),
+    }];
+
+    let cw20_id = app.store_code(cw20_base_contract());
+    let cw20_stake_id = app.store_code(cw20_stake_contract());
+    let staked_balances_voting_id = app.store_code(cw20_staked_balances_voting_contract());
+    let core_contract_id = app.store_code(cw_core_contract());
+
+    let instantiate_core = dao_core::msg::InstantiateMsg {
+        admin: None,
+        name: "DAO DAO".to_string(),
+        description: "A DAO that builds DAOs".to_string(),
+        image_url: None,
+        dao_uri: None,
+        automatically_add_cw20s: true,
+        automatically_add_cw721s: false,
+        voting_module_instantiate_info: ModuleInstantiateInfo {
+            code_id: staked_balances_voting_id,
+            msg: to_binary(&dao_voting_cw20_staked::msg::InstantiateMsg {
+                active_threshold: None,
+                token_info: dao_voting_cw20_staked::msg::TokenInfo::New {
+                    code_id: cw20_id,
+                    label: "DAO DAO governance token.".to_string(),
+                    name: "DAO DAO".to_string(),
+                    symbol: "DAO".to_string(),
+                    decimals: 6,
+                    initial_balances: initial_balances.clone(),
+                    marketing: None,
+                    staking_code_id: cw20_stake_id,
+                    unstaking_duration: Some(Duration::Height(6)),
+                    initial_dao_balance: None,
+                },
+            })
+            .unwrap(),
+            admin: None,
+            label: "DAO DAO voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+            code_id: v1_proposal_single_code,
+            label: "DAO DAO governance module.".to_string(),
+            admin: Some(Admin::CoreModule {}),
+            msg: to_binary(&instantiate).unwrap(),
+        }],
+        initial_items: None,
+    };
+
+    let core_addr = app
+        .instantiate_contract(
+            core_contract_id,
+            Addr::unchecked(CREATOR_ADDR),
+            &instantiate_core,
+            &[],
+            "DAO DAO",
+            None,
+        )
+        .unwrap();
+
+    let core_state: dao_core::query::DumpStateResponse = app
+        .wrap()
+        .query_wasm_smart(core_addr.clone(), &dao_core::msg::QueryMsg::DumpState {})
+        .unwrap();
+    let voting_module = core_state.voting_module;
+
+    let staking_contract: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module.clone(),
+            &dao_voting_cw20_staked::msg::QueryMsg::StakingContract {},
+        )
+        .unwrap();
+    let token_contract: Addr = app
+        .wrap()
+        .query_wasm_smart(
+            voting_module,
+            &dao_interface::voting::Query::TokenContract {},
+        )
+        .unwrap();
+
     // Stake all the initial balances.
     for Cw20Coin { address, amount } in initial_balances {
         app.execute_contract(
@@ -1728,8 +2668,14 @@ fn test_migrate_from_v1() {
                         denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                         amount: Uint128::new(1),
                         refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                        slash_recipient: None,
                     }),
                     open_proposal_submission: false,
+                    submission_policy: None,
+                    proposal_limits: None,
+                    alternative_deposits: vec![],
+                    nft_deposit: None,
+                    submission_fee: None,
                     extension: Empty::default(),
                 })
                 .unwrap(),
@@ -1780,6 +2726,12 @@ fn test_migrate_from_v1() {
             allow_revoting: false,
             dao: core_addr.clone(),
             close_proposal_on_execution_failure: true,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         }
     );
 
@@ -1823,10 +2775,16 @@ fn test_migrate_from_v1() {
         pre_propose_config,
         cppbps::Config {
             open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
             deposit_info: Some(CheckedDepositInfo {
                 denom: CheckedDenom::Cw20(token_contract.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             })
         }
     );
@@ -1913,6 +2871,12 @@ fn test_execution_failed() {
             dao: config.dao.into_string(),
             // Disable.
             close_proposal_on_execution_failure: false,
+            early_execution: None,
+            execution_window: None,
+            retry_policy: None,
+            amendment_window: None,
+            reveal_period: None,
+            veto_threshold: None,
         },
         &[],
     )
@@ -1949,6 +2913,40 @@ fn test_execution_failed() {
     assert_eq!(balance, Uint128::zero());
 }
 
+#[test]
+fn test_simulate_execution() {
+    let CommonTest {
+        mut app,
+        core_addr,
+        proposal_module,
+        gov_token: _,
+        proposal_id,
+    } = setup_test(vec![BankMsg::Send {
+        to_address: "ekez".to_string(),
+        amount: coins(10, "ujuno"),
+    }
+    .into()]);
+
+    // The DAO has no funds so execution would fail.
+    let err: StdError = simulate_single_choice_proposal(&mut app, &proposal_module, proposal_id)
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(err, StdError::Overflow { .. }));
+
+    mint_natives(&mut app, core_addr.as_str(), coins(10, "ujuno"));
+    simulate_single_choice_proposal(&mut app, &proposal_module, proposal_id).unwrap();
+
+    // Simulation does not change state.
+    assert_eq!(query_balance_native(&app, "ekez", "ujuno"), Uint128::zero());
+    assert_eq!(
+        query_balance_native(&app, core_addr.as_str(), "ujuno"),
+        Uint128::new(10)
+    );
+    let proposal = query_proposal(&app, &proposal_module, proposal_id);
+    assert_eq!(proposal.proposal.status, Status::Open);
+}
+
 #[test]
 fn test_reply_proposal_mock() {
     use crate::contract::reply;
@@ -1968,11 +2966,18 @@ fn test_reply_proposal_mock() {
                 proposer: Addr::unchecked(CREATOR_ADDR),
                 start_height: env.block.height,
                 expiration: cw_utils::Duration::Height(6).after(&env.block),
+                reveal_deadline: None,
+                execution_deadline: None,
+                veto_threshold: None,
                 min_voting_period: None,
+                early_execution: None,
                 threshold: Threshold::AbsolutePercentage {
                     percentage: PercentageThreshold::Majority {},
                 },
                 allow_revoting: false,
+                execution_attempts: 0,
+                execution_failures: vec![],
+                ballot_version: 0,
                 total_power: Uint128::new(100_000_000),
                 msgs: vec![],
                 status: Status::Open,
@@ -2016,6 +3021,8 @@ fn test_proposal_too_large() {
                 description: "a".repeat(MAX_PROPOSAL_SIZE as usize),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -2067,6 +3074,8 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -2092,6 +3101,8 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: None,
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -2122,6 +3133,8 @@ fn test_proposal_creation_permissions() {
                 description: "description".to_string(),
                 msgs: vec![],
                 proposer: Some("ekez".to_string()),
+                threshold: None,
+                depends_on: None,
             }),
             &[],
         )
@@ -2130,6 +3143,31 @@ fn test_proposal_creation_permissions() {
         .unwrap();
     assert!(matches!(err, ContractError::InvalidProposer {}));
 
+    // Only pre-propose modules may override the threshold.
+    let err = app
+        .execute_contract(
+            Addr::unchecked("ekez"),
+            proposal_module.clone(),
+            &ExecuteMsg::Propose(ProposeMsg {
+                title: "title".to_string(),
+                description: "description".to_string(),
+                msgs: vec![],
+                proposer: None,
+                threshold: Some(Threshold::AbsoluteCount {
+                    threshold: Uint128::new(1),
+                }),
+                depends_on: None,
+            }),
+            &[],
+        )
+        .unwrap_err()
+        .downcast()
+        .unwrap();
+    assert!(matches!(
+        err,
+        ContractError::ThresholdOverrideNotPermitted {}
+    ));
+
     // Works normally.
     let proposal_id = make_proposal(&mut app, &proposal_module, "ekez", vec![]);
     let proposal = query_proposal(&app, &proposal_module, proposal_id);
@@ -2398,8 +3436,14 @@ fn test_update_pre_propose_module() {
                                 denom: dao_voting::deposit::DepositToken::VotingModuleToken {},
                                 amount: Uint128::new(1),
                                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                                slash_recipient: None,
                             }),
                             open_proposal_submission: false,
+                            submission_policy: None,
+                            proposal_limits: None,
+                            alternative_deposits: vec![],
+                            nft_deposit: None,
+                            submission_fee: None,
                             extension: Empty::default(),
                         })
                         .unwrap(),
@@ -2447,8 +3491,14 @@ fn test_update_pre_propose_module() {
                 denom: CheckedDenom::Cw20(gov_token.clone()),
                 amount: Uint128::new(1),
                 refund_policy: dao_voting::deposit::DepositRefundPolicy::OnlyPassed,
+                slash_recipient: None,
             }),
             open_proposal_submission: false,
+            submission_policy: None,
+            proposal_limits: None,
+            alternative_deposits: vec![],
+            nft_deposit: None,
+            submission_fee: None,
         }
     );
 
@@ -0,0 +1,750 @@
+use cosmwasm_std::{
+    to_binary, Addr, Binary, BlockInfo, Deps, DepsMut, Empty, Env, MessageInfo, Order, Response,
+    StdResult, Storage, SubMsg, WasmMsg,
+};
+use cw_paginate::paginate_map_values;
+use cw_storage_plus::Bound;
+use cw_utils::Duration;
+use dao_pre_propose_base::{error::PreProposeError, msg::ExecuteMsg as ExecuteBase};
+use serde::Serialize;
+
+use crate::msg::{
+    ApprovalProposeMessage, ApproverProposeMessage, ExecuteExt, ExecuteMsg, InstantiateMsg,
+    ProposeMessageInternal, ProposeMsgContent, UncheckedApprovalCommittee,
+};
+use crate::state::{
+    ApprovalCommittee, ApproverDecision, Decision, DecisionTally, PendingProposal,
+    PreProposeApprovalContract,
+};
+
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
+
+impl<ProposeMessage> PreProposeApprovalContract<ProposeMessage>
+where
+    ProposeMessage: ApprovalProposeMessage + Serialize,
+{
+    pub fn instantiate(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, PreProposeError> {
+        let approver = deps.api.addr_validate(&msg.extension.approver)?;
+        self.approver.save(deps.storage, &approver)?;
+        self.save_approval_settings(
+            deps.branch(),
+            msg.extension.committee.clone(),
+            msg.extension.pending_expiration,
+        )?;
+
+        let resp = self.base.instantiate(deps, env, info, msg)?;
+        Ok(resp.add_attribute("approver", approver.to_string()))
+    }
+
+    pub fn execute(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ExecuteMsg<ProposeMessage>,
+    ) -> Result<Response, PreProposeError> {
+        match msg {
+            ExecuteMsg::Propose { msg } => self.execute_propose(deps, env, info, msg),
+
+            ExecuteMsg::AddProposalSubmittedHook { address } => {
+                self.execute_add_approver_hook(deps, info, address)
+            }
+            ExecuteMsg::RemoveProposalSubmittedHook { address } => {
+                self.execute_remove_approver_hook(deps, info, address)
+            }
+
+            ExecuteMsg::Extension { msg } => match msg {
+                ExecuteExt::Approve { id } => self.execute_approve(deps, env, info, id),
+                ExecuteExt::Reject { id } => self.execute_reject(deps, env, info, id),
+                ExecuteExt::UpdateApprover { address } => {
+                    self.execute_update_approver(deps, info, address)
+                }
+                ExecuteExt::UpdateApprovalSettings {
+                    committee,
+                    pending_expiration,
+                } => {
+                    self.execute_update_approval_settings(deps, info, committee, pending_expiration)
+                }
+                ExecuteExt::Expire { id } => self.execute_expire(deps, env, id),
+                ExecuteExt::Edit { id, msg } => self.execute_edit(deps, env, info, id, msg),
+                ExecuteExt::WithdrawProposal { id } => {
+                    self.execute_withdraw_proposal(deps, info, id)
+                }
+                ExecuteExt::Return { id, comment } => {
+                    self.execute_return(deps, env, info, id, comment)
+                }
+            },
+            // Default pre-propose-base behavior for all other messages
+            _ => self.base.execute(deps, env, info, msg),
+        }
+    }
+
+    pub fn execute_propose(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: ProposeMessage,
+    ) -> Result<Response, PreProposeError> {
+        let config = self.base.config.load(deps.storage)?;
+
+        self.base
+            .check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.base
+            .check_proposal_limits(deps.as_ref(), &env.block, &info.sender)?;
+
+        // NFT deposits are not supported by this module as pending
+        // proposals do not track them.
+        if config.nft_deposit.is_some() {
+            return Err(PreProposeError::NftDepositUnsupported {});
+        }
+
+        // Take the submission fee and deposit, if configured.
+        let (info, fee_messages) = self
+            .base
+            .take_submission_fee(deps.as_ref(), info, &config)?;
+        let deposit = self
+            .base
+            .select_deposit(deps.as_ref(), &env, &info, &config)?;
+        let deposit_messages = if let Some(ref deposit_info) = deposit {
+            deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
+        } else {
+            vec![]
+        };
+
+        let approval_id = self.advance_approval_id(deps.storage)?;
+
+        let propose_msg_internal = msg.into_propose_msg(&info.sender);
+
+        // Prepare proposal submitted hooks msg to notify approver.  Make
+        // a proposal on the approver DAO to approve this pre-proposal
+        let hooks_msgs =
+            self.prepare_submitted_hooks(deps.storage, approval_id, &propose_msg_internal)?;
+
+        // Pending proposals count towards the proposer's open proposals.
+        self.base
+            .record_submission(deps.storage, &env.block, &info.sender)?;
+
+        let expiration = self
+            .pending_expiration
+            .may_load(deps.storage)?
+            .map(|duration| duration.after(&env.block));
+
+        // Save the proposal and its information as pending.
+        self.pending_proposals.save(
+            deps.storage,
+            approval_id,
+            &PendingProposal {
+                approval_id,
+                proposer: info.sender,
+                msg: propose_msg_internal,
+                deposit,
+                submission_fee: config.submission_fee,
+                expiration,
+                return_comment: None,
+                start_height: Some(env.block.height),
+            },
+        )?;
+
+        Ok(Response::default()
+            .add_messages(deposit_messages)
+            .add_messages(fee_messages)
+            .add_submessages(hooks_msgs)
+            .add_attribute("method", "pre-propose")
+            .add_attribute("id", approval_id.to_string()))
+    }
+
+    pub fn execute_approve(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let proposal = self.load_reviewable_pending(deps.storage, &env.block, id)?;
+
+        // The approver may always approve, with or without a committee.
+        if self.approver.load(deps.storage)? == info.sender {
+            return self.approve_pending(deps, id, proposal);
+        }
+
+        match self.committee.may_load(deps.storage)? {
+            None => Err(PreProposeError::Unauthorized {}),
+            Some(committee) => {
+                let tally = self.record_decision(
+                    deps.branch(),
+                    &committee,
+                    &info.sender,
+                    &proposal,
+                    Decision::Approve,
+                )?;
+                let total_weight = committee.total_weight(&deps.querier, proposal.start_height)?;
+                if tally.approvals >= committee.effective_threshold(total_weight) {
+                    self.approve_pending(deps, id, proposal)
+                } else {
+                    Ok(Response::default()
+                        .add_attribute("method", "approve")
+                        .add_attribute("approval_id", id.to_string())
+                        .add_attribute("approver", info.sender)
+                        .add_attribute("approvals", tally.approvals.to_string()))
+                }
+            }
+        }
+    }
+
+    pub fn execute_reject(
+        &self,
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let proposal = self.load_reviewable_pending(deps.storage, &env.block, id)?;
+
+        // The approver may always reject, with or without a committee.
+        if self.approver.load(deps.storage)? == info.sender {
+            return self.reject_pending(deps, id, proposal);
+        }
+
+        match self.committee.may_load(deps.storage)? {
+            None => Err(PreProposeError::Unauthorized {}),
+            Some(committee) => {
+                let tally = self.record_decision(
+                    deps.branch(),
+                    &committee,
+                    &info.sender,
+                    &proposal,
+                    Decision::Reject,
+                )?;
+                // Reject once enough approvers have rejected the proposal
+                // that it can no longer reach the approval threshold.
+                let total_weight = committee.total_weight(&deps.querier, proposal.start_height)?;
+                if tally.rejections > total_weight - committee.effective_threshold(total_weight) {
+                    self.reject_pending(deps, id, proposal)
+                } else {
+                    Ok(Response::default()
+                        .add_attribute("method", "reject")
+                        .add_attribute("approval_id", id.to_string())
+                        .add_attribute("approver", info.sender)
+                        .add_attribute("rejections", tally.rejections.to_string()))
+                }
+            }
+        }
+    }
+
+    pub fn execute_expire(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let PendingProposal {
+            deposit,
+            proposer,
+            expiration,
+            ..
+        } = self
+            .pending_proposals
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+
+        match expiration {
+            Some(expiration) if expiration.is_expired(&env.block) => (),
+            _ => return Err(PreProposeError::PendingProposalNotExpired {}),
+        }
+
+        self.pending_proposals.remove(deps.storage, id);
+        self.base.release_submission(deps.storage, &proposer)?;
+
+        // Expired proposals were never considered, so the deposit is
+        // returned in full regardless of the refund policy.
+        let messages = if let Some(ref deposit_info) = deposit {
+            deposit_info.get_return_deposit_message(&proposer)?
+        } else {
+            vec![]
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_expired")
+            .add_attribute("approval_id", id.to_string())
+            .add_messages(messages))
+    }
+
+    pub fn execute_edit(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+        msg: ProposeMessage,
+    ) -> Result<Response, PreProposeError> {
+        let mut proposal = self.load_unexpired_pending(deps.storage, &env.block, id)?;
+        if proposal.proposer != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        // The edited proposal must be approved again from scratch, so
+        // it is given a new approval ID. Decisions on the old ID, such
+        // as an approver DAO's proposal to approve the old content,
+        // no longer apply to it.
+        self.pending_proposals.remove(deps.storage, id);
+        self.clear_decisions(deps.storage, id)?;
+        let approval_id = self.advance_approval_id(deps.storage)?;
+
+        proposal.approval_id = approval_id;
+        proposal.msg = msg.into_propose_msg(&info.sender);
+        proposal.return_comment = None;
+        self.pending_proposals
+            .save(deps.storage, approval_id, &proposal)?;
+
+        let hooks_msgs = self.prepare_submitted_hooks(deps.storage, approval_id, &proposal.msg)?;
+
+        Ok(Response::default()
+            .add_submessages(hooks_msgs)
+            .add_attribute("method", "edit_pending_proposal")
+            .add_attribute("previous_approval_id", id.to_string())
+            .add_attribute("approval_id", approval_id.to_string()))
+    }
+
+    pub fn execute_withdraw_proposal(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        id: u64,
+    ) -> Result<Response, PreProposeError> {
+        let PendingProposal {
+            deposit, proposer, ..
+        } = self
+            .pending_proposals
+            .may_load(deps.storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        if proposer != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        self.pending_proposals.remove(deps.storage, id);
+        self.base.release_submission(deps.storage, &proposer)?;
+
+        // Withdrawn proposals were never decided on, so the deposit is
+        // returned in full regardless of the refund policy.
+        let messages = if let Some(ref deposit_info) = deposit {
+            deposit_info.get_return_deposit_message(&proposer)?
+        } else {
+            vec![]
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "withdraw_pending_proposal")
+            .add_attribute("approval_id", id.to_string())
+            .add_messages(messages))
+    }
+
+    pub fn execute_return(
+        &self,
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        id: u64,
+        comment: String,
+    ) -> Result<Response, PreProposeError> {
+        let mut proposal = self.load_reviewable_pending(deps.storage, &env.block, id)?;
+
+        // Check sender is the approver or a member of the committee
+        let authorized = self.approver.load(deps.storage)? == info.sender
+            || match self.committee.may_load(deps.storage)? {
+                None => false,
+                Some(committee) => {
+                    committee.weight(&deps.querier, &info.sender, proposal.start_height)? > 0
+                }
+            };
+        if !authorized {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        proposal.return_comment = Some(comment);
+        self.pending_proposals.save(deps.storage, id, &proposal)?;
+
+        Ok(Response::default()
+            .add_attribute("method", "return_pending_proposal")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("sender", info.sender))
+    }
+
+    pub fn execute_update_approver(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        // Check sender is the approver
+        let approver = self.approver.load(deps.storage)?;
+        if approver != info.sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+
+        // Validate address and save new approver
+        let addr = deps.api.addr_validate(&address)?;
+        self.approver.save(deps.storage, &addr)?;
+
+        Ok(Response::default())
+    }
+
+    pub fn execute_update_approval_settings(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        committee: Option<UncheckedApprovalCommittee>,
+        pending_expiration: Option<Duration>,
+    ) -> Result<Response, PreProposeError> {
+        self.check_approver_or_dao(deps.as_ref(), &info.sender)?;
+
+        self.save_approval_settings(deps, committee, pending_expiration)?;
+
+        Ok(Response::default().add_attribute("method", "update_approval_settings"))
+    }
+
+    pub fn execute_add_approver_hook(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        self.check_approver_or_dao(deps.as_ref(), &info.sender)?;
+
+        let addr = deps.api.addr_validate(&address)?;
+        self.base
+            .proposal_submitted_hooks
+            .add_hook(deps.storage, addr)?;
+
+        Ok(Response::default())
+    }
+
+    pub fn execute_remove_approver_hook(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        address: String,
+    ) -> Result<Response, PreProposeError> {
+        self.check_approver_or_dao(deps.as_ref(), &info.sender)?;
+
+        // Validate address
+        let addr = deps.api.addr_validate(&address)?;
+
+        // remove hook
+        self.base
+            .proposal_submitted_hooks
+            .remove_hook(deps.storage, addr)?;
+
+        Ok(Response::default())
+    }
+
+    pub fn query_approver(&self, deps: Deps) -> StdResult<Binary> {
+        to_binary(&self.approver.load(deps.storage)?)
+    }
+
+    pub fn query_pending_proposal(&self, deps: Deps, id: u64) -> StdResult<Binary> {
+        to_binary(&self.pending_proposals.load(deps.storage, id)?)
+    }
+
+    /// Lists pending proposals, newest first if `order` is
+    /// descending.
+    pub fn query_pending_proposals(
+        &self,
+        deps: Deps,
+        start: Option<u64>,
+        limit: Option<u32>,
+        order: Order,
+    ) -> StdResult<Binary> {
+        to_binary(&paginate_map_values(
+            deps,
+            &self.pending_proposals,
+            start,
+            limit,
+            order,
+        )?)
+    }
+
+    pub fn query_approval_committee(&self, deps: Deps) -> StdResult<Binary> {
+        to_binary(&self.committee.may_load(deps.storage)?)
+    }
+
+    pub fn query_pending_expiration(&self, deps: Deps) -> StdResult<Binary> {
+        to_binary(&self.pending_expiration.may_load(deps.storage)?)
+    }
+
+    pub fn query_proposal_decisions(
+        &self,
+        deps: Deps,
+        id: u64,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    ) -> StdResult<Binary> {
+        let start_after = start_after
+            .map(|a| deps.api.addr_validate(&a))
+            .transpose()?;
+        let decisions = self
+            .decisions
+            .prefix(id)
+            .range(
+                deps.storage,
+                start_after.as_ref().map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+            .map(|item| item.map(|(_, record)| record))
+            .collect::<StdResult<Vec<_>>>()?;
+        to_binary(&decisions)
+    }
+
+    pub fn query_approver_decisions(
+        &self,
+        deps: Deps,
+        address: String,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Binary> {
+        let address = deps.api.addr_validate(&address)?;
+        let decisions = self
+            .approver_decisions
+            .prefix(&address)
+            .range(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize)
+            .map(|item| item.map(|(_, record)| record))
+            .collect::<StdResult<Vec<_>>>()?;
+        to_binary(&decisions)
+    }
+
+    /// Prepares the proposal submitted hooks notifying approvers that
+    /// MSG is awaiting approval under APPROVAL_ID.
+    fn prepare_submitted_hooks(
+        &self,
+        storage: &dyn Storage,
+        approval_id: u64,
+        msg: &ProposeMessage::ProposeMsg,
+    ) -> StdResult<Vec<SubMsg>> {
+        self.base
+            .proposal_submitted_hooks
+            .prepare_hooks(storage, |a| {
+                let execute_msg = WasmMsg::Execute {
+                    contract_addr: a.into_string(),
+                    msg: to_binary(&ExecuteBase::<ApproverProposeMessage, Empty>::Propose {
+                        msg: ApproverProposeMessage::Propose {
+                            title: msg.title().to_string(),
+                            description: msg.description().to_string(),
+                            approval_id,
+                        },
+                    })?,
+                    funds: vec![],
+                };
+                Ok(SubMsg::new(execute_msg))
+            })
+    }
+
+    fn check_approver_or_dao(&self, deps: Deps, sender: &Addr) -> Result<(), PreProposeError> {
+        let dao = self.base.dao.load(deps.storage)?;
+        let approver = self.approver.load(deps.storage)?;
+        if approver != *sender && dao != *sender {
+            return Err(PreProposeError::Unauthorized {});
+        }
+        Ok(())
+    }
+
+    fn save_approval_settings(
+        &self,
+        deps: DepsMut,
+        committee: Option<UncheckedApprovalCommittee>,
+        pending_expiration: Option<Duration>,
+    ) -> Result<(), PreProposeError> {
+        match committee {
+            Some(committee) => {
+                let committee = committee.into_checked(deps.as_ref())?;
+                self.committee.save(deps.storage, &committee)?;
+            }
+            None => self.committee.remove(deps.storage),
+        }
+        match pending_expiration {
+            Some(duration) => self.pending_expiration.save(deps.storage, &duration)?,
+            None => self.pending_expiration.remove(deps.storage),
+        }
+        Ok(())
+    }
+
+    fn load_unexpired_pending(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        id: u64,
+    ) -> Result<PendingProposal<ProposeMessage::ProposeMsg>, PreProposeError> {
+        let proposal = self
+            .pending_proposals
+            .may_load(storage, id)?
+            .ok_or(PreProposeError::ProposalNotFound {})?;
+        match proposal.expiration {
+            Some(expiration) if expiration.is_expired(block) => {
+                Err(PreProposeError::PendingProposalExpired { expiration })
+            }
+            _ => Ok(proposal),
+        }
+    }
+
+    /// Loads a pending proposal that may be approved, rejected, or
+    /// returned.
+    fn load_reviewable_pending(
+        &self,
+        storage: &dyn Storage,
+        block: &BlockInfo,
+        id: u64,
+    ) -> Result<PendingProposal<ProposeMessage::ProposeMsg>, PreProposeError> {
+        let proposal = self.load_unexpired_pending(storage, block, id)?;
+        if proposal.return_comment.is_some() {
+            return Err(PreProposeError::PendingProposalReturned {});
+        }
+        Ok(proposal)
+    }
+
+    /// Removes all committee decisions on a pending proposal.
+    fn clear_decisions(&self, storage: &mut dyn Storage, id: u64) -> StdResult<()> {
+        let approvers = self
+            .decisions
+            .prefix(id)
+            .keys(storage, None, None, Order::Ascending)
+            .collect::<StdResult<Vec<_>>>()?;
+        for approver in approvers {
+            self.decisions.remove(storage, (id, &approver));
+            self.approver_decisions.remove(storage, (&approver, id));
+        }
+        self.tallies.remove(storage, id);
+        Ok(())
+    }
+
+    /// Records a committee member's decision on a pending proposal,
+    /// weighted by their weight in the committee when the proposal
+    /// was submitted, and returns the proposal's updated tally.
+    fn record_decision(
+        &self,
+        deps: DepsMut,
+        committee: &ApprovalCommittee,
+        approver: &Addr,
+        proposal: &PendingProposal<ProposeMessage::ProposeMsg>,
+        decision: Decision,
+    ) -> Result<DecisionTally, PreProposeError> {
+        let id = proposal.approval_id;
+        let weight = committee.weight(&deps.querier, approver, proposal.start_height)?;
+        if weight == 0 {
+            return Err(PreProposeError::Unauthorized {});
+        }
+        if self.decisions.has(deps.storage, (id, approver)) {
+            return Err(PreProposeError::AlreadyDecided {});
+        }
+
+        let record = ApproverDecision {
+            approval_id: id,
+            approver: approver.clone(),
+            decision,
+        };
+        self.decisions.save(deps.storage, (id, approver), &record)?;
+        self.approver_decisions
+            .save(deps.storage, (approver, id), &record)?;
+
+        let mut tally = self.tallies.may_load(deps.storage, id)?.unwrap_or_default();
+        match decision {
+            Decision::Approve => tally.approvals += weight,
+            Decision::Reject => tally.rejections += weight,
+        }
+        self.tallies.save(deps.storage, id, &tally)?;
+        Ok(tally)
+    }
+
+    fn approve_pending(
+        &self,
+        deps: DepsMut,
+        id: u64,
+        proposal: PendingProposal<ProposeMessage::ProposeMsg>,
+    ) -> Result<Response, PreProposeError> {
+        let proposal_module = self.base.proposal_module.load(deps.storage)?;
+
+        // Snapshot the deposit for the proposal that we're about to
+        // create.
+        let proposal_id = deps.querier.query_wasm_smart(
+            &proposal_module,
+            &dao_interface::proposal::Query::NextProposalId {},
+        )?;
+        self.base.record_deposit(
+            deps.storage,
+            proposal_id,
+            &proposal.deposit,
+            &proposal.proposer,
+        )?;
+        if let Some(ref submission_fee) = proposal.submission_fee {
+            self.base
+                .submission_fees
+                .save(deps.storage, proposal_id, submission_fee)?;
+        }
+
+        let propose_messsage = WasmMsg::Execute {
+            contract_addr: proposal_module.into_string(),
+            msg: to_binary(&ProposeMessageInternal::Propose(proposal.msg))?,
+            funds: vec![],
+        };
+        self.pending_proposals.remove(deps.storage, id);
+
+        Ok(Response::default()
+            .add_message(propose_messsage)
+            .add_attribute("method", "proposal_approved")
+            .add_attribute("approval_id", id.to_string())
+            .add_attribute("proposal_id", proposal_id.to_string()))
+    }
+
+    fn reject_pending(
+        &self,
+        deps: DepsMut,
+        id: u64,
+        proposal: PendingProposal<ProposeMessage::ProposeMsg>,
+    ) -> Result<Response, PreProposeError> {
+        let PendingProposal {
+            deposit, proposer, ..
+        } = proposal;
+
+        self.pending_proposals.remove(deps.storage, id);
+        self.base.release_submission(deps.storage, &proposer)?;
+
+        let messages = if let Some(ref deposit_info) = deposit {
+            // A rejected pending proposal is treated as a closed
+            // proposal that received no votes. `Always` and `Partial`
+            // policies refund as usual, `OnlyPassed` and `Never` do not
+            // refund, and, as there are no votes to judge it by,
+            // `SlashOnSpam` refunds.
+            let refund = deposit_info
+                .refund_policy
+                .refund_amount(deposit_info.amount, false, None);
+            // If the proposer doesn't get the deposit, the slash
+            // recipient or the DAO does.
+            let slash_recipient = match deposit_info.slash_recipient {
+                Some(ref recipient) => recipient.clone(),
+                None => self.base.dao.load(deps.storage)?,
+            };
+            deposit_info.get_split_deposit_messages(&proposer, refund, &slash_recipient)?
+        } else {
+            vec![]
+        };
+
+        Ok(Response::default()
+            .add_attribute("method", "proposal_rejected")
+            .add_attribute("proposal", id.to_string())
+            .add_attribute("deposit_info", to_binary(&deposit)?.to_string())
+            .add_messages(messages))
+    }
+}
@@ -0,0 +1,159 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Deps};
+use cw_utils::Duration;
+use dao_pre_propose_base::error::PreProposeError;
+use dao_pre_propose_base::msg::{ExecuteMsg as ExecuteBase, InstantiateMsg as InstantiateBase};
+use dao_voting::proposal::{MultipleChoiceProposeMsg, SingleChoiceProposeMsg};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::state::{ApprovalCommittee, Approvers};
+
+/// Implemented by the message proposers send to an approval module to
+/// create a proposal.
+pub trait ApprovalProposeMessage {
+    /// The message sent to the proposal module once a pending
+    /// proposal is approved.
+    type ProposeMsg: ProposeMsgContent + Serialize + DeserializeOwned;
+
+    /// Converts this message into the proposal module's propose
+    /// message, creating the proposal on behalf of `proposer`.
+    fn into_propose_msg(self, proposer: &Addr) -> Self::ProposeMsg;
+}
+
+/// Implemented by proposal modules' propose messages so that approvers
+/// may be told what is awaiting their approval.
+pub trait ProposeMsgContent {
+    fn title(&self) -> &str;
+    fn description(&self) -> &str;
+}
+
+impl ProposeMsgContent for SingleChoiceProposeMsg {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+impl ProposeMsgContent for MultipleChoiceProposeMsg {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[cw_serde]
+pub enum ApproverProposeMessage {
+    Propose {
+        title: String,
+        description: String,
+        approval_id: u64,
+    },
+}
+
+#[cw_serde]
+pub enum UncheckedApprovers {
+    /// A fixed list of approver addresses.
+    Addresses { addresses: Vec<String> },
+    /// Every member of this cw4 group with a non-zero weight is an
+    /// approver.
+    Cw4Group { group: String },
+}
+
+#[cw_serde]
+pub struct UncheckedApprovalCommittee {
+    pub approvers: UncheckedApprovers,
+    /// The total weight of approvals required to create a
+    /// proposal. Each address in an address list has a weight of one.
+    pub threshold: u64,
+}
+
+impl UncheckedApprovalCommittee {
+    pub fn into_checked(self, deps: Deps) -> Result<ApprovalCommittee, PreProposeError> {
+        let approvers = match self.approvers {
+            UncheckedApprovers::Addresses { addresses } => {
+                let mut addresses = addresses
+                    .into_iter()
+                    .map(|a| deps.api.addr_validate(&a))
+                    .collect::<Result<Vec<_>, _>>()?;
+                addresses.sort();
+                addresses.dedup();
+                Approvers::Addresses { addresses }
+            }
+            UncheckedApprovers::Cw4Group { group } => Approvers::Cw4Group {
+                group: deps.api.addr_validate(&group)?,
+            },
+        };
+        let committee = ApprovalCommittee {
+            approvers,
+            threshold: self.threshold,
+        };
+        if committee.threshold == 0
+            || committee.threshold > committee.total_weight(&deps.querier, None)?
+        {
+            return Err(PreProposeError::InvalidApprovalThreshold {});
+        }
+        Ok(committee)
+    }
+}
+
+#[cw_serde]
+pub struct InstantiateExt {
+    /// The approver. This address may always approve and reject
+    /// proposals, and manage the approval settings.
+    pub approver: String,
+    /// An optional committee of approvers that may also approve and
+    /// reject proposals.
+    pub committee: Option<UncheckedApprovalCommittee>,
+    /// If set, pending proposals that have not been approved or
+    /// rejected within this duration expire and have their deposits
+    /// refunded.
+    pub pending_expiration: Option<Duration>,
+}
+
+#[cw_serde]
+pub enum ExecuteExt<ProposeMessage> {
+    /// Approve a proposal, only callable by approver or, if
+    /// configured, a committee member
+    Approve { id: u64 },
+    /// Reject a proposal, only callable by approver or, if
+    /// configured, a committee member
+    Reject { id: u64 },
+    /// Updates the approver, can only be called the current approver
+    UpdateApprover { address: String },
+    /// Updates the approval committee and pending proposal
+    /// expiration, can only be called by the approver or the DAO
+    UpdateApprovalSettings {
+        committee: Option<UncheckedApprovalCommittee>,
+        pending_expiration: Option<Duration>,
+    },
+    /// Removes an expired pending proposal and refunds its deposit,
+    /// callable by anyone
+    Expire { id: u64 },
+    /// Replaces the contents of a pending proposal and moves it to a
+    /// new approval ID, resetting any approvals, only callable by the
+    /// proposer
+    Edit { id: u64, msg: ProposeMessage },
+    /// Removes a pending proposal and refunds its deposit, only
+    /// callable by the proposer
+    WithdrawProposal { id: u64 },
+    /// Returns a pending proposal to the proposer for revision, only
+    /// callable by approver or, if configured, a committee member
+    Return { id: u64, comment: String },
+}
+
+pub type InstantiateMsg = InstantiateBase<InstantiateExt>;
+pub type ExecuteMsg<ProposeMessage> = ExecuteBase<ProposeMessage, ExecuteExt<ProposeMessage>>;
+
+/// Internal version of the propose message that includes the
+/// `proposer` field. The module will fill this in based on the sender
+/// of the external message.
+#[cw_serde]
+pub(crate) enum ProposeMessageInternal<ProposeMsg> {
+    Propose(ProposeMsg),
+}
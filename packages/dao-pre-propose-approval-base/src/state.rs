@@ -0,0 +1,204 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, QuerierWrapper, StdResult, Storage};
+use cw4::{Cw4Contract, Cw4QueryMsg, TotalWeightResponse};
+use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
+
+use dao_pre_propose_base::state::PreProposeContract;
+use dao_voting::deposit::{CheckedDepositInfo, CheckedSubmissionFee};
+
+use crate::msg::{ApprovalProposeMessage, ExecuteExt, InstantiateExt};
+
+#[cw_serde]
+pub struct PendingProposal<ProposeMsg> {
+    /// The approval ID used to identify this pending proposal.
+    pub approval_id: u64,
+    /// The address that created the proposal.
+    pub proposer: Addr,
+    /// The propose message that ought to be executed on the proposal
+    /// message if this proposal is approved.
+    pub msg: ProposeMsg,
+    /// Snapshot of the deposit info at the time of proposal
+    /// submission.
+    pub deposit: Option<CheckedDepositInfo>,
+    /// Snapshot of the submission fee paid at the time of proposal
+    /// submission. Submission fees are not refunded if the proposal
+    /// is rejected.
+    pub submission_fee: Option<CheckedSubmissionFee>,
+    /// When this pending proposal expires if it has not been approved
+    /// or rejected. Once expired, anyone may remove it and have the
+    /// deposit returned to the proposer.
+    pub expiration: Option<Expiration>,
+    /// If set, the approver has returned this proposal to the
+    /// proposer with this comment. Returned proposals may not be
+    /// approved or rejected until the proposer edits them.
+    pub return_comment: Option<String>,
+    /// The block height at which this proposal was submitted. The
+    /// membership of a cw4 group approval committee is read at this
+    /// height. `None` for proposals submitted before this was
+    /// recorded, in which case the group's current membership is
+    /// used.
+    pub start_height: Option<u64>,
+}
+
+#[cw_serde]
+pub enum Approvers {
+    /// A fixed list of approver addresses.
+    Addresses { addresses: Vec<Addr> },
+    /// Every member of this cw4 group with a non-zero weight is an
+    /// approver.
+    Cw4Group { group: Addr },
+}
+
+/// A committee of approvers. A pending proposal is created once
+/// approvers with a total weight of `threshold` approve it. Each
+/// address in an address list has a weight of one.
+#[cw_serde]
+pub struct ApprovalCommittee {
+    pub approvers: Approvers,
+    pub threshold: u64,
+}
+
+impl ApprovalCommittee {
+    /// Returns the weight of `who` in the committee, zero if they are
+    /// not an approver. Cw4 group membership is read at `height`, or
+    /// currently if `height` is `None`.
+    pub fn weight(
+        &self,
+        querier: &QuerierWrapper,
+        who: &Addr,
+        height: Option<u64>,
+    ) -> StdResult<u64> {
+        match self.approvers {
+            Approvers::Addresses { ref addresses } => Ok(addresses.contains(who) as u64),
+            Approvers::Cw4Group { ref group } => Ok(Cw4Contract::new(group.clone())
+                .member_at_height(querier, who, height)?
+                .unwrap_or_default()),
+        }
+    }
+
+    /// Returns the total weight of the committee's approvers. Cw4
+    /// group membership is read at `height`, or currently if `height`
+    /// is `None`.
+    pub fn total_weight(&self, querier: &QuerierWrapper, height: Option<u64>) -> StdResult<u64> {
+        match self.approvers {
+            Approvers::Addresses { ref addresses } => Ok(addresses.len() as u64),
+            Approvers::Cw4Group { ref group } => {
+                let response: TotalWeightResponse = querier
+                    .query_wasm_smart(group, &Cw4QueryMsg::TotalWeight { at_height: height })?;
+                Ok(response.weight)
+            }
+        }
+    }
+
+    /// Returns the weight of approvals needed for a pending proposal
+    /// to be created given that the committee has a total weight of
+    /// `total_weight`. Members may leave a cw4 group committee, so the
+    /// threshold is capped at the committee's total weight.
+    pub fn effective_threshold(&self, total_weight: u64) -> u64 {
+        self.threshold.min(total_weight)
+    }
+}
+
+#[cw_serde]
+#[derive(Copy)]
+pub enum Decision {
+    Approve,
+    Reject,
+}
+
+/// An individual approver's decision on a pending proposal.
+#[cw_serde]
+pub struct ApproverDecision {
+    pub approval_id: u64,
+    pub approver: Addr,
+    pub decision: Decision,
+}
+
+/// The total weight of the committee decisions on a pending proposal.
+#[cw_serde]
+#[derive(Default)]
+pub struct DecisionTally {
+    pub approvals: u64,
+    pub rejections: u64,
+}
+
+pub struct PreProposeApprovalContract<ProposeMessage: ApprovalProposeMessage> {
+    /// The pre-propose base contract that handles deposits, hooks,
+    /// and configuration.
+    pub base: PreProposeContract<
+        InstantiateExt,
+        ExecuteExt<ProposeMessage>,
+        cosmwasm_std::Empty,
+        ProposeMessage,
+    >,
+    /// The approver. If no committee is configured this address
+    /// approves and rejects proposals.
+    pub approver: Item<'static, Addr>,
+    /// Proposals awaiting approval by approval ID.
+    pub pending_proposals: Map<'static, u64, PendingProposal<ProposeMessage::ProposeMsg>>,
+    /// If set, pending proposals are decided by this committee
+    /// instead of by `approver` alone.
+    pub committee: Item<'static, ApprovalCommittee>,
+    /// If set, how long proposals may remain pending before expiring.
+    pub pending_expiration: Item<'static, Duration>,
+    /// Committee decisions keyed by approval ID and approver.
+    pub decisions: Map<'static, (u64, &'static Addr), ApproverDecision>,
+    /// Committee decisions keyed by approver and approval ID.
+    pub approver_decisions: Map<'static, (&'static Addr, u64), ApproverDecision>,
+    /// The total weight of committee decisions by approval ID.
+    pub tallies: Map<'static, u64, DecisionTally>,
+    /// The most recently assigned approval ID.
+    current_id: Item<'static, u64>,
+}
+
+impl<ProposeMessage: ApprovalProposeMessage> PreProposeApprovalContract<ProposeMessage> {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        approver_key: &'static str,
+        pending_proposals_key: &'static str,
+        committee_key: &'static str,
+        pending_expiration_key: &'static str,
+        decisions_key: &'static str,
+        approver_decisions_key: &'static str,
+        tallies_key: &'static str,
+        current_id_key: &'static str,
+    ) -> Self {
+        Self {
+            base: PreProposeContract::default(),
+            approver: Item::new(approver_key),
+            pending_proposals: Map::new(pending_proposals_key),
+            committee: Item::new(committee_key),
+            pending_expiration: Item::new(pending_expiration_key),
+            decisions: Map::new(decisions_key),
+            approver_decisions: Map::new(approver_decisions_key),
+            tallies: Map::new(tallies_key),
+            current_id: Item::new(current_id_key),
+        }
+    }
+
+    /// Advances and returns the approval ID to be assigned to the
+    /// next pending proposal.
+    pub(crate) fn advance_approval_id(&self, store: &mut dyn Storage) -> StdResult<u64> {
+        let id: u64 = self.current_id.may_load(store)?.unwrap_or_default() + 1;
+        self.current_id.save(store, &id)?;
+        Ok(id)
+    }
+}
+
+impl<ProposeMessage: ApprovalProposeMessage> Default
+    for PreProposeApprovalContract<ProposeMessage>
+{
+    fn default() -> Self {
+        Self::new(
+            "approver",
+            "pending_proposals",
+            "committee",
+            "pending_expiration",
+            "decisions",
+            "approver_decisions",
+            "decision_tallies",
+            "current_id",
+        )
+    }
+}
@@ -1,6 +1,6 @@
 use cosmwasm_std::StdError;
 use cw_denom::DenomError;
-use cw_utils::ParseReplyError;
+use cw_utils::{Expiration, ParseReplyError};
 use thiserror::Error;
 
 use cw_hooks::HookError;
@@ -32,15 +32,69 @@ pub enum PreProposeError {
     #[error("You must be a member of this DAO (have voting power) to create a proposal")]
     NotMember {},
 
+    #[error("Submission policy does not permit this address to create proposals")]
+    SubmissionNotPermitted {},
+
+    #[error("Minimum voting power percentage for proposal submission must be greater than zero and at most one")]
+    InvalidSubmissionPercent {},
+
+    #[error("Maximum open proposals and proposal cooldown must be non-zero")]
+    InvalidProposalLimits {},
+
+    #[error("Proposer already has the maximum number of open proposals ({max})")]
+    TooManyOpenProposals { max: u64 },
+
+    #[error("Proposer may not create another proposal until {available}")]
+    ProposalCooldown { available: Expiration },
+
+    #[error("Each proposal deposit option must use a different denomination")]
+    DuplicateDepositDenom {},
+
+    #[error("Deposit does not match any of the accepted proposal deposits")]
+    NoMatchingDeposit {},
+
+    #[error("An NFT must be escrowed with this contract to create a proposal")]
+    NftDepositRequired {},
+
+    #[error("This module does not support NFT proposal deposits")]
+    NftDepositUnsupported {},
+
+    #[error("NFTs from this collection are not accepted as proposal deposits")]
+    WrongNftCollection {},
+
+    #[error("Sender already has an NFT escrowed with this contract")]
+    NftAlreadyEscrowed {},
+
+    #[error("Sender has no NFT escrowed with this contract")]
+    NoEscrowedNft {},
+
+    #[error("Approval threshold must be non-zero and no greater than the number of approvers")]
+    InvalidApprovalThreshold {},
+
+    #[error("Approver has already approved or rejected this proposal")]
+    AlreadyDecided {},
+
+    #[error("Pending proposal expired at {expiration}")]
+    PendingProposalExpired { expiration: Expiration },
+
+    #[error("Pending proposal has not expired")]
+    PendingProposalNotExpired {},
+
+    #[error("Pending proposal has been returned to the proposer for revision")]
+    PendingProposalReturned {},
+
     #[error("No denomination for withdrawal. specify a denomination to withdraw")]
     NoWithdrawalDenom {},
 
     #[error("Nothing to withdraw")]
     NothingToWithdraw {},
 
-    #[error("Proposal status ({status}) not closed or executed")]
+    #[error("Proposal status ({status}) not closed, executed, or expired")]
     NotClosedOrExecuted { status: Status },
 
+    #[error("Proposal ({id}) has no outstanding deposit")]
+    NoOutstandingDeposit { id: u64 },
+
     #[error("Proposal not found")]
     ProposalNotFound {},
 
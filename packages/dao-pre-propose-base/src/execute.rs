@@ -1,26 +1,135 @@
-use cosmwasm_schema::schemars::JsonSchema;
+use cosmwasm_schema::{cw_serde, schemars::JsonSchema};
 use cosmwasm_std::{
-    to_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult, SubMsg, WasmMsg,
+    to_binary, Addr, Binary, BlockInfo, CosmosMsg, Decimal, Deps, DepsMut, Empty, Env, MessageInfo,
+    Order, Response, StdResult, Storage, SubMsg, Uint128, WasmMsg,
 };
 
 use cw2::set_contract_version;
+use cw_storage_plus::Bound;
 
-use cw_denom::UncheckedDenom;
+use cw721::Cw721ReceiveMsg;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_utils::nonpayable;
 use dao_interface::voting::{Query as CwCoreQuery, VotingPowerAtHeightResponse};
 use dao_voting::{
-    deposit::{DepositRefundPolicy, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, CheckedNftDepositInfo, DepositError, DepositRefundPolicy,
+        UncheckedDepositInfo, UncheckedNftDepositInfo, UncheckedSubmissionFee,
+    },
     status::Status,
+    voting::Votes,
 };
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     error::PreProposeError,
-    msg::{DepositInfoResponse, ExecuteMsg, InstantiateMsg, QueryMsg},
-    state::{Config, PreProposeContract},
+    msg::{
+        DepositInfoResponse, ExecuteMsg, InstantiateMsg, OutstandingDeposit,
+        OutstandingDepositTotal, ProposerUsageResponse, QueryMsg, UncheckedSubmissionPolicy,
+    },
+    state::{
+        Config, DepositOutcome, EscrowedNft, NftDeposit, PreProposeContract, ProposalLimits,
+        ProposerUsage,
+    },
 };
 
+#[cw_serde]
+enum ProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+/// The subset of a single choice proposal module's `Proposal {}`
+/// query response needed to inspect its votes. Fields not listed
+/// here are ignored.
+#[derive(Deserialize)]
+struct ProposalVotesResponse {
+    proposal: ProposalVotes,
+}
+
+#[derive(Deserialize)]
+struct ProposalVotes {
+    votes: Votes,
+}
+
+/// The subset of a proposal module's `Proposal {}` query response
+/// needed to inspect its status.
+#[derive(Deserialize)]
+struct ProposalStatusResponse {
+    proposal: ProposalStatus,
+}
+
+#[derive(Deserialize)]
+struct ProposalStatus {
+    status: Status,
+}
+
+/// Returns true if a proposal with STATUS has been completed and its
+/// deposit may be settled.
+fn is_completed(status: Status) -> bool {
+    matches!(status, Status::Closed | Status::Executed | Status::Expired)
+}
+
+/// Queries the portion of votes cast on a proposal that were "no"
+/// votes. Returns `None` if the proposal module does not use yes / no
+/// / abstain voting, or if no votes were cast.
+fn query_no_ratio(deps: Deps, proposal_module: &Addr, proposal_id: u64) -> Option<Decimal> {
+    let response: ProposalVotesResponse = deps
+        .querier
+        .query_wasm_smart(proposal_module, &ProposalQuery::Proposal { proposal_id })
+        .ok()?;
+    let votes = response.proposal.votes;
+    let total = votes.total();
+    if total.is_zero() {
+        None
+    } else {
+        Some(Decimal::from_ratio(votes.no, total))
+    }
+}
+
+type DepositOptions = (
+    Option<CheckedDepositInfo>,
+    Vec<CheckedDepositInfo>,
+    Option<CheckedNftDepositInfo>,
+);
+
+/// Validates the deposits that may be used to create a proposal.
+fn check_deposit_options(
+    deps: Deps,
+    dao: &Addr,
+    deposit_info: Option<UncheckedDepositInfo>,
+    alternative_deposits: Vec<UncheckedDepositInfo>,
+    nft_deposit: Option<UncheckedNftDepositInfo>,
+) -> Result<DepositOptions, PreProposeError> {
+    let deposit_info = deposit_info
+        .map(|d| d.into_checked(deps, dao.clone()))
+        .transpose()?;
+    let alternative_deposits = alternative_deposits
+        .into_iter()
+        .map(|d| d.into_checked(deps, dao.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let denoms: Vec<&CheckedDenom> = deposit_info
+        .iter()
+        .chain(alternative_deposits.iter())
+        .map(|d| &d.denom)
+        .collect();
+    if denoms
+        .iter()
+        .enumerate()
+        .any(|(i, denom)| denoms[i + 1..].contains(denom))
+    {
+        return Err(PreProposeError::DuplicateDepositDenom {});
+    }
+
+    let nft_deposit = nft_deposit.map(|d| d.into_checked(deps)).transpose()?;
+
+    Ok((deposit_info, alternative_deposits, nft_deposit))
+}
+
 const CONTRACT_NAME: &str = "crates.io::dao-pre-propose-base";
 const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const DEFAULT_LIMIT: u32 = 10;
+pub const MAX_LIMIT: u32 = 50;
 
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
@@ -50,14 +159,37 @@ where
 
         self.dao.save(deps.storage, &dao)?;
 
-        let deposit_info = msg
-            .deposit_info
-            .map(|info| info.into_checked(deps.as_ref(), dao.clone()))
+        let (deposit_info, alternative_deposits, nft_deposit) = check_deposit_options(
+            deps.as_ref(),
+            &dao,
+            msg.deposit_info,
+            msg.alternative_deposits,
+            msg.nft_deposit,
+        )?;
+        let submission_fee = msg
+            .submission_fee
+            .map(|fee| fee.into_checked(deps.as_ref()))
             .transpose()?;
 
+        let submission_policy = msg
+            .submission_policy
+            .map(|policy| policy.into_checked(deps.api))
+            .transpose()?;
+
+        if let Some(ref limits) = msg.proposal_limits {
+            if !limits.is_valid() {
+                return Err(PreProposeError::InvalidProposalLimits {});
+            }
+        }
+
         let config = Config {
             deposit_info,
             open_proposal_submission: msg.open_proposal_submission,
+            submission_policy,
+            proposal_limits: msg.proposal_limits,
+            alternative_deposits,
+            nft_deposit,
+            submission_fee,
         };
 
         self.config.save(deps.storage, &config)?;
@@ -70,6 +202,17 @@ where
                 "open_proposal_submission",
                 config.open_proposal_submission.to_string(),
             )
+            .add_attribute(
+                "submission_policy",
+                format!("{:?}", config.submission_policy),
+            )
+            .add_attribute("proposal_limits", format!("{:?}", config.proposal_limits))
+            .add_attribute(
+                "alternative_deposits",
+                format!("{:?}", config.alternative_deposits),
+            )
+            .add_attribute("nft_deposit", format!("{:?}", config.nft_deposit))
+            .add_attribute("submission_fee", format!("{:?}", config.submission_fee))
             .add_attribute("dao", dao))
     }
 
@@ -85,7 +228,24 @@ where
             ExecuteMsg::UpdateConfig {
                 deposit_info,
                 open_proposal_submission,
-            } => self.execute_update_config(deps, info, deposit_info, open_proposal_submission),
+                submission_policy,
+                proposal_limits,
+                alternative_deposits,
+                nft_deposit,
+                submission_fee,
+            } => self.execute_update_config(
+                deps,
+                info,
+                deposit_info,
+                open_proposal_submission,
+                submission_policy,
+                proposal_limits,
+                alternative_deposits,
+                nft_deposit,
+                submission_fee,
+            ),
+            ExecuteMsg::ReceiveNft(msg) => self.execute_receive_nft(deps, info, msg),
+            ExecuteMsg::ReturnNft {} => self.execute_return_nft(deps, info),
             ExecuteMsg::Withdraw { denom } => {
                 self.execute_withdraw(deps.as_ref(), env, info, denom)
             }
@@ -98,7 +258,11 @@ where
             ExecuteMsg::ProposalCompletedHook {
                 proposal_id,
                 new_status,
-            } => self.execute_proposal_completed_hook(deps.as_ref(), info, proposal_id, new_status),
+                vetoed,
+            } => self.execute_proposal_completed_hook(deps, info, proposal_id, new_status, vetoed),
+            ExecuteMsg::RefundDeposits { proposal_ids } => {
+                self.execute_refund_deposits(deps, info, proposal_ids)
+            }
 
             ExecuteMsg::Extension { .. } => Ok(Response::default()),
         }
@@ -112,11 +276,41 @@ where
         msg: ProposalMessage,
     ) -> Result<Response, PreProposeError> {
         self.check_can_submit(deps.as_ref(), info.sender.clone())?;
+        self.check_proposal_limits(deps.as_ref(), &env.block, &info.sender)?;
 
         let config = self.config.load(deps.storage)?;
 
-        let deposit_messages = if let Some(ref deposit_info) = config.deposit_info {
-            deposit_info.check_native_deposit_paid(&info)?;
+        // The submission fee is paid first. Whatever funds remain pay
+        // the deposit.
+        let (info, fee_messages) = self.take_submission_fee(deps.as_ref(), info, &config)?;
+
+        // Proposers who have escrowed an NFT from the configured
+        // collection use it as their deposit. Otherwise, one of the
+        // fungible deposits is taken.
+        let nft_deposit = match config.nft_deposit {
+            Some(ref nft_deposit) => self
+                .escrowed_nfts
+                .may_load(deps.storage, &info.sender)?
+                .filter(|escrowed| escrowed.collection == nft_deposit.collection)
+                .map(|escrowed| NftDeposit {
+                    info: nft_deposit.clone(),
+                    token_id: escrowed.token_id,
+                }),
+            None => None,
+        };
+        let deposit_info = if nft_deposit.is_some() {
+            // Funds sent alongside an NFT deposit would be stuck.
+            nonpayable(&info).map_err(DepositError::from)?;
+            None
+        } else {
+            let deposit_info = self.select_deposit(deps.as_ref(), &env, &info, &config)?;
+            if deposit_info.is_none() && config.nft_deposit.is_some() {
+                return Err(PreProposeError::NftDepositRequired {});
+            }
+            deposit_info
+        };
+
+        let deposit_messages = if let Some(ref deposit_info) = deposit_info {
             deposit_info.get_take_deposit_messages(&info.sender, &env.contract.address)?
         } else {
             vec![]
@@ -130,11 +324,17 @@ where
             &proposal_module,
             &dao_interface::proposal::Query::NextProposalId {},
         )?;
-        self.deposits.save(
-            deps.storage,
-            next_id,
-            &(config.deposit_info, info.sender.clone()),
-        )?;
+        self.record_deposit(deps.storage, next_id, &deposit_info, &info.sender)?;
+        if let Some(ref submission_fee) = config.submission_fee {
+            self.submission_fees
+                .save(deps.storage, next_id, submission_fee)?;
+        }
+        if let Some(nft_deposit) = nft_deposit {
+            self.escrowed_nfts.remove(deps.storage, &info.sender);
+            self.nft_deposits
+                .save(deps.storage, next_id, &nft_deposit)?;
+        }
+        self.record_submission(deps.storage, &env.block, &info.sender)?;
 
         let propose_messsage = WasmMsg::Execute {
             contract_addr: proposal_module.into_string(),
@@ -162,28 +362,76 @@ where
             // {}` query.
             .add_message(propose_messsage)
             .add_submessages(hooks_msgs)
-            .add_messages(deposit_messages))
+            .add_messages(deposit_messages)
+            .add_messages(fee_messages))
+    }
+
+    /// Takes the configured submission fee, if any, from the funds
+    /// sent by the proposer. Returns INFO with the fee removed from
+    /// its funds so that the remainder may be checked against the
+    /// deposit, and the messages that send the fee to its recipient.
+    pub fn take_submission_fee(
+        &self,
+        deps: Deps,
+        mut info: MessageInfo,
+        config: &Config,
+    ) -> Result<(MessageInfo, Vec<CosmosMsg>), PreProposeError> {
+        match config.submission_fee {
+            Some(ref fee) => {
+                let dao = self.dao.load(deps.storage)?;
+                let messages = fee.get_fee_messages(&info.sender, &dao)?;
+                info.funds = fee.deduct_native_fee(&info.funds)?;
+                Ok((info, messages))
+            }
+            None => Ok((info, vec![])),
+        }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn execute_update_config(
         &self,
         deps: DepsMut,
         info: MessageInfo,
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        submission_policy: Option<UncheckedSubmissionPolicy>,
+        proposal_limits: Option<ProposalLimits>,
+        alternative_deposits: Vec<UncheckedDepositInfo>,
+        nft_deposit: Option<UncheckedNftDepositInfo>,
+        submission_fee: Option<UncheckedSubmissionFee>,
     ) -> Result<Response, PreProposeError> {
         let dao = self.dao.load(deps.storage)?;
         if info.sender != dao {
             Err(PreProposeError::NotDao {})
         } else {
-            let deposit_info = deposit_info
-                .map(|d| d.into_checked(deps.as_ref(), dao))
+            let (deposit_info, alternative_deposits, nft_deposit) = check_deposit_options(
+                deps.as_ref(),
+                &dao,
+                deposit_info,
+                alternative_deposits,
+                nft_deposit,
+            )?;
+            let submission_fee = submission_fee
+                .map(|fee| fee.into_checked(deps.as_ref()))
+                .transpose()?;
+            let submission_policy = submission_policy
+                .map(|policy| policy.into_checked(deps.api))
                 .transpose()?;
+            if let Some(ref limits) = proposal_limits {
+                if !limits.is_valid() {
+                    return Err(PreProposeError::InvalidProposalLimits {});
+                }
+            }
             self.config.save(
                 deps.storage,
                 &Config {
                     deposit_info,
                     open_proposal_submission,
+                    submission_policy,
+                    proposal_limits,
+                    alternative_deposits,
+                    nft_deposit,
+                    submission_fee,
                 },
             )?;
 
@@ -270,10 +518,11 @@ where
 
     pub fn execute_proposal_completed_hook(
         &self,
-        deps: Deps,
+        deps: DepsMut,
         info: MessageInfo,
         id: u64,
         new_status: Status,
+        vetoed: bool,
     ) -> Result<Response, PreProposeError> {
         let proposal_module = self.proposal_module.load(deps.storage)?;
         if info.sender != proposal_module {
@@ -285,31 +534,19 @@ where
         // bizare has happened. In that event, this message errors
         // which ought to cause the proposal module to remove this
         // module and open proposal submission to anyone.
-        if new_status != Status::Closed && new_status != Status::Executed {
+        if !is_completed(new_status) {
             return Err(PreProposeError::NotClosedOrExecuted { status: new_status });
         }
 
         match self.deposits.may_load(deps.storage, id)? {
-            Some((deposit_info, proposer)) => {
-                let messages = if let Some(ref deposit_info) = deposit_info {
-                    // Refund can be issued if proposal if it is going to
-                    // closed or executed.
-                    let should_refund_to_proposer = (new_status == Status::Closed
-                        && deposit_info.refund_policy == DepositRefundPolicy::Always)
-                        || (new_status == Status::Executed
-                            && deposit_info.refund_policy != DepositRefundPolicy::Never);
-
-                    if should_refund_to_proposer {
-                        deposit_info.get_return_deposit_message(&proposer)?
-                    } else {
-                        // If the proposer doesn't get the deposit, the DAO does.
-                        let dao = self.dao.load(deps.storage)?;
-                        deposit_info.get_return_deposit_message(&dao)?
-                    }
-                } else {
-                    // No deposit info for this proposal. Nothing to do.
-                    vec![]
-                };
+            // Proposals are only settled once, so a repeated hook does
+            // nothing.
+            Some(_) if self.settled_proposals.has(deps.storage, id) => Ok(Response::default()
+                .add_attribute("method", "execute_proposal_completed_hook")
+                .add_attribute("proposal", id.to_string())),
+
+            Some((deposit_info, _)) => {
+                let messages = self.settle_proposal(deps, id, new_status, vetoed)?;
 
                 Ok(Response::default()
                     .add_attribute("method", "execute_proposal_completed_hook")
@@ -328,15 +565,412 @@ where
         }
     }
 
+    pub fn execute_refund_deposits(
+        &self,
+        mut deps: DepsMut,
+        info: MessageInfo,
+        proposal_ids: Vec<u64>,
+    ) -> Result<Response, PreProposeError> {
+        let dao = self.dao.load(deps.storage)?;
+        if info.sender != dao {
+            return Err(PreProposeError::NotDao {});
+        }
+        let proposal_module = self.proposal_module.load(deps.storage)?;
+
+        let mut messages = vec![];
+        for id in proposal_ids.iter().copied() {
+            if !self.unsettled_deposits.has(deps.storage, id) {
+                return Err(PreProposeError::NoOutstandingDeposit { id });
+            }
+
+            let status = deps
+                .querier
+                .query_wasm_smart::<ProposalStatusResponse>(
+                    &proposal_module,
+                    &ProposalQuery::Proposal { proposal_id: id },
+                )?
+                .proposal
+                .status;
+            if !is_completed(status) {
+                return Err(PreProposeError::NotClosedOrExecuted { status });
+            }
+
+            // Vetoes are only reported by the proposal module's
+            // completion hook, which failed to settle this deposit.
+            messages.extend(self.settle_proposal(deps.branch(), id, status, false)?);
+        }
+
+        Ok(Response::default()
+            .add_attribute("method", "refund_deposits")
+            .add_attribute(
+                "proposals",
+                proposal_ids
+                    .iter()
+                    .map(|id| id.to_string())
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )
+            .add_messages(messages))
+    }
+
+    /// Records a deposit of DEPOSIT_INFO, paid by PROPOSER for the
+    /// proposal identified by ID, so that it may be settled once the
+    /// proposal is completed.
+    pub fn record_deposit(
+        &self,
+        storage: &mut dyn Storage,
+        id: u64,
+        deposit_info: &Option<CheckedDepositInfo>,
+        proposer: &Addr,
+    ) -> StdResult<()> {
+        self.deposits
+            .save(storage, id, &(deposit_info.clone(), proposer.clone()))?;
+        self.unsettled_deposits.save(storage, id, &Empty {})?;
+        if let Some(deposit_info) = deposit_info {
+            let mut totals = self
+                .outstanding_deposit_totals
+                .may_load(storage)?
+                .unwrap_or_default();
+            match totals.iter_mut().find(|(d, _)| *d == deposit_info.denom) {
+                Some((_, total)) => *total += deposit_info.amount,
+                None => totals.push((deposit_info.denom.clone(), deposit_info.amount)),
+            }
+            self.outstanding_deposit_totals.save(storage, &totals)?;
+        }
+        Ok(())
+    }
+
+    /// Settles the deposit paid for the proposal identified by ID,
+    /// if any, now that the proposal has been completed with
+    /// NEW_STATUS, and releases the proposer's submission. Returns
+    /// the messages that refund and slash the deposit.
+    fn settle_proposal(
+        &self,
+        mut deps: DepsMut,
+        id: u64,
+        new_status: Status,
+        vetoed: bool,
+    ) -> Result<Vec<CosmosMsg>, PreProposeError> {
+        let proposal_module = self.proposal_module.load(deps.storage)?;
+        let (deposit_info, proposer) = self.deposits.load(deps.storage, id)?;
+        self.settled_proposals.save(deps.storage, id, &Empty {})?;
+        self.release_submission(deps.storage, &proposer)?;
+
+        // Deposits taken before deposits were tracked are not part of
+        // the outstanding totals.
+        if self.unsettled_deposits.has(deps.storage, id) {
+            self.unsettled_deposits.remove(deps.storage, id);
+            if let Some(ref deposit_info) = deposit_info {
+                let mut totals = self.outstanding_deposit_totals.load(deps.storage)?;
+                for (denom, total) in totals.iter_mut() {
+                    if *denom == deposit_info.denom {
+                        *total -= deposit_info.amount;
+                    }
+                }
+                totals.retain(|(_, total)| !total.is_zero());
+                self.outstanding_deposit_totals
+                    .save(deps.storage, &totals)?;
+            }
+        }
+
+        let mut messages: Vec<CosmosMsg> = self
+            .settle_nft_deposit(
+                deps.branch(),
+                &proposal_module,
+                id,
+                new_status,
+                vetoed,
+                &proposer,
+            )?
+            .into_iter()
+            .collect();
+        let deposit_info = match deposit_info {
+            Some(deposit_info) => deposit_info,
+            // No fungible deposit for this proposal. Nothing to do.
+            None => return Ok(messages),
+        };
+
+        // Vetoed proposals forfeit their entire deposit regardless of
+        // the refund policy.
+        let refunded = if vetoed {
+            Uint128::zero()
+        } else {
+            self.refund_amount(
+                deps.as_ref(),
+                &proposal_module,
+                id,
+                new_status,
+                &deposit_info.refund_policy,
+                deposit_info.amount,
+            )
+        };
+
+        // Whatever the proposer doesn't get goes to the slash
+        // recipient, or the DAO if there is none.
+        let slash_recipient = self.slash_recipient(deps.as_ref(), &deposit_info.slash_recipient)?;
+        self.deposit_outcomes.save(
+            deps.storage,
+            id,
+            &DepositOutcome {
+                status: new_status,
+                proposer: proposer.clone(),
+                refunded,
+                slash_recipient: slash_recipient.clone(),
+                slashed: deposit_info.amount - refunded,
+            },
+        )?;
+
+        messages.extend(deposit_info.get_split_deposit_messages(
+            &proposer,
+            refunded,
+            &slash_recipient,
+        )?);
+        Ok(messages)
+    }
+
+    /// Records the outcome of the NFT deposit paid by PROPOSER for the
+    /// proposal identified by ID, if there is one, now that the
+    /// proposal has been completed with NEW_STATUS. Returns the
+    /// message that returns or slashes the NFT.
+    fn settle_nft_deposit(
+        &self,
+        deps: DepsMut,
+        proposal_module: &Addr,
+        id: u64,
+        new_status: Status,
+        vetoed: bool,
+        proposer: &Addr,
+    ) -> Result<Option<CosmosMsg>, PreProposeError> {
+        let NftDeposit { info, token_id } = match self.nft_deposits.may_load(deps.storage, id)? {
+            Some(deposit) => deposit,
+            None => return Ok(None),
+        };
+        self.nft_deposits.remove(deps.storage, id);
+
+        // An NFT can not be split, so it is returned if any of a
+        // deposit would have been refunded.
+        let refunded = if vetoed {
+            Uint128::zero()
+        } else {
+            self.refund_amount(
+                deps.as_ref(),
+                proposal_module,
+                id,
+                new_status,
+                &info.refund_policy,
+                Uint128::one(),
+            )
+        };
+        let slash_recipient = self.slash_recipient(deps.as_ref(), &info.slash_recipient)?;
+        self.deposit_outcomes.save(
+            deps.storage,
+            id,
+            &DepositOutcome {
+                status: new_status,
+                proposer: proposer.clone(),
+                refunded,
+                slash_recipient: slash_recipient.clone(),
+                slashed: Uint128::one() - refunded,
+            },
+        )?;
+
+        let recipient = if refunded.is_zero() {
+            &slash_recipient
+        } else {
+            proposer
+        };
+        Ok(Some(info.get_transfer_message(token_id, recipient)?))
+    }
+
+    /// Returns the deposits held for proposals that have not been
+    /// completed.
+    fn outstanding_deposits(
+        &self,
+        deps: Deps,
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    ) -> StdResult<Vec<OutstandingDeposit>> {
+        let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        self.unsettled_deposits
+            .keys(
+                deps.storage,
+                start_after.map(Bound::exclusive),
+                None,
+                Order::Ascending,
+            )
+            .take(limit as usize)
+            .map(|proposal_id| {
+                let proposal_id = proposal_id?;
+                let (deposit_info, proposer) = self.deposits.load(deps.storage, proposal_id)?;
+                Ok(OutstandingDeposit {
+                    proposal_id,
+                    deposit_info,
+                    nft_deposit: self.nft_deposits.may_load(deps.storage, proposal_id)?,
+                    proposer,
+                })
+            })
+            .collect()
+    }
+
+    /// Determines how much of a deposit of AMOUNT ought to be refunded
+    /// according to POLICY now that the proposal identified by ID has
+    /// been completed.
+    fn refund_amount(
+        &self,
+        deps: Deps,
+        proposal_module: &Addr,
+        id: u64,
+        new_status: Status,
+        policy: &DepositRefundPolicy,
+        amount: Uint128,
+    ) -> Uint128 {
+        // Vote counts are only needed to decide if a rejected
+        // proposal was spam.
+        let no_ratio = match policy {
+            DepositRefundPolicy::SlashOnSpam { .. } if new_status == Status::Closed => {
+                query_no_ratio(deps, proposal_module, id)
+            }
+            _ => None,
+        };
+        policy.refund_amount(
+            amount,
+            matches!(new_status, Status::Executed | Status::Expired),
+            no_ratio,
+        )
+    }
+
+    /// Returns the address that deposits which are not refunded are
+    /// sent to.
+    fn slash_recipient(&self, deps: Deps, recipient: &Option<Addr>) -> StdResult<Addr> {
+        match recipient {
+            Some(recipient) => Ok(recipient.clone()),
+            None => self.dao.load(deps.storage),
+        }
+    }
+
+    /// Selects which of the configured fungible deposits a proposer
+    /// is paying. Native deposits are selected by the denomination of
+    /// the funds sent with the message, and cw20 deposits by the
+    /// proposer having given this contract a sufficient
+    /// allowance. Returns `None` if no deposit is required.
+    pub fn select_deposit(
+        &self,
+        deps: Deps,
+        env: &Env,
+        info: &MessageInfo,
+        config: &Config,
+    ) -> Result<Option<CheckedDepositInfo>, PreProposeError> {
+        let mut options = config
+            .deposit_info
+            .iter()
+            .chain(config.alternative_deposits.iter());
+
+        let selected = if config.alternative_deposits.is_empty() {
+            options.next()
+        } else if info.funds.is_empty() {
+            let mut selected = None;
+            for option in options {
+                if let CheckedDenom::Cw20(ref token) = option.denom {
+                    let allowance: cw20::AllowanceResponse = deps.querier.query_wasm_smart(
+                        token,
+                        &cw20::Cw20QueryMsg::Allowance {
+                            owner: info.sender.to_string(),
+                            spender: env.contract.address.to_string(),
+                        },
+                    )?;
+                    if allowance.allowance >= option.amount
+                        && !allowance.expires.is_expired(&env.block)
+                    {
+                        selected = Some(option);
+                        break;
+                    }
+                }
+            }
+            Some(selected.ok_or(PreProposeError::NoMatchingDeposit {})?)
+        } else {
+            let selected = options.find(|option| match option.denom {
+                CheckedDenom::Native(ref denom) => info.funds.iter().any(|c| &c.denom == denom),
+                CheckedDenom::Cw20(_) => false,
+            });
+            Some(selected.ok_or(PreProposeError::NoMatchingDeposit {})?)
+        };
+
+        if let Some(deposit_info) = selected {
+            deposit_info.check_native_deposit_paid(info)?;
+        }
+        Ok(selected.cloned())
+    }
+
+    pub fn execute_receive_nft(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+        msg: Cw721ReceiveMsg,
+    ) -> Result<Response, PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        match config.nft_deposit {
+            Some(nft_deposit) if nft_deposit.collection == info.sender => (),
+            _ => return Err(PreProposeError::WrongNftCollection {}),
+        }
+
+        let sender = deps.api.addr_validate(&msg.sender)?;
+        if self.escrowed_nfts.has(deps.storage, &sender) {
+            return Err(PreProposeError::NftAlreadyEscrowed {});
+        }
+        self.escrowed_nfts.save(
+            deps.storage,
+            &sender,
+            &EscrowedNft {
+                collection: info.sender,
+                token_id: msg.token_id.clone(),
+            },
+        )?;
+
+        Ok(Response::default()
+            .add_attribute("method", "receive_nft")
+            .add_attribute("sender", sender)
+            .add_attribute("token_id", msg.token_id))
+    }
+
+    pub fn execute_return_nft(
+        &self,
+        deps: DepsMut,
+        info: MessageInfo,
+    ) -> Result<Response, PreProposeError> {
+        let EscrowedNft {
+            collection,
+            token_id,
+        } = self
+            .escrowed_nfts
+            .may_load(deps.storage, &info.sender)?
+            .ok_or(PreProposeError::NoEscrowedNft {})?;
+        self.escrowed_nfts.remove(deps.storage, &info.sender);
+
+        let transfer = WasmMsg::Execute {
+            contract_addr: collection.into_string(),
+            msg: to_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                recipient: info.sender.to_string(),
+                token_id: token_id.clone(),
+            })?,
+            funds: vec![],
+        };
+
+        Ok(Response::default()
+            .add_message(transfer)
+            .add_attribute("method", "return_nft")
+            .add_attribute("sender", info.sender)
+            .add_attribute("token_id", token_id))
+    }
+
     pub fn check_can_submit(&self, deps: Deps, who: Addr) -> Result<(), PreProposeError> {
         let config = self.config.load(deps.storage)?;
+        let dao = self.dao.load(deps.storage)?;
 
         if !config.open_proposal_submission {
-            let dao = self.dao.load(deps.storage)?;
             let voting_power: VotingPowerAtHeightResponse = deps.querier.query_wasm_smart(
-                dao.into_string(),
+                dao.to_string(),
                 &CwCoreQuery::VotingPowerAtHeight {
-                    address: who.into_string(),
+                    address: who.to_string(),
                     height: None,
                 },
             )?;
@@ -344,10 +978,69 @@ where
                 return Err(PreProposeError::NotMember {});
             }
         }
+
+        if let Some(policy) = config.submission_policy {
+            if !policy.is_permitted(deps, &dao, &who)? {
+                return Err(PreProposeError::SubmissionNotPermitted {});
+            }
+        }
         Ok(())
     }
 
-    pub fn query(&self, deps: Deps, _env: Env, msg: QueryMsg<QueryExt>) -> StdResult<Binary> {
+    /// Errors if WHO has reached the maximum number of open proposals
+    /// or is waiting for their submission cooldown to expire.
+    pub fn check_proposal_limits(
+        &self,
+        deps: Deps,
+        block: &BlockInfo,
+        who: &Addr,
+    ) -> Result<(), PreProposeError> {
+        let config = self.config.load(deps.storage)?;
+        let usage = self.proposer_usage.may_load(deps.storage, who)?;
+
+        if let (Some(limits), Some(usage)) = (config.proposal_limits, usage) {
+            if let Some(max) = limits.max_open_proposals {
+                if usage.open_proposals >= max {
+                    return Err(PreProposeError::TooManyOpenProposals { max });
+                }
+            }
+            if let Some(available) = usage.active_cooldown(block, limits.cooldown.as_ref()) {
+                return Err(PreProposeError::ProposalCooldown { available });
+            }
+        }
+        Ok(())
+    }
+
+    /// Records a proposal submission by WHO. Usage is tracked even
+    /// if no limits are configured so that limits set later account
+    /// for proposals that are already open.
+    pub fn record_submission(
+        &self,
+        storage: &mut dyn Storage,
+        block: &BlockInfo,
+        who: &Addr,
+    ) -> StdResult<()> {
+        self.proposer_usage
+            .update(storage, who, |usage| -> StdResult<_> {
+                Ok(ProposerUsage {
+                    open_proposals: usage.map_or(0, |u| u.open_proposals) + 1,
+                    last_submission_height: block.height,
+                    last_submission_time: block.time,
+                })
+            })?;
+        Ok(())
+    }
+
+    /// Records that a proposal submitted by WHO is no longer open.
+    pub fn release_submission(&self, storage: &mut dyn Storage, who: &Addr) -> StdResult<()> {
+        if let Some(mut usage) = self.proposer_usage.may_load(storage, who)? {
+            usage.open_proposals = usage.open_proposals.saturating_sub(1);
+            self.proposer_usage.save(storage, who, &usage)?;
+        }
+        Ok(())
+    }
+
+    pub fn query(&self, deps: Deps, env: Env, msg: QueryMsg<QueryExt>) -> StdResult<Binary> {
         match msg {
             QueryMsg::ProposalModule {} => to_binary(&self.proposal_module.load(deps.storage)?),
             QueryMsg::Dao {} => to_binary(&self.dao.load(deps.storage)?),
@@ -357,11 +1050,58 @@ where
                 to_binary(&DepositInfoResponse {
                     deposit_info,
                     proposer,
+                    submission_fee: self.submission_fees.may_load(deps.storage, proposal_id)?,
                 })
             }
             QueryMsg::ProposalSubmittedHooks {} => {
                 to_binary(&self.proposal_submitted_hooks.query_hooks(deps)?)
             }
+            QueryMsg::NftDeposit { proposal_id } => {
+                to_binary(&self.nft_deposits.may_load(deps.storage, proposal_id)?)
+            }
+            QueryMsg::EscrowedNft { address } => {
+                let address = deps.api.addr_validate(&address)?;
+                to_binary(&self.escrowed_nfts.may_load(deps.storage, &address)?)
+            }
+            QueryMsg::DepositOutcome { proposal_id } => {
+                to_binary(&self.deposit_outcomes.may_load(deps.storage, proposal_id)?)
+            }
+            QueryMsg::ProposerUsage { address } => {
+                let address = deps.api.addr_validate(&address)?;
+                let config = self.config.load(deps.storage)?;
+                let usage = self.proposer_usage.may_load(deps.storage, &address)?;
+                to_binary(&match usage {
+                    Some(usage) => ProposerUsageResponse {
+                        open_proposals: usage.open_proposals,
+                        cooldown_expires: config.proposal_limits.and_then(|limits| {
+                            usage.active_cooldown(&env.block, limits.cooldown.as_ref())
+                        }),
+                    },
+                    None => ProposerUsageResponse {
+                        open_proposals: 0,
+                        cooldown_expires: None,
+                    },
+                })
+            }
+            QueryMsg::ListDeposits { start_after, limit } => {
+                to_binary(&self.outstanding_deposits(deps, start_after, limit)?)
+            }
+            QueryMsg::OutstandingDepositTotal {} => {
+                let totals = self
+                    .outstanding_deposit_totals
+                    .may_load(deps.storage)?
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(denom, outstanding)| {
+                        Ok(OutstandingDepositTotal {
+                            balance: denom.query_balance(&deps.querier, &env.contract.address)?,
+                            denom,
+                            outstanding,
+                        })
+                    })
+                    .collect::<StdResult<Vec<_>>>()?;
+                to_binary(&totals)
+            }
             QueryMsg::QueryExtension { .. } => Ok(Binary::default()),
         }
     }
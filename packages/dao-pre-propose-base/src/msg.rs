@@ -1,10 +1,21 @@
 use cosmwasm_schema::{cw_serde, schemars::JsonSchema, QueryResponses};
-use cw_denom::UncheckedDenom;
+use cosmwasm_std::{Addr, Api, Decimal, Uint128};
+use cw721::Cw721ReceiveMsg;
+use cw_denom::{CheckedDenom, UncheckedDenom};
+use cw_utils::Expiration;
 use dao_voting::{
-    deposit::{CheckedDepositInfo, UncheckedDepositInfo},
+    deposit::{
+        CheckedDepositInfo, CheckedSubmissionFee, UncheckedDepositInfo, UncheckedNftDepositInfo,
+        UncheckedSubmissionFee,
+    },
     status::Status,
 };
 
+use crate::{
+    error::PreProposeError,
+    state::{NftDeposit, ProposalLimits, SubmissionPolicy},
+};
+
 #[cw_serde]
 pub struct InstantiateMsg<InstantiateExt> {
     /// Information about the deposit requirements for this
@@ -14,11 +25,71 @@ pub struct InstantiateMsg<InstantiateExt> {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Additional restrictions on who may create proposals. None if
+    /// no additional restrictions.
+    pub submission_policy: Option<UncheckedSubmissionPolicy>,
+    /// Limits on how many proposals a single address may create. None
+    /// if no limits.
+    pub proposal_limits: Option<ProposalLimits>,
+    /// Deposits that may be paid instead of `deposit_info`. Each must
+    /// use a different denomination.
+    #[serde(default)]
+    pub alternative_deposits: Vec<UncheckedDepositInfo>,
+    /// If set, proposers may escrow an NFT from this collection
+    /// instead of paying a deposit.
+    pub nft_deposit: Option<UncheckedNftDepositInfo>,
+    /// A non-refundable fee paid when a proposal is submitted, in
+    /// addition to any deposit. None if no fee.
+    pub submission_fee: Option<UncheckedSubmissionFee>,
     /// Extension for instantiation. The default implementation will
     /// do nothing with this data.
     pub extension: InstantiateExt,
 }
 
+/// Restricts which addresses may create proposals. Addresses are
+/// validated when converted into a `SubmissionPolicy`.
+#[cw_serde]
+pub enum UncheckedSubmissionPolicy {
+    /// Proposers must have at least POWER voting power.
+    MinVotingPower { power: Uint128 },
+    /// Proposers must have at least PERCENT of the DAO's total
+    /// voting power. Must be greater than zero and at most one.
+    MinVotingPowerPercent { percent: Decimal },
+    /// Only these addresses may create proposals.
+    Allowlist { addresses: Vec<String> },
+    /// These addresses may not create proposals.
+    Denylist { addresses: Vec<String> },
+}
+
+impl UncheckedSubmissionPolicy {
+    pub fn into_checked(self, api: &dyn Api) -> Result<SubmissionPolicy, PreProposeError> {
+        let validate = |addresses: Vec<String>| {
+            addresses
+                .into_iter()
+                .map(|a| api.addr_validate(&a))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(match self {
+            UncheckedSubmissionPolicy::MinVotingPower { power } => {
+                SubmissionPolicy::MinVotingPower { power }
+            }
+            UncheckedSubmissionPolicy::MinVotingPowerPercent { percent } => {
+                if percent.is_zero() || percent > Decimal::one() {
+                    return Err(PreProposeError::InvalidSubmissionPercent {});
+                }
+                SubmissionPolicy::MinVotingPowerPercent { percent }
+            }
+            UncheckedSubmissionPolicy::Allowlist { addresses } => SubmissionPolicy::Allowlist {
+                addresses: validate(addresses)?,
+            },
+            UncheckedSubmissionPolicy::Denylist { addresses } => SubmissionPolicy::Denylist {
+                addresses: validate(addresses)?,
+            },
+        })
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
 #[cw_serde]
 pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     /// Creates a new proposal in the pre-propose module. MSG will be
@@ -32,8 +103,23 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     UpdateConfig {
         deposit_info: Option<UncheckedDepositInfo>,
         open_proposal_submission: bool,
+        submission_policy: Option<UncheckedSubmissionPolicy>,
+        proposal_limits: Option<ProposalLimits>,
+        #[serde(default)]
+        alternative_deposits: Vec<UncheckedDepositInfo>,
+        nft_deposit: Option<UncheckedNftDepositInfo>,
+        submission_fee: Option<UncheckedSubmissionFee>,
     },
 
+    /// Escrows an NFT sent by the cw721 contract to be used as the
+    /// deposit for the sender's next proposal. Only NFTs from the
+    /// configured NFT deposit collection are accepted.
+    ReceiveNft(Cw721ReceiveMsg),
+
+    /// Returns the sender's escrowed NFT if it has not been used as a
+    /// proposal deposit.
+    ReturnNft {},
+
     /// Withdraws funds inside of this contract to the message
     /// sender. The contracts entire balance for the specifed DENOM is
     /// withdrawn to the message sender. Only the DAO may call this
@@ -65,6 +151,17 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
         denom: Option<UncheckedDenom>,
     },
 
+    /// Settles the deposits for the proposals identified by
+    /// PROPOSAL_IDS as if their proposal completed hooks had been
+    /// received. This is intended for proposals that were closed or
+    /// executed while this module was not attached to its proposal
+    /// module, or while proposal hooks were failing, and so never had
+    /// their deposits returned. Each proposal must be closed or
+    /// executed and be listed by `ListDeposits`. Deposits are refunded
+    /// according to their refund policy. Only the DAO may call this
+    /// method.
+    RefundDeposits { proposal_ids: Vec<u64> },
+
     /// Extension message. Contracts that extend this one should put
     /// their custom execute logic here. The default implementation
     /// will do nothing if this variant is executed.
@@ -85,9 +182,19 @@ pub enum ExecuteMsg<ProposalMessage, ExecuteExt> {
     ProposalCompletedHook {
         proposal_id: u64,
         new_status: Status,
+        /// True if the proposal was vetoed. The deposits of vetoed
+        /// proposals are never refunded. Omitted when false so that
+        /// pre-propose modules predating this field, which deny
+        /// unknown fields, still accept the hook.
+        #[serde(default, skip_serializing_if = "is_false")]
+        vetoed: bool,
     },
 }
 
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg<QueryExt>
@@ -112,6 +219,38 @@ where
     /// Returns list of proposal submitted hooks.
     #[returns(cw_hooks::HooksResponse)]
     ProposalSubmittedHooks {},
+    /// Gets what happened to the deposit for the proposal identified
+    /// by PROPOSAL_ID when it was completed. Returns `None` if the
+    /// proposal has not been completed or had no deposit.
+    #[returns(Option<crate::state::DepositOutcome>)]
+    DepositOutcome { proposal_id: u64 },
+    /// Gets the NFT held as the deposit for the proposal identified by
+    /// PROPOSAL_ID, if any.
+    #[returns(Option<crate::state::NftDeposit>)]
+    NftDeposit { proposal_id: u64 },
+    /// Gets the NFT ADDRESS has escrowed for their next proposal, if
+    /// any.
+    #[returns(Option<crate::state::EscrowedNft>)]
+    EscrowedNft { address: String },
+    /// Gets the number of open proposals created by ADDRESS and when
+    /// they may next submit a proposal.
+    #[returns(ProposerUsageResponse)]
+    ProposerUsage { address: String },
+    /// Lists deposits held by this module for proposals that have not
+    /// yet been completed, ordered by proposal ID. Proposals without a
+    /// deposit are listed so that they may be settled with
+    /// `RefundDeposits`. Deposits taken before this module began
+    /// tracking them are not listed.
+    #[returns(Vec<OutstandingDeposit>)]
+    ListDeposits {
+        start_after: Option<u64>,
+        limit: Option<u32>,
+    },
+    /// Gets the total of the outstanding deposits held by this module
+    /// for each denomination, alongside this module's balance of that
+    /// denomination.
+    #[returns(Vec<OutstandingDepositTotal>)]
+    OutstandingDepositTotal {},
     /// Extension for queries. The default implementation will do
     /// nothing if queried for will return `Binary::default()`.
     #[returns(cosmwasm_std::Binary)]
@@ -124,4 +263,39 @@ pub struct DepositInfoResponse {
     pub deposit_info: Option<CheckedDepositInfo>,
     /// The address that created the proposal.
     pub proposer: cosmwasm_std::Addr,
+    /// The submission fee that was paid for the specified proposal.
+    pub submission_fee: Option<CheckedSubmissionFee>,
+}
+
+#[cw_serde]
+pub struct ProposerUsageResponse {
+    /// The number of proposals created by the address that have not
+    /// yet been closed or executed.
+    pub open_proposals: u64,
+    /// If the address is waiting for a submission cooldown to expire,
+    /// when that cooldown expires.
+    pub cooldown_expires: Option<Expiration>,
+}
+
+#[cw_serde]
+pub struct OutstandingDeposit {
+    /// The proposal the deposit was paid for.
+    pub proposal_id: u64,
+    /// The fungible deposit that was paid, if any.
+    pub deposit_info: Option<CheckedDepositInfo>,
+    /// The NFT held as the deposit, if any.
+    pub nft_deposit: Option<NftDeposit>,
+    /// The address that created the proposal.
+    pub proposer: Addr,
+}
+
+#[cw_serde]
+pub struct OutstandingDepositTotal {
+    pub denom: CheckedDenom,
+    /// The sum of the outstanding deposits in this denomination.
+    pub outstanding: Uint128,
+    /// This module's balance of this denomination. If this is less
+    /// than `outstanding`, not all outstanding deposits can be
+    /// returned, most likely because of a `Withdraw`.
+    pub balance: Uint128,
 }
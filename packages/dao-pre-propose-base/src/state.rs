@@ -1,11 +1,17 @@
 use std::marker::PhantomData;
 
 use cosmwasm_schema::cw_serde;
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, BlockInfo, Decimal, Deps, Empty, StdResult, Timestamp, Uint128};
+use cw_denom::CheckedDenom;
 use cw_hooks::Hooks;
 use cw_storage_plus::{Item, Map};
+use cw_utils::{Duration, Expiration};
 
-use dao_voting::deposit::CheckedDepositInfo;
+use dao_voting::{
+    deposit::{CheckedDepositInfo, CheckedNftDepositInfo, CheckedSubmissionFee},
+    status::Status,
+    voting::{compare_vote_count, get_total_power, get_voting_power, VoteCmp},
+};
 
 #[cw_serde]
 pub struct Config {
@@ -16,6 +22,157 @@ pub struct Config {
     /// proposals in the DAO. Otherwise, any address may create a
     /// proposal so long as they pay the deposit.
     pub open_proposal_submission: bool,
+    /// Additional restrictions on who may create proposals. Applied
+    /// regardless of the value of `open_proposal_submission`. If
+    /// `None`, no additional restrictions are applied.
+    pub submission_policy: Option<SubmissionPolicy>,
+    /// Limits on how many proposals a single address may create. If
+    /// `None`, no limits are applied.
+    pub proposal_limits: Option<ProposalLimits>,
+    /// Deposits that may be paid instead of `deposit_info`. Each must
+    /// use a different denomination.
+    #[serde(default)]
+    pub alternative_deposits: Vec<CheckedDepositInfo>,
+    /// If set, proposers may escrow an NFT from this collection
+    /// instead of paying a deposit.
+    pub nft_deposit: Option<CheckedNftDepositInfo>,
+    /// A non-refundable fee paid when a proposal is submitted, in
+    /// addition to any deposit. If `None`, no fee is charged.
+    pub submission_fee: Option<CheckedSubmissionFee>,
+}
+
+/// An NFT that has been sent to this contract by a proposer to be
+/// used as the deposit for their next proposal.
+#[cw_serde]
+pub struct EscrowedNft {
+    pub collection: Addr,
+    pub token_id: String,
+}
+
+/// An NFT held as the deposit for a proposal.
+#[cw_serde]
+pub struct NftDeposit {
+    /// The NFT deposit configuration when the proposal was created.
+    pub info: CheckedNftDepositInfo,
+    pub token_id: String,
+}
+
+/// Per-proposer limits on proposal creation.
+#[cw_serde]
+pub struct ProposalLimits {
+    /// The maximum number of proposals created by a single address
+    /// that may be open at once. A proposal is open until it is
+    /// closed or executed.
+    pub max_open_proposals: Option<u64>,
+    /// The minimum amount of time that must pass between proposal
+    /// submissions from a single address.
+    pub cooldown: Option<Duration>,
+}
+
+impl ProposalLimits {
+    /// A limit of zero proposals or a zero length cooldown is not
+    /// meaningful and is most likely a configuration error.
+    pub fn is_valid(&self) -> bool {
+        self.max_open_proposals != Some(0)
+            && !matches!(
+                self.cooldown,
+                Some(Duration::Height(0)) | Some(Duration::Time(0))
+            )
+    }
+}
+
+/// What happened to a proposal's deposit when the proposal was
+/// completed.
+#[cw_serde]
+pub struct DepositOutcome {
+    /// The status of the proposal when it was completed.
+    pub status: Status,
+    /// The address the deposit was refunded to.
+    pub proposer: Addr,
+    /// The amount of the deposit that was refunded to the proposer.
+    pub refunded: Uint128,
+    /// The address that received the portion of the deposit that was
+    /// not refunded.
+    pub slash_recipient: Addr,
+    /// The amount of the deposit that was not refunded.
+    pub slashed: Uint128,
+}
+
+/// Proposal creation activity for a single address.
+#[cw_serde]
+pub struct ProposerUsage {
+    /// The number of proposals created by this address that have not
+    /// yet been closed or executed.
+    pub open_proposals: u64,
+    /// The block height of this address' last submission.
+    pub last_submission_height: u64,
+    /// The block time of this address' last submission.
+    pub last_submission_time: Timestamp,
+}
+
+impl ProposerUsage {
+    /// Returns the time at which a COOLDOWN started by this
+    /// proposer's last submission expires.
+    pub fn cooldown_expiration(&self, cooldown: &Duration) -> Expiration {
+        match cooldown {
+            Duration::Height(height) => Expiration::AtHeight(self.last_submission_height + height),
+            Duration::Time(time) => {
+                Expiration::AtTime(self.last_submission_time.plus_seconds(*time))
+            }
+        }
+    }
+
+    /// Returns the expiration of the proposer's current cooldown, or
+    /// `None` if they are not on cooldown.
+    pub fn active_cooldown(
+        &self,
+        block: &BlockInfo,
+        cooldown: Option<&Duration>,
+    ) -> Option<Expiration> {
+        cooldown
+            .map(|cooldown| self.cooldown_expiration(cooldown))
+            .filter(|expiration| !expiration.is_expired(block))
+    }
+}
+
+/// Restricts which addresses may create proposals.
+#[cw_serde]
+pub enum SubmissionPolicy {
+    /// Proposers must have at least POWER voting power.
+    MinVotingPower { power: Uint128 },
+    /// Proposers must have at least PERCENT of the DAO's total
+    /// voting power.
+    MinVotingPowerPercent { percent: Decimal },
+    /// Only these addresses may create proposals.
+    Allowlist { addresses: Vec<Addr> },
+    /// These addresses may not create proposals.
+    Denylist { addresses: Vec<Addr> },
+}
+
+impl SubmissionPolicy {
+    /// Determines if WHO may create a proposal in DAO under this
+    /// policy. Voting power is measured at the current block height.
+    pub fn is_permitted(&self, deps: Deps, dao: &Addr, who: &Addr) -> StdResult<bool> {
+        Ok(match self {
+            SubmissionPolicy::MinVotingPower { power } => {
+                get_voting_power(deps, who.clone(), dao, None)? >= *power
+            }
+            SubmissionPolicy::MinVotingPowerPercent { percent } => {
+                let total = get_total_power(deps, dao, None)?;
+                // If there is no voting power in the DAO nobody can
+                // meet a percentage requirement.
+                !total.is_zero()
+                    && compare_vote_count(
+                        get_voting_power(deps, who.clone(), dao, None)?,
+                        VoteCmp::Geq,
+                        total,
+                        *percent,
+                    )
+            }
+            SubmissionPolicy::Allowlist { addresses } => addresses.contains(who),
+            SubmissionPolicy::Denylist { addresses } => !addresses.contains(who),
+        })
+    }
 }
 
 pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> {
@@ -30,6 +187,29 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
     pub deposits: Map<'static, u64, (Option<CheckedDepositInfo>, Addr)>,
     /// Consumers of proposal submitted hooks.
     pub proposal_submitted_hooks: Hooks<'static>,
+    /// Map between proposers and their proposal creation activity.
+    pub proposer_usage: Map<'static, &'static Addr, ProposerUsage>,
+    /// Map between proposal IDs and what happened to their deposits
+    /// on completion.
+    pub deposit_outcomes: Map<'static, u64, DepositOutcome>,
+    /// Map between proposal IDs and the NFTs held as their deposits.
+    pub nft_deposits: Map<'static, u64, NftDeposit>,
+    /// Map between addresses and NFTs they have sent to this contract
+    /// that have not yet been used as a proposal deposit.
+    pub escrowed_nfts: Map<'static, &'static Addr, EscrowedNft>,
+    /// Map between proposal IDs and the submission fees paid to
+    /// create them.
+    pub submission_fees: Map<'static, u64, CheckedSubmissionFee>,
+    /// Proposal IDs whose deposits, if any, have not been settled.
+    /// Only deposits taken after this map was added are recorded, as
+    /// older deposits may already have been returned.
+    pub unsettled_deposits: Map<'static, u64, Empty>,
+    /// The sum of the unsettled fungible deposits in each
+    /// denomination.
+    pub outstanding_deposit_totals: Item<'static, Vec<(CheckedDenom, Uint128)>>,
+    /// Proposal IDs that have been completed and settled, whether or
+    /// not a deposit was paid for them.
+    pub settled_proposals: Map<'static, u64, Empty>,
 
     // These types are used in associated functions, but not
     // assocaited data. To stop the compiler complaining about unused
@@ -43,12 +223,21 @@ pub struct PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMess
 impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
     PreProposeContract<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
 {
+    #[allow(clippy::too_many_arguments)]
     const fn new(
         proposal_key: &'static str,
         dao_key: &'static str,
         config_key: &'static str,
         deposits_key: &'static str,
         proposal_submitted_hooks_key: &'static str,
+        proposer_usage_key: &'static str,
+        deposit_outcomes_key: &'static str,
+        nft_deposits_key: &'static str,
+        escrowed_nfts_key: &'static str,
+        submission_fees_key: &'static str,
+        unsettled_deposits_key: &'static str,
+        outstanding_deposit_totals_key: &'static str,
+        settled_proposals_key: &'static str,
     ) -> Self {
         Self {
             proposal_module: Item::new(proposal_key),
@@ -56,6 +245,14 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage>
             config: Item::new(config_key),
             deposits: Map::new(deposits_key),
             proposal_submitted_hooks: Hooks::new(proposal_submitted_hooks_key),
+            proposer_usage: Map::new(proposer_usage_key),
+            deposit_outcomes: Map::new(deposit_outcomes_key),
+            nft_deposits: Map::new(nft_deposits_key),
+            escrowed_nfts: Map::new(escrowed_nfts_key),
+            submission_fees: Map::new(submission_fees_key),
+            unsettled_deposits: Map::new(unsettled_deposits_key),
+            outstanding_deposit_totals: Item::new(outstanding_deposit_totals_key),
+            settled_proposals: Map::new(settled_proposals_key),
             execute_type: PhantomData,
             instantiate_type: PhantomData,
             query_type: PhantomData,
@@ -77,6 +274,14 @@ impl<InstantiateExt, ExecuteExt, QueryExt, ProposalMessage> Default
             "config",
             "deposits",
             "proposal_submitted_hooks",
+            "proposer_usage",
+            "deposit_outcomes",
+            "nft_deposits",
+            "escrowed_nfts",
+            "submission_fees",
+            "unsettled_deposits",
+            "outstanding_deposit_totals",
+            "settled_proposals",
         )
     }
 }
@@ -1,5 +1,5 @@
 use cosmwasm_std::{
-    from_binary,
+    from_binary, from_slice,
     testing::{mock_dependencies, mock_env, mock_info},
     to_binary, Addr, Binary, ContractResult, Empty, Response, SubMsg, WasmMsg,
 };
@@ -8,7 +8,7 @@ use dao_voting::status::Status;
 
 use crate::{
     error::PreProposeError,
-    msg::{ExecuteMsg, QueryMsg},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
     state::{Config, PreProposeContract},
 };
 
@@ -33,6 +33,7 @@ fn test_completed_hook_status_invariant() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            vetoed: false,
         },
     );
 
@@ -62,6 +63,7 @@ fn test_completed_hook_auth() {
         ExecuteMsg::ProposalCompletedHook {
             proposal_id: 1,
             new_status: Status::Passed,
+            vetoed: false,
         },
     );
 
@@ -88,6 +90,11 @@ fn test_proposal_submitted_hooks() {
             &Config {
                 deposit_info: None,
                 open_proposal_submission: true,
+                submission_policy: None,
+                proposal_limits: None,
+                alternative_deposits: vec![],
+                nft_deposit: None,
+                submission_fee: None,
             },
         )
         .unwrap();
@@ -200,3 +207,69 @@ fn test_execute_ext_does_nothing() {
         .unwrap();
     assert_eq!(res, Response::default())
 }
+
+#[test]
+fn test_deserialize_messages_without_alternative_deposits() {
+    // Configs stored, and messages sent, before alternative deposits
+    // were added must still deserialize.
+    let config: Config =
+        from_slice(br#"{"deposit_info":null,"open_proposal_submission":true}"#).unwrap();
+    assert!(config.alternative_deposits.is_empty());
+    assert!(config.open_proposal_submission);
+
+    let msg: InstantiateMsg<Empty> =
+        from_slice(br#"{"deposit_info":null,"open_proposal_submission":false,"extension":{}}"#)
+            .unwrap();
+    assert!(msg.alternative_deposits.is_empty());
+
+    let msg: ExecuteMsg<Empty, Empty> =
+        from_slice(br#"{"update_config":{"deposit_info":null,"open_proposal_submission":false}}"#)
+            .unwrap();
+    assert!(matches!(
+        msg,
+        ExecuteMsg::UpdateConfig {
+            alternative_deposits,
+            ..
+        } if alternative_deposits.is_empty()
+    ));
+}
+
+#[test]
+fn test_completed_hook_without_veto_decodes_as_old_message() {
+    // Pre-propose modules deployed before vetoes were added deny
+    // unknown fields in the proposal completed hook.
+    #[cosmwasm_schema::cw_serde]
+    enum OldExecuteMsg {
+        ProposalCompletedHook {
+            proposal_id: u64,
+            new_status: Status,
+        },
+    }
+
+    let hook = to_binary(&ExecuteMsg::<Empty, Empty>::ProposalCompletedHook {
+        proposal_id: 1,
+        new_status: Status::Executed,
+        vetoed: false,
+    })
+    .unwrap();
+    let old: OldExecuteMsg = from_binary(&hook).unwrap();
+    assert_eq!(
+        old,
+        OldExecuteMsg::ProposalCompletedHook {
+            proposal_id: 1,
+            new_status: Status::Executed,
+        }
+    );
+
+    let hook = to_binary(&ExecuteMsg::<Empty, Empty>::ProposalCompletedHook {
+        proposal_id: 1,
+        new_status: Status::Closed,
+        vetoed: true,
+    })
+    .unwrap();
+    let msg: ExecuteMsg<Empty, Empty> = from_binary(&hook).unwrap();
+    assert!(matches!(
+        msg,
+        ExecuteMsg::ProposalCompletedHook { vetoed: true, .. }
+    ));
+}
@@ -50,6 +50,16 @@ pub fn proposal_condorcet_contract() -> Box<dyn Contract<Empty>> {
     Box::new(contract)
 }
 
+pub fn proposal_conviction_contract() -> Box<dyn Contract<Empty>> {
+    let contract = ContractWrapper::new(
+        dao_proposal_conviction::contract::execute,
+        dao_proposal_conviction::contract::instantiate,
+        dao_proposal_conviction::contract::query,
+    )
+    .with_reply(dao_proposal_conviction::contract::reply);
+    Box::new(contract)
+}
+
 pub fn proposal_single_contract() -> Box<dyn Contract<Empty>> {
     let contract = ContractWrapper::new(
         dao_proposal_single::contract::execute,
@@ -9,5 +9,11 @@ pub mod helpers;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod contracts;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod simulate;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod signed_ballots;
+
 #[cfg(not(target_arch = "wasm32"))]
 pub use tests::*;
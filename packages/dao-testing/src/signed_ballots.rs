@@ -0,0 +1,205 @@
+//! Helpers for testing proposal modules that accept signed ballots.
+//!
+//! Signed ballots are counted for the address derived from their
+//! signer's public key. That address is a 20 byte canonical address
+//! which `MockApi` is unable to humanize, so tests that submit signed
+//! ballots run against an app using `BallotApi` instead.
+
+use cosmwasm_schema::serde::Serialize;
+use cosmwasm_std::{
+    testing::MockApi, to_binary, to_vec, Addr, Api, Binary, CanonicalAddr, Empty,
+    RecoverPubkeyError, StdResult, VerificationError,
+};
+use cw_multi_test::{App, AppBuilder, BankKeeper, Contract, Executor};
+use dao_interface::{Admin, ModuleInstantiateInfo};
+use dao_voting::signed_ballot::{BallotSignDoc, SignedBallot};
+use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+
+use crate::contracts::{cw4_group_contract, dao_core_contract, dao_voting_cw4_contract};
+
+/// Length of the canonical address of a secp256k1 account.
+const SIGNER_ADDRESS_LENGTH: usize = 20;
+
+/// A `MockApi` that also humanizes the canonical addresses of
+/// ballot signers. These are rendered as lowercase hex, which
+/// `MockApi` accepts as a valid human readable address.
+#[derive(Default, Clone, Copy)]
+pub struct BallotApi(MockApi);
+
+impl Api for BallotApi {
+    fn addr_validate(&self, human: &str) -> StdResult<Addr> {
+        self.0.addr_validate(human)
+    }
+
+    fn addr_canonicalize(&self, human: &str) -> StdResult<CanonicalAddr> {
+        self.0.addr_canonicalize(human)
+    }
+
+    fn addr_humanize(&self, canonical: &CanonicalAddr) -> StdResult<Addr> {
+        if canonical.len() == SIGNER_ADDRESS_LENGTH {
+            Ok(Addr::unchecked(canonical.to_string().to_lowercase()))
+        } else {
+            self.0.addr_humanize(canonical)
+        }
+    }
+
+    fn secp256k1_verify(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.secp256k1_verify(message_hash, signature, public_key)
+    }
+
+    fn secp256k1_recover_pubkey(
+        &self,
+        message_hash: &[u8],
+        signature: &[u8],
+        recovery_param: u8,
+    ) -> Result<Vec<u8>, RecoverPubkeyError> {
+        self.0
+            .secp256k1_recover_pubkey(message_hash, signature, recovery_param)
+    }
+
+    fn ed25519_verify(
+        &self,
+        message: &[u8],
+        signature: &[u8],
+        public_key: &[u8],
+    ) -> Result<bool, VerificationError> {
+        self.0.ed25519_verify(message, signature, public_key)
+    }
+
+    fn ed25519_batch_verify(
+        &self,
+        messages: &[&[u8]],
+        signatures: &[&[u8]],
+        public_keys: &[&[u8]],
+    ) -> Result<bool, VerificationError> {
+        self.0
+            .ed25519_batch_verify(messages, signatures, public_keys)
+    }
+
+    fn debug(&self, message: &str) {
+        self.0.debug(message)
+    }
+}
+
+/// An app whose API is able to humanize the addresses of ballot
+/// signers.
+pub type BallotApp = App<BankKeeper, BallotApi>;
+
+pub fn ballot_app() -> BallotApp {
+    AppBuilder::new()
+        .with_api(BallotApi::default())
+        .build(|_, _, _| {})
+}
+
+/// Gets the address that ballots signed by KEY are counted for.
+pub fn signer_address(key: &SigningKey) -> Addr {
+    let pubkey = key.verifying_key().to_bytes();
+    let canonical = Ripemd160::digest(Sha256::digest(pubkey));
+    BallotApi::default()
+        .addr_humanize(&CanonicalAddr::from(canonical.as_slice()))
+        .unwrap()
+}
+
+/// Signs a ballot casting VOTE on proposal PROPOSAL_ID in
+/// PROPOSAL_MODULE with KEY.
+pub fn sign_ballot<V: Serialize + Clone>(
+    key: &SigningKey,
+    chain_id: &str,
+    proposal_module: &Addr,
+    proposal_id: u64,
+    vote: V,
+    nonce: u64,
+) -> SignedBallot<V> {
+    let doc = BallotSignDoc {
+        chain_id: chain_id.to_string(),
+        proposal_module: proposal_module.to_string(),
+        proposal_id,
+        vote: vote.clone(),
+        nonce,
+    };
+    // Signing hashes the message with SHA-256, so this signs the
+    // document's hash.
+    let signature: Signature = key.sign(&to_vec(&doc).unwrap());
+    SignedBallot {
+        vote,
+        nonce,
+        pubkey: Binary::from(key.verifying_key().to_bytes().as_slice()),
+        signature: Binary::from(signature.as_ref()),
+    }
+}
+
+/// Instantiates a DAO whose voting power is determined by a cw4
+/// group with MEMBERS, and whose only proposal module is
+/// PROPOSAL_MODULE instantiated with PROPOSAL_MODULE_INSTANTIATE.
+/// Returns the address of the proposal module.
+pub fn instantiate_with_signers(
+    app: &mut BallotApp,
+    proposal_module: Box<dyn Contract<Empty>>,
+    proposal_module_instantiate: Binary,
+    members: Vec<cw4::Member>,
+) -> Addr {
+    let proposal_module_code_id = app.store_code(proposal_module);
+    let cw4_id = app.store_code(cw4_group_contract());
+    let core_id = app.store_code(dao_core_contract());
+    let votemod_id = app.store_code(dao_voting_cw4_contract());
+
+    let governance_instantiate = dao_core::msg::InstantiateMsg {
+        admin: None,
+        name: "DAO DAO".to_string(),
+        description: "A DAO that builds DAOs".to_string(),
+        dao_uri: None,
+        image_url: None,
+        automatically_add_cw20s: true,
+        automatically_add_cw721s: true,
+        voting_module_instantiate_info: ModuleInstantiateInfo {
+            code_id: votemod_id,
+            msg: to_binary(&dao_voting_cw4::msg::InstantiateMsg {
+                cw4_group_code_id: cw4_id,
+                initial_members: members,
+            })
+            .unwrap(),
+            admin: Some(Admin::CoreModule {}),
+            label: "DAO DAO voting module".to_string(),
+        },
+        proposal_modules_instantiate_info: vec![ModuleInstantiateInfo {
+            code_id: proposal_module_code_id,
+            msg: proposal_module_instantiate,
+            admin: Some(Admin::CoreModule {}),
+            label: "DAO DAO governance module".to_string(),
+        }],
+        initial_items: None,
+    };
+
+    let core = app
+        .instantiate_contract(
+            core_id,
+            Addr::unchecked("creator"),
+            &governance_instantiate,
+            &[],
+            "DAO DAO",
+            None,
+        )
+        .unwrap();
+
+    // Update the block so that weights appear.
+    app.update_block(|block| block.height += 1);
+
+    let proposal_modules: Vec<dao_core::state::ProposalModule> = app
+        .wrap()
+        .query_wasm_smart(
+            core,
+            &dao_core::msg::QueryMsg::ProposalModules {
+                start_after: None,
+                limit: None,
+            },
+        )
+        .unwrap();
+    proposal_modules.into_iter().next().unwrap().address
+}
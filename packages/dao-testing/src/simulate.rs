@@ -0,0 +1,86 @@
+//! Helpers for checking that a proposal's messages will execute
+//! successfully before voting on the proposal ends.
+//!
+//! Simulation replays a proposal's messages against the app's
+//! current state exactly as the DAO would execute them, and then
+//! restores the app's state so that the simulation has no effect on
+//! the rest of the test.
+
+use cosmwasm_std::{Addr, CosmosMsg, Empty, Order};
+use cw_multi_test::{App, AppResponse, Executor};
+use dao_interface::ExecuteMsg as DaoExecuteMsg;
+
+/// Executes MSGS as though PROPOSAL_MODULE had executed a proposal
+/// containing them, and then reverts all state changes made by the
+/// execution. Returns the result of execution.
+pub fn simulate_execution(
+    app: &mut App,
+    proposal_module: &Addr,
+    msgs: Vec<CosmosMsg<Empty>>,
+) -> anyhow::Result<AppResponse> {
+    let dao: Addr = app
+        .wrap()
+        .query_wasm_smart(proposal_module, &dao_proposal_single::msg::QueryMsg::Dao {})?;
+
+    let snapshot: Vec<(Vec<u8>, Vec<u8>)> =
+        app.read_module(|_, _, storage| storage.range(None, None, Order::Ascending).collect());
+
+    let result = app.execute_contract(
+        proposal_module.clone(),
+        dao,
+        &DaoExecuteMsg::ExecuteProposalHook { msgs },
+        &[],
+    );
+
+    app.init_modules(|_, _, storage| {
+        let keys: Vec<Vec<u8>> = storage
+            .range(None, None, Order::Ascending)
+            .map(|(key, _)| key)
+            .collect();
+        for key in keys {
+            storage.remove(&key);
+        }
+        for (key, value) in snapshot {
+            storage.set(&key, &value);
+        }
+    });
+
+    result
+}
+
+/// Simulates executing proposal PROPOSAL_ID in the single choice
+/// proposal module PROPOSAL_MODULE. See `simulate_execution`.
+pub fn simulate_single_choice_proposal(
+    app: &mut App,
+    proposal_module: &Addr,
+    proposal_id: u64,
+) -> anyhow::Result<AppResponse> {
+    let proposal: dao_proposal_single::query::ProposalResponse = app.wrap().query_wasm_smart(
+        proposal_module,
+        &dao_proposal_single::msg::QueryMsg::Proposal { proposal_id },
+    )?;
+    simulate_execution(app, proposal_module, proposal.proposal.msgs)
+}
+
+/// Simulates executing option OPTION of proposal PROPOSAL_ID in the
+/// multiple choice proposal module PROPOSAL_MODULE. See
+/// `simulate_execution`.
+pub fn simulate_multiple_choice_proposal(
+    app: &mut App,
+    proposal_module: &Addr,
+    proposal_id: u64,
+    option: u32,
+) -> anyhow::Result<AppResponse> {
+    let proposal: dao_proposal_multiple::query::ProposalResponse = app.wrap().query_wasm_smart(
+        proposal_module,
+        &dao_proposal_multiple::msg::QueryMsg::Proposal { proposal_id },
+    )?;
+    let msgs = proposal
+        .proposal
+        .choices
+        .into_iter()
+        .find(|c| c.index == option)
+        .map(|c| c.msgs)
+        .ok_or_else(|| anyhow::anyhow!("proposal has no option ({option})"))?;
+    simulate_execution(app, proposal_module, msgs)
+}
@@ -1,6 +1,7 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::{
-    to_binary, Addr, CosmosMsg, Deps, MessageInfo, StdError, StdResult, Uint128, WasmMsg,
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, Decimal, Deps, MessageInfo, StdError, StdResult,
+    Uint128, WasmMsg,
 };
 use cw_utils::{must_pay, PaymentError};
 
@@ -25,6 +26,18 @@ pub enum DepositError {
 
     #[error("invalid deposit amount. got ({actual}), expected ({expected})")]
     InvalidDeposit { actual: Uint128, expected: Uint128 },
+
+    #[error("refund policy percentages must be between zero and one")]
+    InvalidRefundPercent,
+
+    #[error("NFT deposits can not be partially refunded")]
+    PartialNftRefund,
+
+    #[error("invalid zero submission fee. set the fee to `None` to have no fee")]
+    ZeroSubmissionFee,
+
+    #[error("invalid submission fee. got ({actual}), expected ({expected})")]
+    InvalidSubmissionFee { actual: Uint128, expected: Uint128 },
 }
 
 /// Information about the token to use for proposal deposits.
@@ -51,6 +64,9 @@ pub struct UncheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding deposits on proposal completion.
     pub refund_policy: DepositRefundPolicy,
+    /// The address that receives deposits that are not refunded. If
+    /// `None`, the DAO receives them.
+    pub slash_recipient: Option<String>,
 }
 
 #[cw_serde]
@@ -61,6 +77,55 @@ pub enum DepositRefundPolicy {
     OnlyPassed,
     /// Deposits should never be refunded.
     Never,
+    /// PERCENT of the deposit is refunded when the proposal is
+    /// closed or executed. The remainder is not refunded.
+    Partial { percent: Decimal },
+    /// Deposits are refunded unless the proposal is rejected with
+    /// more than NO_THRESHOLD of the votes cast being "no" votes, in
+    /// which case the proposal is considered spam and its deposit is
+    /// not refunded. Only applies to proposal modules with yes / no /
+    /// abstain voting. Deposits are always refunded for other
+    /// proposal modules.
+    SlashOnSpam { no_threshold: Decimal },
+}
+
+impl DepositRefundPolicy {
+    /// Validates that any percentages in the policy are between zero
+    /// and one.
+    pub fn validate(&self) -> Result<(), DepositError> {
+        match self {
+            DepositRefundPolicy::Partial { percent: p }
+            | DepositRefundPolicy::SlashOnSpam { no_threshold: p }
+                if *p > Decimal::one() =>
+            {
+                Err(DepositError::InvalidRefundPercent)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the portion of DEPOSIT that ought to be refunded to
+    /// the proposer. PASSED is true if the proposal was executed or
+    /// expired after passing and false if it was closed. NO_RATIO is
+    /// the portion of votes cast on the proposal that were "no"
+    /// votes, if known.
+    pub fn refund_amount(
+        &self,
+        deposit: Uint128,
+        passed: bool,
+        no_ratio: Option<Decimal>,
+    ) -> Uint128 {
+        match self {
+            DepositRefundPolicy::Always => deposit,
+            DepositRefundPolicy::OnlyPassed if passed => deposit,
+            DepositRefundPolicy::OnlyPassed | DepositRefundPolicy::Never => Uint128::zero(),
+            DepositRefundPolicy::Partial { percent } => deposit * *percent,
+            DepositRefundPolicy::SlashOnSpam { no_threshold } => match no_ratio {
+                Some(no_ratio) if !passed && no_ratio > *no_threshold => Uint128::zero(),
+                _ => deposit,
+            },
+        }
+    }
 }
 
 /// Counterpart to the `DepositInfo` struct which has been
@@ -79,6 +144,199 @@ pub struct CheckedDepositInfo {
     pub amount: Uint128,
     /// The policy used for refunding proposal deposits.
     pub refund_policy: DepositRefundPolicy,
+    /// The address that receives deposits that are not refunded. If
+    /// `None`, the DAO receives them.
+    pub slash_recipient: Option<Addr>,
+}
+
+/// Information about the NFT collection whose tokens may be escrowed
+/// to create a proposal.
+#[cw_serde]
+pub struct UncheckedNftDepositInfo {
+    /// The address of the cw721 collection.
+    pub collection: String,
+    /// The policy used for returning escrowed NFTs on proposal
+    /// completion. May not be `Partial`.
+    pub refund_policy: DepositRefundPolicy,
+    /// The address that receives NFTs that are not returned. If
+    /// `None`, the DAO receives them.
+    pub slash_recipient: Option<String>,
+}
+
+/// Counterpart to `UncheckedNftDepositInfo` which has been
+/// validated.
+#[cw_serde]
+pub struct CheckedNftDepositInfo {
+    /// The address of the cw721 collection.
+    pub collection: Addr,
+    /// The policy used for returning escrowed NFTs.
+    pub refund_policy: DepositRefundPolicy,
+    /// The address that receives NFTs that are not returned. If
+    /// `None`, the DAO receives them.
+    pub slash_recipient: Option<Addr>,
+}
+
+impl UncheckedNftDepositInfo {
+    /// Converts NFT deposit info into checked NFT deposit info.
+    pub fn into_checked(self, deps: Deps) -> Result<CheckedNftDepositInfo, DepositError> {
+        if let DepositRefundPolicy::Partial { .. } = self.refund_policy {
+            return Err(DepositError::PartialNftRefund);
+        }
+        self.refund_policy.validate()?;
+        // Make sure this is a cw721 contract.
+        let _: cw721::NumTokensResponse = deps
+            .querier
+            .query_wasm_smart(&self.collection, &cw721::Cw721QueryMsg::NumTokens {})?;
+        Ok(CheckedNftDepositInfo {
+            collection: deps.api.addr_validate(&self.collection)?,
+            refund_policy: self.refund_policy,
+            slash_recipient: self
+                .slash_recipient
+                .map(|addr| deps.api.addr_validate(&addr))
+                .transpose()?,
+        })
+    }
+}
+
+impl CheckedNftDepositInfo {
+    /// Returns a message transferring TOKEN_ID in this collection to
+    /// RECIPIENT.
+    pub fn get_transfer_message(&self, token_id: String, recipient: &Addr) -> StdResult<CosmosMsg> {
+        Ok(WasmMsg::Execute {
+            contract_addr: self.collection.to_string(),
+            msg: to_binary(&cw721::Cw721ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id,
+            })?,
+            funds: vec![],
+        }
+        .into())
+    }
+}
+
+/// Where proposal submission fees are sent.
+#[cw_serde]
+pub enum FeeRecipient {
+    /// Fees are sent to the DAO's treasury.
+    Dao {},
+    /// Fees are burned.
+    Burn {},
+}
+
+/// A non-refundable fee paid when a proposal is submitted, in
+/// addition to any deposit.
+#[cw_serde]
+pub struct UncheckedSubmissionFee {
+    /// The token the fee is paid in.
+    pub denom: UncheckedDenom,
+    /// The fee amount. Must be non-zero.
+    pub amount: Uint128,
+    /// Where the fee is sent when it is paid.
+    pub recipient: FeeRecipient,
+}
+
+/// Counterpart to `UncheckedSubmissionFee` which has been validated.
+#[cw_serde]
+pub struct CheckedSubmissionFee {
+    /// The token the fee is paid in.
+    pub denom: CheckedDenom,
+    /// The fee amount.
+    pub amount: Uint128,
+    /// Where the fee is sent when it is paid.
+    pub recipient: FeeRecipient,
+}
+
+impl UncheckedSubmissionFee {
+    /// Converts submission fee info into checked submission fee info.
+    pub fn into_checked(self, deps: Deps) -> Result<CheckedSubmissionFee, DepositError> {
+        if self.amount.is_zero() {
+            return Err(DepositError::ZeroSubmissionFee);
+        }
+        Ok(CheckedSubmissionFee {
+            denom: self.denom.into_checked(deps)?,
+            amount: self.amount,
+            recipient: self.recipient,
+        })
+    }
+}
+
+impl CheckedSubmissionFee {
+    /// Removes the fee from FUNDS if it is paid in a native token,
+    /// returning the funds that remain to pay any deposit. Errors if
+    /// FUNDS does not include the fee.
+    pub fn deduct_native_fee(&self, funds: &[Coin]) -> Result<Vec<Coin>, DepositError> {
+        let denom = match self.denom {
+            CheckedDenom::Native(ref denom) => denom,
+            // cw20 fees are pulled, not pushed. See:
+            // `get_fee_messages`.
+            CheckedDenom::Cw20(_) => return Ok(funds.to_vec()),
+        };
+        let paid = funds
+            .iter()
+            .filter(|coin| &coin.denom == denom)
+            .map(|coin| coin.amount)
+            .sum::<Uint128>();
+        if paid < self.amount {
+            return Err(DepositError::InvalidSubmissionFee {
+                actual: paid,
+                expected: self.amount,
+            });
+        }
+        let remaining = paid - self.amount;
+        Ok(funds
+            .iter()
+            .filter(|coin| &coin.denom != denom)
+            .cloned()
+            .chain((!remaining.is_zero()).then(|| Coin {
+                denom: denom.clone(),
+                amount: remaining,
+            }))
+            .collect())
+    }
+
+    /// Returns messages that send the fee paid by PAYER to its
+    /// recipient. Native fees are sent from this contract as they
+    /// are included in the funds of the proposal submission, and cw20
+    /// fees are taken from PAYER's allowance to this contract.
+    pub fn get_fee_messages(&self, payer: &Addr, dao: &Addr) -> StdResult<Vec<CosmosMsg>> {
+        let message = match (&self.denom, &self.recipient) {
+            (CheckedDenom::Native(denom), FeeRecipient::Dao {}) => BankMsg::Send {
+                to_address: dao.to_string(),
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount: self.amount,
+                }],
+            }
+            .into(),
+            (CheckedDenom::Native(denom), FeeRecipient::Burn {}) => BankMsg::Burn {
+                amount: vec![Coin {
+                    denom: denom.clone(),
+                    amount: self.amount,
+                }],
+            }
+            .into(),
+            (CheckedDenom::Cw20(address), FeeRecipient::Dao {}) => WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg: to_binary(&cw20::Cw20ExecuteMsg::TransferFrom {
+                    owner: payer.to_string(),
+                    recipient: dao.to_string(),
+                    amount: self.amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+            (CheckedDenom::Cw20(address), FeeRecipient::Burn {}) => WasmMsg::Execute {
+                contract_addr: address.to_string(),
+                msg: to_binary(&cw20::Cw20ExecuteMsg::BurnFrom {
+                    owner: payer.to_string(),
+                    amount: self.amount,
+                })?,
+                funds: vec![],
+            }
+            .into(),
+        };
+        Ok(vec![message])
+    }
 }
 
 impl UncheckedDepositInfo {
@@ -88,6 +346,7 @@ impl UncheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            slash_recipient,
         } = self;
         // Check that the deposit is non-zero. Modules should make
         // deposit information optional and consumers should provide
@@ -95,6 +354,10 @@ impl UncheckedDepositInfo {
         if amount.is_zero() {
             return Err(DepositError::ZeroDeposit);
         }
+        refund_policy.validate()?;
+        let slash_recipient = slash_recipient
+            .map(|addr| deps.api.addr_validate(&addr))
+            .transpose()?;
 
         let denom = match denom {
             DepositToken::Token { denom } => denom.into_checked(deps),
@@ -120,6 +383,7 @@ impl UncheckedDepositInfo {
             denom,
             amount,
             refund_policy,
+            slash_recipient,
         })
     }
 }
@@ -194,6 +458,29 @@ impl CheckedDepositInfo {
         let message = self.denom.get_transfer_to_message(depositor, self.amount)?;
         Ok(vec![message])
     }
+
+    /// Returns messages that send REFUND of the deposit to the
+    /// depositor and the remainder to SLASH_RECIPIENT. REFUND must be
+    /// no larger than the deposit amount.
+    pub fn get_split_deposit_messages(
+        &self,
+        depositor: &Addr,
+        refund: Uint128,
+        slash_recipient: &Addr,
+    ) -> StdResult<Vec<CosmosMsg>> {
+        let slashed = self.amount.checked_sub(refund)?;
+        let mut messages = vec![];
+        if !refund.is_zero() {
+            messages.push(self.denom.get_transfer_to_message(depositor, refund)?);
+        }
+        if !slashed.is_zero() {
+            messages.push(
+                self.denom
+                    .get_transfer_to_message(slash_recipient, slashed)?,
+            );
+        }
+        Ok(messages)
+    }
 }
 
 #[cfg(test)]
@@ -212,6 +499,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         deposit_info.check_native_deposit_paid(&info).unwrap();
 
@@ -234,6 +522,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -252,6 +541,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(
@@ -272,6 +562,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
 
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
@@ -285,6 +576,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let err = deposit_info.check_native_deposit_paid(&info).unwrap_err();
         assert_eq!(err, DepositError::Payment(PaymentError::NoFunds {}));
@@ -297,6 +589,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let messages = deposit_info
             .get_take_deposit_messages(&Addr::unchecked("ekez"), &Addr::unchecked(CW20))
@@ -337,6 +630,7 @@ pub mod tests {
             denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -363,6 +657,7 @@ pub mod tests {
             denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
             amount: Uint128::new(10),
             refund_policy: DepositRefundPolicy::Always,
+            slash_recipient: None,
         };
         let messages = deposit_info
             .get_return_deposit_message(&Addr::unchecked("ekez"))
@@ -387,4 +682,143 @@ pub mod tests {
             .unwrap();
         assert_eq!(messages, vec![]);
     }
+
+    #[test]
+    fn test_refund_amount() {
+        let deposit = Uint128::new(10);
+
+        assert_eq!(
+            DepositRefundPolicy::Always.refund_amount(deposit, false, None),
+            deposit
+        );
+        assert_eq!(
+            DepositRefundPolicy::OnlyPassed.refund_amount(deposit, true, None),
+            deposit
+        );
+        assert_eq!(
+            DepositRefundPolicy::OnlyPassed.refund_amount(deposit, false, None),
+            Uint128::zero()
+        );
+        assert_eq!(
+            DepositRefundPolicy::Never.refund_amount(deposit, true, None),
+            Uint128::zero()
+        );
+
+        let partial = DepositRefundPolicy::Partial {
+            percent: Decimal::percent(75),
+        };
+        assert_eq!(partial.refund_amount(deposit, false, None), Uint128::new(7));
+        assert_eq!(partial.refund_amount(deposit, true, None), Uint128::new(7));
+
+        let spam = DepositRefundPolicy::SlashOnSpam {
+            no_threshold: Decimal::percent(50),
+        };
+        assert_eq!(
+            spam.refund_amount(deposit, false, Some(Decimal::percent(51))),
+            Uint128::zero()
+        );
+        assert_eq!(
+            spam.refund_amount(deposit, false, Some(Decimal::percent(50))),
+            deposit
+        );
+        assert_eq!(
+            spam.refund_amount(deposit, true, Some(Decimal::percent(100))),
+            deposit
+        );
+        // Unknown vote counts are never treated as spam.
+        assert_eq!(spam.refund_amount(deposit, false, None), deposit);
+    }
+
+    #[test]
+    fn test_validate_refund_policy() {
+        DepositRefundPolicy::Partial {
+            percent: Decimal::one(),
+        }
+        .validate()
+        .unwrap();
+        let err = DepositRefundPolicy::SlashOnSpam {
+            no_threshold: Decimal::percent(101),
+        }
+        .validate()
+        .unwrap_err();
+        assert_eq!(err, DepositError::InvalidRefundPercent);
+    }
+
+    #[test]
+    fn test_get_split_deposit_messages() {
+        let deposit_info = CheckedDepositInfo {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(10),
+            refund_policy: DepositRefundPolicy::Partial {
+                percent: Decimal::percent(30),
+            },
+            slash_recipient: None,
+        };
+        let messages = deposit_info
+            .get_split_deposit_messages(
+                &Addr::unchecked("ekez"),
+                Uint128::new(3),
+                &Addr::unchecked("dao"),
+            )
+            .unwrap();
+        assert_eq!(
+            messages,
+            vec![
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "ekez".to_string(),
+                    amount: coins(3, NATIVE_DENOM)
+                }),
+                CosmosMsg::Bank(BankMsg::Send {
+                    to_address: "dao".to_string(),
+                    amount: coins(7, NATIVE_DENOM)
+                })
+            ]
+        );
+
+        // Nothing is sent to the slash recipient on a full refund.
+        let messages = deposit_info
+            .get_split_deposit_messages(
+                &Addr::unchecked("ekez"),
+                Uint128::new(10),
+                &Addr::unchecked("dao"),
+            )
+            .unwrap();
+        assert_eq!(messages.len(), 1);
+    }
+
+    #[test]
+    fn test_deduct_native_fee() {
+        let fee = CheckedSubmissionFee {
+            denom: CheckedDenom::Native(NATIVE_DENOM.to_string()),
+            amount: Uint128::new(5),
+            recipient: FeeRecipient::Dao {},
+        };
+        // The fee and deposit may be paid in the same denomination.
+        assert_eq!(
+            fee.deduct_native_fee(&coins(15, NATIVE_DENOM)).unwrap(),
+            coins(10, NATIVE_DENOM)
+        );
+        assert_eq!(
+            fee.deduct_native_fee(&[coin(5, NATIVE_DENOM), coin(10, "uatom")])
+                .unwrap(),
+            coins(10, "uatom")
+        );
+        assert_eq!(
+            fee.deduct_native_fee(&coins(4, NATIVE_DENOM)).unwrap_err(),
+            DepositError::InvalidSubmissionFee {
+                actual: Uint128::new(4),
+                expected: Uint128::new(5)
+            }
+        );
+
+        // cw20 fees are not paid with funds.
+        let fee = CheckedSubmissionFee {
+            denom: CheckedDenom::Cw20(Addr::unchecked(CW20)),
+            ..fee
+        };
+        assert_eq!(
+            fee.deduct_native_fee(&coins(10, NATIVE_DENOM)).unwrap(),
+            coins(10, NATIVE_DENOM)
+        );
+    }
 }
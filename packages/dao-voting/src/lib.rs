@@ -6,6 +6,7 @@ pub mod multiple_choice;
 pub mod pre_propose;
 pub mod proposal;
 pub mod reply;
+pub mod signed_ballot;
 pub mod status;
 pub mod threshold;
 pub mod voting;
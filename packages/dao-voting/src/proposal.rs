@@ -1,9 +1,13 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{CosmosMsg, Empty};
+use cosmwasm_schema::{cw_serde, serde::Deserialize};
+use cosmwasm_std::{Addr, Api, CosmosMsg, Empty, QuerierWrapper, StdResult};
+
+use crate::{multiple_choice::MultipleChoiceOptions, status::Status, threshold::Threshold};
 
 /// Default limit for proposal pagination.
 pub const DEFAULT_LIMIT: u64 = 30;
 pub const MAX_PROPOSAL_SIZE: u64 = 30_000;
+/// The maximum number of proposals a single proposal may depend on.
+pub const MAX_DEPENDENCIES: usize = 10;
 
 /// The contents of a message to create a proposal in the single
 /// choice proposal module.
@@ -31,4 +35,101 @@ pub struct SingleChoiceProposeMsg {
     /// pre-propose module is attached, this must be Some and will
     /// set the proposer of the proposal it creates.
     pub proposer: Option<String>,
+    /// A threshold to use for this proposal in place of the proposal
+    /// module's. Only a pre-propose module may set this, for example
+    /// to require a higher threshold for certain kinds of proposals.
+    pub threshold: Option<Threshold>,
+    /// Proposals that must be executed before this proposal may be
+    /// executed. These may live in this or any other proposal module.
+    pub depends_on: Option<Vec<ProposalDependency>>,
+}
+
+/// The contents of a message to create a proposal in the multiple
+/// choice proposal module.
+///
+/// See `SingleChoiceProposeMsg` for why this type lives in this
+/// package.
+#[cw_serde]
+pub struct MultipleChoiceProposeMsg {
+    /// The title of the proposal.
+    pub title: String,
+    /// A description of the proposal.
+    pub description: String,
+    /// The multiple choices.
+    pub choices: MultipleChoiceOptions,
+    /// The address creating the proposal. If no pre-propose
+    /// module is attached to this module this must always be None
+    /// as the proposer is the sender of the propose message. If a
+    /// pre-propose module is attached, this must be Some and will
+    /// set the proposer of the proposal it creates.
+    pub proposer: Option<String>,
+    /// Proposals that must be executed before this proposal may be
+    /// executed. These may live in this or any other proposal module.
+    pub depends_on: Option<Vec<ProposalDependency>>,
+}
+
+/// A proposal that must be executed before the proposal depending on
+/// it may be executed.
+#[cw_serde]
+pub struct ProposalDependency {
+    /// The address of the proposal module the proposal was created
+    /// in.
+    pub proposal_module: String,
+    /// The ID of the proposal in that module.
+    pub proposal_id: u64,
+}
+
+/// A `ProposalDependency` whose proposal module address has been
+/// validated.
+#[cw_serde]
+pub struct CheckedProposalDependency {
+    pub proposal_module: Addr,
+    pub proposal_id: u64,
+}
+
+/// The query sent to a dependency's proposal module. Every proposal
+/// module exposes a `Proposal {}` query.
+#[cw_serde]
+enum ProposalQuery {
+    Proposal { proposal_id: u64 },
+}
+
+/// The subset of a proposal module's `Proposal {}` query response
+/// needed to inspect its status.
+#[derive(Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct ProposalStatusResponse {
+    proposal: ProposalStatus,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "cosmwasm_schema::serde")]
+struct ProposalStatus {
+    status: Status,
+}
+
+impl ProposalDependency {
+    pub fn into_checked(self, api: &dyn Api) -> StdResult<CheckedProposalDependency> {
+        Ok(CheckedProposalDependency {
+            proposal_module: api.addr_validate(&self.proposal_module)?,
+            proposal_id: self.proposal_id,
+        })
+    }
+}
+
+impl CheckedProposalDependency {
+    /// Queries the dependency's proposal module for the proposal's
+    /// current status. Errors if the proposal does not exist.
+    ///
+    /// Proposal modules should look up dependencies on their own
+    /// proposals in storage rather than querying themselves.
+    pub fn query_status(&self, querier: &QuerierWrapper) -> StdResult<Status> {
+        let response: ProposalStatusResponse = querier.query_wasm_smart(
+            &self.proposal_module,
+            &ProposalQuery::Proposal {
+                proposal_id: self.proposal_id,
+            },
+        )?;
+        Ok(response.proposal.status)
+    }
 }
@@ -10,6 +10,14 @@ const FAILED_VOTE_HOOK_MASK: u64 = 0b010;
 const PRE_PROPOSE_MODULE_INSTANTIATION_ID: u64 = 0b011;
 const FAILED_PRE_PROPOSE_MODULE_HOOK_ID: u64 = 0b100;
 
+const FAILED_PROPOSAL_MESSAGE_MASK: u64 = 0b101;
+
+/// Number of bits, after the reply type, used to store the index of a
+/// proposal message that failed when messages are executed
+/// individually. The proposal ID occupies the remaining bits.
+const BITS_RESERVED_FOR_MESSAGE_INDEX: u8 = 16;
+const MESSAGE_INDEX_MASK: u64 = (1 << BITS_RESERVED_FOR_MESSAGE_INDEX) - 1;
+
 const BITS_RESERVED_FOR_REPLY_TYPE: u8 = 3;
 const REPLY_TYPE_MASK: u64 = (1 << BITS_RESERVED_FOR_REPLY_TYPE) - 1;
 
@@ -32,6 +40,9 @@ pub enum TaggedReplyId {
     FailedPreProposeModuleHook,
     /// Fired when a pre-propose module is successfully instantiated.
     PreProposeModuleInstantiation,
+    /// Fired when a single message of a proposal executed message by
+    /// message fails. Contains the proposal ID and message index.
+    FailedProposalMessage(u64, u64),
 }
 
 impl TaggedReplyId {
@@ -50,6 +61,10 @@ impl TaggedReplyId {
             FAILED_VOTE_HOOK_MASK => Ok(TaggedReplyId::FailedVoteHook(id_after_shift)),
             PRE_PROPOSE_MODULE_INSTANTIATION_ID => Ok(TaggedReplyId::PreProposeModuleInstantiation),
             FAILED_PRE_PROPOSE_MODULE_HOOK_ID => Ok(TaggedReplyId::FailedPreProposeModuleHook),
+            FAILED_PROPOSAL_MESSAGE_MASK => Ok(TaggedReplyId::FailedProposalMessage(
+                id_after_shift >> BITS_RESERVED_FOR_MESSAGE_INDEX,
+                id_after_shift & MESSAGE_INDEX_MASK,
+            )),
             _ => Err(error::TagError::UnknownReplyId { id }),
         }
     }
@@ -68,6 +83,15 @@ pub const fn mask_vote_hook_index(index: u64) -> u64 {
     FAILED_VOTE_HOOK_MASK | (index << BITS_RESERVED_FOR_REPLY_TYPE)
 }
 
+/// This function can drop bits, if you have more than
+/// `u(64-[`BITS_RESERVED_FOR_REPLY_TYPE`]-[`BITS_RESERVED_FOR_MESSAGE_INDEX`])`
+/// proposals or more than `u16::MAX` messages in a proposal.
+pub const fn mask_proposal_message_index(proposal_id: u64, index: u64) -> u64 {
+    FAILED_PROPOSAL_MESSAGE_MASK
+        | ((index & MESSAGE_INDEX_MASK) << BITS_RESERVED_FOR_REPLY_TYPE)
+        | (proposal_id << (BITS_RESERVED_FOR_REPLY_TYPE + BITS_RESERVED_FOR_MESSAGE_INDEX))
+}
+
 pub const fn pre_propose_module_instantiation_id() -> u64 {
     PRE_PROPOSE_MODULE_INSTANTIATION_ID
 }
@@ -100,6 +124,7 @@ mod test {
         let m_proposal_id = mask_proposal_execution_proposal_id(proposal_id_max);
         let m_proposal_hook_idx = mask_proposal_hook_index(proposal_hook_idx);
         let m_vote_hook_idx = mask_vote_hook_index(vote_hook_idx);
+        let m_proposal_message_idx = mask_proposal_message_index(proposal_hook_idx, 7);
 
         assert_eq!(
             TaggedReplyId::new(m_proposal_id).unwrap(),
@@ -113,6 +138,10 @@ mod test {
             TaggedReplyId::new(m_vote_hook_idx).unwrap(),
             TaggedReplyId::FailedVoteHook(vote_hook_idx)
         );
+        assert_eq!(
+            TaggedReplyId::new(m_proposal_message_idx).unwrap(),
+            TaggedReplyId::FailedProposalMessage(proposal_hook_idx, 7)
+        );
         assert_eq!(
             TaggedReplyId::new(0b110).unwrap_err(),
             error::TagError::UnknownReplyId { id: 0b110 }
@@ -0,0 +1,178 @@
+use cosmwasm_schema::{cw_serde, serde::Serialize};
+use cosmwasm_std::{to_vec, Addr, Api, Binary, CanonicalAddr, StdError, VerificationError};
+use ripemd::Ripemd160;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// Length of a compressed secp256k1 public key.
+const COMPRESSED_PUBKEY_LENGTH: usize = 33;
+
+/// A ballot signed off-chain by a voter. Signed ballots may be
+/// submitted to a proposal module by any address, allowing voters
+/// without gas to vote.
+#[cw_serde]
+pub struct SignedBallot<V> {
+    /// The vote being cast.
+    pub vote: V,
+    /// The voter's next nonce in the proposal module. Nonces must be
+    /// used in order so that ballots may not be replayed.
+    pub nonce: u64,
+    /// The voter's compressed secp256k1 public key. The voter's
+    /// address is derived from this key.
+    pub pubkey: Binary,
+    /// The voter's 64 byte secp256k1 signature over the SHA-256 hash
+    /// of the ballot's `BallotSignDoc` serialized as JSON.
+    pub signature: Binary,
+}
+
+/// The message signed by voters to create a `SignedBallot`. Fields
+/// are serialized in the order they are declared.
+#[cw_serde]
+pub struct BallotSignDoc<V> {
+    /// The ID of the chain the proposal module is on.
+    pub chain_id: String,
+    /// The address of the proposal module.
+    pub proposal_module: String,
+    /// The ID of the proposal being voted on.
+    pub proposal_id: u64,
+    /// The vote being cast.
+    pub vote: V,
+    /// The voter's nonce in the proposal module.
+    pub nonce: u64,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SignedBallotError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Verification(#[from] VerificationError),
+
+    #[error("ballot public keys must be compressed secp256k1 keys")]
+    InvalidPubkey {},
+
+    #[error("ballot signature does not match its contents")]
+    InvalidSignature {},
+
+    #[error("ballot nonce ({actual}) does not match the voter's next nonce ({expected})")]
+    WrongNonce { expected: u64, actual: u64 },
+}
+
+impl<V: Serialize> BallotSignDoc<V> {
+    /// Gets the SHA-256 hash of this document. This is the message
+    /// voters sign.
+    pub fn hash(&self) -> Result<Vec<u8>, StdError> {
+        Ok(Sha256::digest(to_vec(self)?).to_vec())
+    }
+}
+
+impl<V: Serialize + Clone> SignedBallot<V> {
+    /// Verifies that this ballot was signed by the owner of its
+    /// public key for the specified proposal and returns the
+    /// canonical address of the signer. The canonical address of a
+    /// secp256k1 account is the RIPEMD-160 hash of the SHA-256 hash of
+    /// its compressed public key.
+    pub fn verify(
+        &self,
+        api: &dyn Api,
+        chain_id: &str,
+        proposal_module: &Addr,
+        proposal_id: u64,
+    ) -> Result<CanonicalAddr, SignedBallotError> {
+        if self.pubkey.len() != COMPRESSED_PUBKEY_LENGTH {
+            return Err(SignedBallotError::InvalidPubkey {});
+        }
+        let hash = BallotSignDoc {
+            chain_id: chain_id.to_string(),
+            proposal_module: proposal_module.to_string(),
+            proposal_id,
+            vote: self.vote.clone(),
+            nonce: self.nonce,
+        }
+        .hash()?;
+        if !api.secp256k1_verify(&hash, &self.signature, &self.pubkey)? {
+            return Err(SignedBallotError::InvalidSignature {});
+        }
+        let address = Ripemd160::digest(Sha256::digest(&self.pubkey));
+        Ok(CanonicalAddr::from(address.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmwasm_std::testing::MockApi;
+    use k256::ecdsa::{signature::Signer, Signature, SigningKey};
+
+    use super::*;
+    use crate::voting::Vote;
+
+    fn sign(key: &SigningKey, vote: Vote, nonce: u64) -> SignedBallot<Vote> {
+        let doc = BallotSignDoc {
+            chain_id: "juno-1".to_string(),
+            proposal_module: "proposal_module".to_string(),
+            proposal_id: 1,
+            vote,
+            nonce,
+        };
+        let signature: Signature = key.sign(&to_vec(&doc).unwrap());
+        SignedBallot {
+            vote,
+            nonce,
+            pubkey: Binary::from(key.verifying_key().to_bytes().as_slice()),
+            signature: Binary::from(signature.as_ref()),
+        }
+    }
+
+    #[test]
+    fn test_verify_signed_ballot() {
+        let api = MockApi::default();
+        let module = Addr::unchecked("proposal_module");
+        let key = SigningKey::from_bytes(&[7; 32]).unwrap();
+
+        let ballot = sign(&key, Vote::Yes, 0);
+        let signer = ballot.verify(&api, "juno-1", &module, 1).unwrap();
+        assert_eq!(signer.len(), 20);
+
+        // Ballots are bound to a chain, module, and proposal.
+        assert_eq!(
+            ballot.verify(&api, "juno-2", &module, 1).unwrap_err(),
+            SignedBallotError::InvalidSignature {}
+        );
+        assert_eq!(
+            ballot
+                .verify(&api, "juno-1", &Addr::unchecked("other"), 1)
+                .unwrap_err(),
+            SignedBallotError::InvalidSignature {}
+        );
+        assert_eq!(
+            ballot.verify(&api, "juno-1", &module, 2).unwrap_err(),
+            SignedBallotError::InvalidSignature {}
+        );
+
+        // Changing the vote or nonce invalidates the signature.
+        let mut tampered = ballot.clone();
+        tampered.vote = Vote::No;
+        assert_eq!(
+            tampered.verify(&api, "juno-1", &module, 1).unwrap_err(),
+            SignedBallotError::InvalidSignature {}
+        );
+        let mut tampered = ballot.clone();
+        tampered.nonce = 1;
+        assert_eq!(
+            tampered.verify(&api, "juno-1", &module, 1).unwrap_err(),
+            SignedBallotError::InvalidSignature {}
+        );
+
+        // The same key always maps to the same signer.
+        let ballot = sign(&key, Vote::No, 1);
+        assert_eq!(ballot.verify(&api, "juno-1", &module, 1).unwrap(), signer);
+
+        let mut ballot = ballot;
+        ballot.pubkey = Binary::from(&[1; 65][..]);
+        assert_eq!(
+            ballot.verify(&api, "juno-1", &module, 1).unwrap_err(),
+            SignedBallotError::InvalidPubkey {}
+        );
+    }
+}
@@ -16,6 +16,10 @@ pub enum Status {
     Closed,
     /// The proposal's execution failed.
     ExecutionFailed,
+    /// The proposal passed but was not executed before its execution
+    /// deadline. A proposal deposit refund has been issued if
+    /// applicable.
+    Expired,
 }
 
 impl std::fmt::Display for Status {
@@ -27,6 +31,7 @@ impl std::fmt::Display for Status {
             Status::Executed => write!(f, "executed"),
             Status::Closed => write!(f, "closed"),
             Status::ExecutionFailed => write!(f, "execution_failed"),
+            Status::Expired => write!(f, "expired"),
         }
     }
 }
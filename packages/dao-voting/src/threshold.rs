@@ -66,10 +66,33 @@ pub enum Threshold {
     /// An absolute number of votes needed for something to cross the
     /// threshold. Useful for multisig style voting.
     AbsoluteCount { threshold: Uint128 },
+
+    /// Proposals pass at expiration unless they are objected to. A
+    /// proposal is objected to, and rejected, once the no votes cast
+    /// on it reach `objection_threshold` of the total voting
+    /// power. Useful for routine operations which members ought to be
+    /// able to veto, but need not approve.
+    Optimistic {
+        objection_threshold: PercentageThreshold,
+    },
+
+    /// Like `ThresholdQuorum`, but the quorum required varies with
+    /// the opposition to a proposal. With no votes against a proposal
+    /// `base_quorum` of the total voting power must participate. As
+    /// the share of non-abstaining votes that are no votes approaches
+    /// 100%, the required quorum moves linearly towards
+    /// `opposed_quorum`. Setting `opposed_quorum` above `base_quorum`
+    /// requires more participation for contested proposals, setting
+    /// it below requires less.
+    DynamicQuorum {
+        threshold: PercentageThreshold,
+        base_quorum: Decimal,
+        opposed_quorum: Decimal,
+    },
 }
 
 /// Asserts that the 0.0 < percent <= 1.0
-fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
+pub fn validate_percentage(percent: &PercentageThreshold) -> Result<(), ThresholdError> {
     if let PercentageThreshold::Percent(percent) = percent {
         if percent.is_zero() {
             Err(ThresholdError::ZeroThreshold {})
@@ -97,12 +120,34 @@ pub fn validate_quorum(quorum: &PercentageThreshold) -> Result<(), ThresholdErro
     }
 }
 
+/// Computes the quorum required by a `Threshold::DynamicQuorum` given
+/// the yes and no votes cast on a proposal.
+pub fn dynamic_quorum(
+    base_quorum: Decimal,
+    opposed_quorum: Decimal,
+    yes: Uint128,
+    no: Uint128,
+) -> Decimal {
+    let cast = yes + no;
+    if cast.is_zero() {
+        return base_quorum;
+    }
+    let opposition = Decimal::from_ratio(no, cast);
+    if opposed_quorum >= base_quorum {
+        base_quorum + (opposed_quorum - base_quorum) * opposition
+    } else {
+        base_quorum - (base_quorum - opposed_quorum) * opposition
+    }
+}
+
 impl Threshold {
     /// Validates the threshold.
     ///
     /// - Quorums must never be over 100%.
     /// - Passing thresholds must never be over 100%, nor be 0%.
     /// - Absolute count thresholds must be non-zero.
+    /// - Objection thresholds must never be over 100%, nor be 0%.
+    /// - Dynamic quorums must never be over 100%.
     pub fn validate(&self) -> Result<(), ThresholdError> {
         match self {
             Threshold::AbsolutePercentage {
@@ -119,6 +164,18 @@ impl Threshold {
                     Ok(())
                 }
             }
+            Threshold::Optimistic {
+                objection_threshold,
+            } => validate_percentage(objection_threshold),
+            Threshold::DynamicQuorum {
+                threshold,
+                base_quorum,
+                opposed_quorum,
+            } => {
+                validate_percentage(threshold)?;
+                validate_quorum(&PercentageThreshold::Percent(*base_quorum))?;
+                validate_quorum(&PercentageThreshold::Percent(*opposed_quorum))
+            }
         }
     }
 }
@@ -179,5 +236,69 @@ mod tests {
             t.validate().unwrap_err(),
             ThresholdError::UnreachableThreshold {}
         );
+
+        let t = Threshold::Optimistic {
+            objection_threshold: p!(0),
+        };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::Optimistic {
+            objection_threshold: p!(101),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+
+        let t = Threshold::Optimistic {
+            objection_threshold: PercentageThreshold::Majority {},
+        };
+        t.validate().unwrap();
+
+        let t = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            base_quorum: Decimal::percent(10),
+            opposed_quorum: Decimal::percent(101),
+        };
+        assert_eq!(
+            t.validate().unwrap_err(),
+            ThresholdError::UnreachableThreshold {}
+        );
+
+        let t = Threshold::DynamicQuorum {
+            threshold: p!(0),
+            base_quorum: Decimal::percent(10),
+            opposed_quorum: Decimal::percent(30),
+        };
+        assert_eq!(t.validate().unwrap_err(), ThresholdError::ZeroThreshold {});
+
+        let t = Threshold::DynamicQuorum {
+            threshold: PercentageThreshold::Majority {},
+            base_quorum: Decimal::percent(30),
+            opposed_quorum: Decimal::zero(),
+        };
+        t.validate().unwrap();
+    }
+
+    #[test]
+    fn test_dynamic_quorum() {
+        let q = |base: u64, opposed: u64, yes: u128, no: u128| {
+            dynamic_quorum(
+                Decimal::percent(base),
+                Decimal::percent(opposed),
+                Uint128::new(yes),
+                Uint128::new(no),
+            )
+        };
+
+        // No votes cast, or no opposition, requires the base quorum.
+        assert_eq!(q(10, 30, 0, 0), Decimal::percent(10));
+        assert_eq!(q(10, 30, 5, 0), Decimal::percent(10));
+        // Quorum increases with opposition..
+        assert_eq!(q(10, 30, 5, 5), Decimal::percent(20));
+        assert_eq!(q(10, 30, 0, 5), Decimal::percent(30));
+        // ..or decreases.
+        assert_eq!(q(30, 10, 5, 5), Decimal::percent(20));
+        assert_eq!(q(30, 10, 0, 5), Decimal::percent(10));
     }
 }
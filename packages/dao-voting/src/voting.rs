@@ -1,7 +1,8 @@
-use cosmwasm_schema::cw_serde;
-use cosmwasm_std::{Addr, Decimal, Deps, StdResult, Uint128, Uint256};
+use cosmwasm_schema::{cw_serde, serde::Serialize};
+use cosmwasm_std::{to_vec, Addr, Binary, Decimal, Deps, StdResult, Uint128, Uint256};
 use cw_utils::Duration;
 use dao_interface::voting;
+use sha2::{Digest, Sha256};
 
 use crate::threshold::PercentageThreshold;
 
@@ -12,8 +13,12 @@ const PRECISION_FACTOR: u128 = 10u128.pow(9);
 #[cw_serde]
 pub struct Votes {
     pub yes: Uint128,
+    /// No votes, including no with veto votes.
     pub no: Uint128,
     pub abstain: Uint128,
+    /// The portion of `no` votes that were no with veto votes.
+    #[serde(default)]
+    pub no_with_veto: Uint128,
 }
 
 #[cw_serde]
@@ -27,6 +32,9 @@ pub enum Vote {
     /// Marks participation but does not count towards the ratio of
     /// support / opposed.
     Abstain,
+    /// Marks opposition to the proposal and that the proposal ought
+    /// to be vetoed. Counts as a no vote.
+    NoWithVeto,
 }
 
 pub enum VoteCmp {
@@ -133,6 +141,7 @@ impl Votes {
             yes: Uint128::zero(),
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -144,6 +153,7 @@ impl Votes {
             yes,
             no: Uint128::zero(),
             abstain: Uint128::zero(),
+            no_with_veto: Uint128::zero(),
         }
     }
 
@@ -153,6 +163,10 @@ impl Votes {
             Vote::Yes => self.yes += power,
             Vote::No => self.no += power,
             Vote::Abstain => self.abstain += power,
+            Vote::NoWithVeto => {
+                self.no += power;
+                self.no_with_veto += power;
+            }
         }
     }
 
@@ -164,6 +178,10 @@ impl Votes {
             Vote::Yes => self.yes -= power,
             Vote::No => self.no -= power,
             Vote::Abstain => self.abstain -= power,
+            Vote::NoWithVeto => {
+                self.no -= power;
+                self.no_with_veto -= power;
+            }
         }
     }
 
@@ -185,6 +203,7 @@ impl std::fmt::Display for Vote {
             Vote::Yes => write!(f, "yes"),
             Vote::No => write!(f, "no"),
             Vote::Abstain => write!(f, "abstain"),
+            Vote::NoWithVeto => write!(f, "no_with_veto"),
         }
     }
 }
@@ -238,6 +257,32 @@ pub fn validate_voting_period(
     Ok((min, max))
 }
 
+/// A vote and the salt hiding it in a commit-reveal vote. The
+/// proposal and voter are included so that a commitment may not be
+/// copied by another voter or reused on another proposal. Fields are
+/// serialized in the order they are declared.
+#[cw_serde]
+pub struct VoteReveal<V> {
+    /// The address of the proposal module.
+    pub proposal_module: String,
+    /// The ID of the proposal being voted on.
+    pub proposal_id: u64,
+    /// The address of the voter.
+    pub voter: String,
+    pub vote: V,
+    /// A random value chosen by the voter so that their commitment
+    /// may not be guessed.
+    pub salt: String,
+}
+
+impl<V: Serialize> VoteReveal<V> {
+    /// Gets the commitment voters submit before revealing their
+    /// vote: the SHA-256 hash of this reveal serialized as JSON.
+    pub fn commitment(&self) -> StdResult<Binary> {
+        Ok(Binary::from(Sha256::digest(to_vec(self)?).as_slice()))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -253,6 +298,15 @@ mod test {
         assert_eq!(votes.yes, Uint128::new(35));
         assert_eq!(votes.no, Uint128::new(10));
         assert_eq!(votes.abstain, Uint128::new(40));
+
+        votes.add_vote(Vote::NoWithVeto, Uint128::new(20));
+        assert_eq!(votes.total(), Uint128::new(5 + 10 + 30 + 40 + 20));
+        assert_eq!(votes.no, Uint128::new(30));
+        assert_eq!(votes.no_with_veto, Uint128::new(20));
+
+        votes.remove_vote(Vote::NoWithVeto, Uint128::new(20));
+        assert_eq!(votes.no, Uint128::new(10));
+        assert!(votes.no_with_veto.is_zero());
     }
 
     #[test]
@@ -149,6 +149,12 @@ fn test_counters() {
         allow_revoting: false,
         pre_propose_info: PreProposeInfo::AnyoneMayPropose {},
         close_proposal_on_execution_failure: true,
+        early_execution: None,
+        execution_window: None,
+        retry_policy: None,
+        amendment_window: None,
+        reveal_period: None,
+        veto_threshold: None,
     };
 
     let governance_addr =
@@ -253,6 +259,8 @@ fn test_counters() {
             description: "This is a simple text proposal".to_string(),
             msgs: vec![],
             proposer: None,
+            threshold: None,
+            depends_on: None,
         }),
         &[],
     )
@@ -353,6 +361,8 @@ fn test_counters() {
             description: "This is a simple text proposal 2nd".to_string(),
             msgs: vec![],
             proposer: None,
+            threshold: None,
+            depends_on: None,
         }),
         &[],
     )